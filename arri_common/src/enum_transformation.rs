@@ -5,7 +5,7 @@ use std::str::FromStr;
 /// These transformations can be applied to strings to convert them
 /// into different cases, such as uppercase, lowercase, snake case,
 /// camel case, or pascal case.
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub enum EnumTransformation {
     /// Converts the string to uppercase.
     Uppercase,
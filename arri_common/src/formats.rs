@@ -0,0 +1,4 @@
+/// The formats that can be used with `#[arri(format = "...")]`.
+///
+/// The derive rejects other formats, and `ronky::validation` checks values against these.
+pub const FORMATS: &[&str] = &["email", "uuid", "uri", "date", "date-time", "ipv4", "ipv6"];
@@ -3,5 +3,7 @@
 /// This crate contains shared definitions to avoid circular dependencies
 /// and code duplication between arri_repr and ronky_derive.
 mod enum_transformation;
+mod formats;
 
 pub use enum_transformation::EnumTransformation;
+pub use formats::FORMATS;
@@ -0,0 +1,153 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, Weak};
+
+use arri_common::EnumTransformation;
+
+use crate::{ExportDirection, Naming, OutputOptions, ResultShape, Serializable};
+
+/// The type and the configuration of the context a cached export was made with.
+pub(crate) type CacheKey = (
+    TypeId,
+    ExportDirection,
+    Naming,
+    Option<EnumTransformation>,
+    ResultShape,
+    OutputOptions,
+);
+
+/// The export of a type, and its serialized form once it has been requested.
+///
+/// Schemas can't be sent between threads, so every thread keeps its own export of the type,
+/// while the serialized form is shared by all threads.
+#[derive(Debug, Default)]
+pub(crate) struct CachedExport {
+    pub(crate) serialized: OnceLock<Option<Arc<str>>>,
+}
+
+/// The schemas of the cache entries exported on a thread.
+type ThreadSchemas = HashMap<*const CachedExport, (Weak<CachedExport>, Arc<dyn Serializable>)>;
+
+thread_local! {
+    /// The schemas exported on this thread, by the cache entry they belong to. An entry keeps
+    /// its allocation alive through the weak reference, so its address isn't reused, and the
+    /// schemas of cleared entries are dropped once the thread caches another schema.
+    static THREAD_SCHEMAS: RefCell<ThreadSchemas> = RefCell::new(HashMap::new());
+}
+
+impl CachedExport {
+    /// Retrieves the schema of the entry exported on this thread, exporting it with `export` the
+    /// first time.
+    pub(crate) fn schema(
+        self: &Arc<Self>,
+        export: impl FnOnce() -> Box<dyn Serializable>,
+    ) -> Arc<dyn Serializable> {
+        let key = Arc::as_ptr(self);
+        if let Some((_, schema)) = THREAD_SCHEMAS.with_borrow(|schemas| schemas.get(&key).cloned())
+        {
+            return schema;
+        }
+
+        // The schemas aren't borrowed while exporting, as the export may use the cache as well
+        let schema = Arc::<dyn Serializable>::from(export());
+        THREAD_SCHEMAS.with_borrow_mut(|schemas| {
+            schemas.retain(|_, (entry, _)| entry.strong_count() > 0);
+            schemas
+                .entry(key)
+                .or_insert_with(|| (Arc::downgrade(self), schema))
+                .1
+                .clone()
+        })
+    }
+}
+
+/// A cache of exported schemas, shared by an [`ExportContext`](crate::ExportContext) and its
+/// clones.
+///
+/// Every type is exported once per configuration of the context, after which the same
+/// immutable schema (and its serialized form) is returned. The cache can be shared between
+/// threads: the serialized form is shared by all of them, and the schema by the exports on the
+/// same thread.
+#[derive(Debug, Default)]
+pub struct ExportCache {
+    entries: Mutex<HashMap<CacheKey, Arc<CachedExport>>>,
+}
+
+impl ExportCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieves the entry of a type, which is initialized by the first export of the type.
+    ///
+    /// The lock is only held to look up the entry, so exports of distinct types don't block
+    /// each other.
+    pub(crate) fn entry(&self, key: CacheKey) -> Arc<CachedExport> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_default()
+            .clone()
+    }
+
+    /// Retrieves the number of cached exports.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether nothing has been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached exports, so the next exports reflect e.g. changed examples or
+    /// description files.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(direction: ExportDirection) -> CacheKey {
+        (
+            TypeId::of::<u8>(),
+            direction,
+            Naming::new(),
+            None,
+            ResultShape::new(),
+            OutputOptions::new(),
+        )
+    }
+
+    #[test]
+    fn test_entries_are_shared_per_key() {
+        let cache = ExportCache::new();
+        assert!(cache.is_empty());
+
+        let output = cache.entry(key(ExportDirection::Output));
+        assert!(Arc::ptr_eq(
+            &output,
+            &cache.entry(key(ExportDirection::Output))
+        ));
+        assert!(!Arc::ptr_eq(
+            &output,
+            &cache.entry(key(ExportDirection::Input))
+        ));
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
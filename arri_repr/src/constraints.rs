@@ -0,0 +1,121 @@
+use ronky_derive::Serializable as SerializableDerive;
+
+/// Represents the validation constraints of a value.
+///
+/// Arri schemas can't express ranges or patterns themselves, so the constraints are recorded
+/// in the metadata of the schema they apply to. Generators and documentation can surface them,
+/// and with the `validation` feature they are enforced by the derived `validate()` method.
+#[derive(Default, Debug, Clone, PartialEq, SerializableDerive)]
+#[arri_disable(metadata, nullable)]
+pub struct ConstraintsSchema {
+    /// The minimum (inclusive) numeric value.
+    pub min: Option<f64>,
+    /// The maximum (inclusive) numeric value.
+    pub max: Option<f64>,
+    /// The minimum length of a string or collection.
+    pub min_length: Option<u64>,
+    /// The maximum length of a string or collection.
+    pub max_length: Option<u64>,
+    /// A regular expression a string must match.
+    pub pattern: Option<String>,
+    /// A well-known format a string must follow, such as `email` or `uuid`.
+    pub format: Option<String>,
+}
+
+impl ConstraintsSchema {
+    /// Creates a new, empty `ConstraintsSchema`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `min` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum (inclusive) numeric value.
+    pub fn set_min(&mut self, min: f64) -> &mut Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the `max` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum (inclusive) numeric value.
+    pub fn set_max(&mut self, max: f64) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the `min_length` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_length` - The minimum length of a string or collection.
+    pub fn set_min_length(&mut self, min_length: u64) -> &mut Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Sets the `max_length` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - The maximum length of a string or collection.
+    pub fn set_max_length(&mut self, max_length: u64) -> &mut Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the `pattern` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A value that can be converted to a `String` containing a regular expression.
+    pub fn set_pattern(&mut self, pattern: impl ToString) -> &mut Self {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Sets the `format` field of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - A value that can be converted to a `String` naming the format.
+    pub fn set_format(&mut self, format: impl ToString) -> &mut Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Serializable;
+
+    #[test]
+    fn test_constraints_schema_serialization() {
+        let mut constraints = ConstraintsSchema::new();
+        constraints
+            .set_min(1.0)
+            .set_max(100.5)
+            .set_min_length(2)
+            .set_pattern("^[a-z]+$")
+            .set_format("email");
+
+        let serialized: serde_json::Value =
+            serde_json::from_str(&constraints.serialize().unwrap()).unwrap();
+
+        assert_eq!(
+            serialized,
+            serde_json::json!({
+                "min": 1,
+                "max": 100.5,
+                "minLength": 2,
+                "pattern": "^[a-z]+$",
+                "format": "email"
+            })
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arri_common::EnumTransformation;
+
+use crate::cache::{CacheKey, ExportCache};
+use crate::{
+    ExportDirection, Exportable, Naming, OutputOptions, ResultShape, SCHEMA_VERSION, SchemaError,
+    Serializable,
+};
+
+thread_local! {
+    /// The context of the export that is currently in progress on this thread.
+    static CONTEXT: RefCell<ExportContext> = RefCell::new(ExportContext::new());
+}
+
+/// The configuration and state of an export.
+///
+/// Exports made through [`ExportContext::export`] only depend on the context they are made
+/// with, so the same context always produces the same schema, regardless of the thread or of
+/// other exports in progress. [`Exportable::export`] uses the context of the export it is
+/// nested in, or the default context.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::{ExportContext, ExportDirection, GenericIds, Naming};
+///
+/// let context = ExportContext::new()
+///     .set_direction(ExportDirection::Input)
+///     .set_naming(Naming::new().set_generics(GenericIds::Of));
+/// let schema = context.export::<Vec<String>>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExportContext {
+    /// The direction data flows in for the exported schema.
+    direction: ExportDirection,
+    /// The strategy used to build the ids of exported types.
+    naming: Naming,
+    /// The casing of properties without an explicit `rename` or `rename_all`.
+    casing: Option<EnumTransformation>,
+    /// The representation of `Result<T, E>`.
+    result_shape: ResultShape,
+    /// The version of the Arri schema specification to target.
+    schema_version: &'static str,
+    /// The options the schemas are serialized with by [`ExportContext::serialize`].
+    output: OutputOptions,
+    /// The cache of exported schemas, shared with the clones of the context.
+    cache: Option<Arc<ExportCache>>,
+    /// The full paths of the types which are being exported, to detect recursion.
+    in_progress: HashSet<&'static str>,
+    /// The ids defined by the export with the path of their type, when they are collected.
+    defined_ids: Option<DefinedIds>,
+    /// The errors reported by the export, when it is fallible.
+    errors: Option<Vec<SchemaError>>,
+}
+
+impl Default for ExportContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExportContext {
+    /// Creates the default context: an output export with the default naming, targeting
+    /// [`SCHEMA_VERSION`].
+    pub fn new() -> Self {
+        Self {
+            direction: ExportDirection::Output,
+            naming: Naming::new(),
+            casing: None,
+            result_shape: ResultShape::new(),
+            schema_version: SCHEMA_VERSION,
+            output: OutputOptions::new(),
+            cache: None,
+            in_progress: HashSet::new(),
+            defined_ids: None,
+            errors: None,
+        }
+    }
+
+    /// Retrieves the configuration of the export that is currently in progress.
+    pub fn current() -> Self {
+        read(|context| Self {
+            in_progress: HashSet::new(),
+            defined_ids: None,
+            errors: None,
+            ..context.clone()
+        })
+    }
+
+    /// Sets the direction data flows in for the exported schema.
+    pub fn set_direction(mut self, direction: ExportDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the strategy used to build the ids of exported types.
+    pub fn set_naming(mut self, naming: Naming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Sets the casing of properties which have neither a `rename` nor a `rename_all`.
+    ///
+    /// The casing only applies to the schema, so the (de)serialization has to use the same
+    /// casing, e.g. with `#[serde(rename_all = "camelCase")]`.
+    pub fn set_casing(mut self, casing: EnumTransformation) -> Self {
+        self.casing = Some(casing);
+        self
+    }
+
+    /// Sets the representation of `Result<T, E>`.
+    pub fn set_result_shape(mut self, shape: ResultShape) -> Self {
+        self.result_shape = shape;
+        self
+    }
+
+    /// Sets the version of the Arri schema specification to target, which the documents of a
+    /// [`Registry`](crate::Registry) declare.
+    pub fn set_schema_version(mut self, version: &'static str) -> Self {
+        self.schema_version = version;
+        self
+    }
+
+    /// Sets the options the schemas are serialized with by [`ExportContext::serialize`].
+    pub fn set_output(mut self, output: OutputOptions) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Sets whether the schemas exported with [`ExportContext::export_shared`] and
+    /// [`ExportContext::serialize`] are cached.
+    ///
+    /// Enabling the cache on a context which already has one keeps the existing cache.
+    pub fn set_cached(mut self, cached: bool) -> Self {
+        self.cache = match cached {
+            true => self.cache.or_else(|| Some(Arc::new(ExportCache::new()))),
+            false => None,
+        };
+        self
+    }
+
+    /// Retrieves the direction data flows in for the exported schema.
+    pub fn direction(&self) -> ExportDirection {
+        self.direction
+    }
+
+    /// Retrieves the strategy used to build the ids of exported types.
+    pub fn naming(&self) -> Naming {
+        self.naming
+    }
+
+    /// Retrieves the casing of properties without an explicit name.
+    pub fn casing(&self) -> Option<EnumTransformation> {
+        self.casing.clone()
+    }
+
+    /// Retrieves the representation of `Result<T, E>`.
+    pub fn result_shape(&self) -> ResultShape {
+        self.result_shape
+    }
+
+    /// Retrieves the version of the Arri schema specification to target.
+    pub fn schema_version(&self) -> &'static str {
+        self.schema_version
+    }
+
+    /// Retrieves the options the schemas are serialized with.
+    pub fn output(&self) -> OutputOptions {
+        self.output
+    }
+
+    /// Retrieves the cache of the context, if it caches its exports.
+    pub fn cache(&self) -> Option<&ExportCache> {
+        self.cache.as_deref()
+    }
+
+    /// Exports `T` with this context.
+    ///
+    /// This always exports the type anew, see [`ExportContext::export_shared`] for cached
+    /// exports.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the export fails, see [`ExportContext::try_export`].
+    pub fn export<T: Exportable + ?Sized>(&self) -> Box<dyn Serializable> {
+        let context = Self {
+            in_progress: HashSet::new(),
+            defined_ids: None,
+            errors: None,
+            ..self.clone()
+        };
+        scoped(context, T::export)
+    }
+
+    /// Exports `T` with this context, without panicking when a modifier can't be applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the export, e.g. [`SchemaError::Unsupported`] when
+    /// metadata is set on a custom schema which doesn't support it.
+    pub fn try_export<T: Exportable + ?Sized>(&self) -> Result<Box<dyn Serializable>, SchemaError> {
+        let (schema, _, errors) = self.collecting::<T>();
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(schema),
+        }
+    }
+
+    /// Exports `T` with this context as a shared, immutable schema.
+    ///
+    /// With a cache (see [`ExportContext::set_cached`]), `T` is only exported the first time,
+    /// and later calls return the same schema. Schemas can't be sent between threads, so each
+    /// thread exports `T` once.
+    pub fn export_shared<T: Exportable + ?Sized + 'static>(&self) -> Arc<dyn Serializable> {
+        match &self.cache {
+            Some(cache) => cache
+                .entry(self.cache_key::<T>())
+                .schema(|| self.export::<T>()),
+            None => self.export::<T>().into(),
+        }
+    }
+
+    /// Exports `T` with this context and serializes the schema with its output options.
+    ///
+    /// With a cache, the serialized schema is reused by later calls, also on other threads, so
+    /// repeatedly serving a schema doesn't export nor serialize it again.
+    ///
+    /// # Returns
+    ///
+    /// The serialized schema, or `None` if the serialization fails.
+    pub fn serialize<T: Exportable + ?Sized + 'static>(&self) -> Option<Arc<str>> {
+        let Some(cache) = &self.cache else {
+            return self.output.serialize(&self.export::<T>()).map(Arc::from);
+        };
+
+        let entry = cache.entry(self.cache_key::<T>());
+        entry
+            .serialized
+            .get_or_init(|| {
+                let schema = entry.schema(|| self.export::<T>());
+                self.output.serialize(schema.as_ref()).map(Arc::from)
+            })
+            .clone()
+    }
+
+    /// Builds the key of `T` in the cache, which includes everything the export depends on.
+    fn cache_key<T: ?Sized + 'static>(&self) -> CacheKey {
+        (
+            TypeId::of::<T>(),
+            self.direction,
+            self.naming,
+            self.casing.clone(),
+            self.result_shape,
+            self.output,
+        )
+    }
+
+    /// Exports `T` with this context, and collects the ids of all types defined by the export.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the export.
+    pub(crate) fn export_collecting_ids<T: Exportable + ?Sized>(
+        &self,
+    ) -> Result<(Box<dyn Serializable>, DefinedIds), SchemaError> {
+        let (schema, defined, errors) = self.collecting::<T>();
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok((schema, defined)),
+        }
+    }
+
+    /// Exports `T` with this context, collecting the ids it defines and the errors it reports.
+    fn collecting<T: Exportable + ?Sized>(&self) -> Collected {
+        let context = Self {
+            in_progress: HashSet::new(),
+            defined_ids: Some(Vec::new()),
+            errors: Some(Vec::new()),
+            ..self.clone()
+        };
+        scoped(context, || {
+            let schema = T::export();
+            CONTEXT.with(|current| {
+                let mut current = current.borrow_mut();
+                let defined = current.defined_ids.take().unwrap_or_default();
+                let errors = current.errors.take().unwrap_or_default();
+                (schema, defined, errors)
+            })
+        })
+    }
+}
+
+/// The ids defined by an export, with the path of their type.
+pub(crate) type DefinedIds = Vec<(String, &'static str)>;
+
+/// A schema, the ids it defines and the errors reported while exporting it.
+type Collected = (Box<dyn Serializable>, DefinedIds, Vec<SchemaError>);
+
+/// Restores the previous context when dropped, even if the export panics.
+struct ContextGuard {
+    previous: Option<ExportContext>,
+    /// Whether the ids and errors collected by the scope belong to the previous context.
+    nested: bool,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        let Some(mut previous) = self.previous.take() else {
+            return;
+        };
+
+        CONTEXT.with(|current| {
+            let mut current = current.borrow_mut();
+            if self.nested {
+                if let (Some(defined), Some(nested)) =
+                    (previous.defined_ids.as_mut(), current.defined_ids.take())
+                {
+                    defined.extend(nested);
+                }
+                if let (Some(errors), Some(nested)) =
+                    (previous.errors.as_mut(), current.errors.take())
+                {
+                    errors.extend(nested);
+                }
+            }
+            *current = previous;
+        });
+    }
+}
+
+/// Runs `export` with the given context.
+fn scoped<R>(context: ExportContext, export: impl FnOnce() -> R) -> R {
+    let previous = CONTEXT.with(|current| current.replace(context));
+    let _guard = ContextGuard {
+        previous: Some(previous),
+        nested: false,
+    };
+    export()
+}
+
+/// Runs `export` with a modified copy of the current context.
+///
+/// The ids and errors collected while running `export` are kept by the current context.
+pub(crate) fn modified<R>(
+    modify: impl FnOnce(ExportContext) -> ExportContext,
+    export: impl FnOnce() -> R,
+) -> R {
+    let context = CONTEXT.with(|current| current.borrow().clone());
+    let context = ExportContext {
+        defined_ids: context.defined_ids.as_ref().map(|_| Vec::new()),
+        errors: context.errors.as_ref().map(|_| Vec::new()),
+        ..modify(context)
+    };
+    let previous = CONTEXT.with(|current| current.replace(context));
+    let _guard = ContextGuard {
+        previous: Some(previous),
+        nested: true,
+    };
+    export()
+}
+
+/// Reads the current context.
+pub(crate) fn read<R>(read: impl FnOnce(&ExportContext) -> R) -> R {
+    CONTEXT.with(|current| read(&current.borrow()))
+}
+
+/// Records an id defined by the current export, if ids are being collected.
+pub(crate) fn record_id(id: &str, path: &'static str) {
+    CONTEXT.with(|current| {
+        if let Some(defined) = current.borrow_mut().defined_ids.as_mut() {
+            defined.push((id.to_string(), path));
+        }
+    });
+}
+
+/// Reports an error of the current export, which [`ExportContext::try_export`] returns.
+///
+/// # Panics
+///
+/// Panics with the error if the export isn't fallible.
+pub fn report(error: SchemaError) {
+    let unreported = CONTEXT.with(|current| match current.borrow_mut().errors.as_mut() {
+        Some(errors) => {
+            errors.push(error);
+            None
+        }
+        None => Some(error),
+    });
+
+    if let Some(error) = unreported {
+        panic!("{}", error);
+    }
+}
+
+/// Marks a type as being exported, until the returned guard is dropped.
+///
+/// # Returns
+///
+/// `None` if the type is already being exported, which means it is recursive.
+pub(crate) fn enter(path: &'static str) -> Option<RecursionGuard> {
+    CONTEXT
+        .with(|current| current.borrow_mut().in_progress.insert(path))
+        .then(|| RecursionGuard(path))
+}
+
+/// Removes a type from the types being exported when dropped, even if its export panics.
+pub(crate) struct RecursionGuard(&'static str);
+
+impl Drop for RecursionGuard {
+    fn drop(&mut self) {
+        CONTEXT.with(|current| current.borrow_mut().in_progress.remove(self.0));
+    }
+}
+
+/// Applies the default casing of the current export to the name of a property.
+///
+/// This is used by `#[derive(Exported)]` for properties without an explicit name.
+pub fn property_name(name: &str) -> String {
+    match read(ExportContext::casing) {
+        Some(casing) => casing.apply(name),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_is_restored() {
+        let context = ExportContext::new()
+            .set_direction(ExportDirection::Input)
+            .set_casing(EnumTransformation::Camelcase)
+            .set_schema_version("v0.0.9");
+
+        scoped(context, || {
+            assert_eq!(ExportContext::current().direction(), ExportDirection::Input);
+            assert_eq!(
+                ExportContext::current().casing(),
+                Some(EnumTransformation::Camelcase)
+            );
+            assert_eq!(ExportContext::current().schema_version(), "v0.0.9");
+        });
+
+        assert_eq!(
+            ExportContext::current().direction(),
+            ExportDirection::Output
+        );
+        assert_eq!(ExportContext::current().casing(), None);
+        assert_eq!(ExportContext::current().schema_version(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_recursion_guard_is_released_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            let _guard = enter("crate::Foo").unwrap();
+            assert!(enter("crate::Foo").is_none());
+            // A failed enter doesn't release the type
+            assert!(enter("crate::Foo").is_none());
+            panic!("export failed");
+        });
+
+        assert!(result.is_err());
+        assert!(enter("crate::Foo").is_some());
+    }
+
+    #[test]
+    fn test_property_name() {
+        assert_eq!(property_name("first_name"), "first_name");
+
+        let context = ExportContext::new().set_casing(EnumTransformation::Camelcase);
+        scoped(context, || {
+            assert_eq!(property_name("first_name"), "firstName")
+        });
+    }
+}
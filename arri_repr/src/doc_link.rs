@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use crate::Exportable;
+
+/// An intra-doc link to a type in the docs of an exported item, e.g. ``[`Pet`]``.
+///
+/// `#[derive(Exported)]` resolves the link to the schema id of the linked type, or to its name
+/// if the type isn't [`Exportable`]. The choice is made by method resolution: called on a
+/// `&DocLink<T>`, the [`ExportableDocLink`] implementation on `DocLink<T>` takes precedence over
+/// the [`NamedDocLink`] implementation on `&DocLink<T>` when `T` is [`Exportable`].
+///
+/// ```ignore
+/// use ronky::{ExportableDocLink as _, NamedDocLink as _};
+///
+/// let id = (&ronky::DocLink::<Pet>::new("Pet")).schema_id();
+/// ```
+pub struct DocLink<T> {
+    name: &'static str,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> DocLink<T> {
+    /// Creates a link to `T`, falling back to `name` if `T` isn't exported.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Resolves a [`DocLink`] to an [`Exportable`] type to its schema id.
+pub trait ExportableDocLink {
+    /// The schema id of the linked type.
+    fn schema_id(&self) -> String;
+}
+
+impl<T: Exportable> ExportableDocLink for DocLink<T> {
+    fn schema_id(&self) -> String {
+        T::get_schema_id()
+    }
+}
+
+/// Resolves a [`DocLink`] to any other type to the name of the link.
+pub trait NamedDocLink {
+    /// The name of the linked type, as written in the link.
+    fn schema_id(&self) -> String;
+}
+
+impl<T> NamedDocLink for &DocLink<T> {
+    fn schema_id(&self) -> String {
+        self.name.to_string()
+    }
+}
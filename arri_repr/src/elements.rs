@@ -1,3 +1,5 @@
+use std::fmt;
+
 use crate::{MetadataSchema, Serializable, serializer::Serializer};
 
 /// Represents the schema for elements in arri
@@ -13,7 +15,7 @@ use crate::{MetadataSchema, Serializable, serializer::Serializer};
 ///   about the elements.
 /// * `is_nullable` - An optional boolean indicating whether the elements can
 ///   be null.
-#[derive(Debug, Eq)]
+#[derive(Debug)]
 pub struct ElementsSchema {
     pub elements: Box<dyn Serializable>,
     pub metadata: Option<MetadataSchema>,
@@ -32,26 +34,29 @@ impl ElementsSchema {
 
 impl PartialEq for ElementsSchema {
     fn eq(&self, other: &Self) -> bool {
-        self.elements.eq(&other.elements)
+        self.elements.as_ref() == other.elements.as_ref()
+            && self.metadata == other.metadata
+            && self.is_nullable == other.is_nullable
     }
 }
 
 impl Serializable for ElementsSchema {
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("elements", &self.elements)
             .set("metadata", &self.metadata)
             .set("isNullable", &self.is_nullable)
-            .build()
-            .into()
+            .finish()
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
-    fn set_nullable(&mut self, nullable: bool) {
+    fn try_set_nullable(&mut self, nullable: bool) -> Result<(), crate::SchemaError> {
         self.is_nullable = Some(nullable);
+        Ok(())
     }
 }
 
@@ -1,14 +1,19 @@
+use std::fmt;
+
 use crate::{MetadataSchema, Serializable, serializer::Serializer};
 
 /// Represents an empty schema in the Arri system.
 ///
 /// This struct is used as a placeholder or default schema
 /// when no specific schema is required. It includes optional
-/// metadata for additional context.
-#[derive(Debug, PartialEq, Eq, Default)]
+/// metadata for additional context. It is nullable unless stated otherwise.
+#[derive(Debug, PartialEq)]
 pub struct EmptySchema {
     /// Optional metadata associated with the schema.
     metadata: Option<MetadataSchema>,
+
+    /// Indicates whether the schema allows null values.
+    is_nullable: Option<bool>,
 }
 
 impl EmptySchema {
@@ -17,16 +22,30 @@ impl EmptySchema {
     }
 }
 
+impl Default for EmptySchema {
+    fn default() -> Self {
+        Self {
+            metadata: None,
+            is_nullable: Some(true),
+        }
+    }
+}
+
 impl Serializable for EmptySchema {
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("metadata", &self.metadata)
-            .set("isNullable", &true)
-            .build()
-            .into()
+            .set("isNullable", &self.is_nullable)
+            .finish()
+    }
+
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), crate::SchemaError> {
+        self.is_nullable = Some(is_nullable);
+        Ok(())
     }
 }
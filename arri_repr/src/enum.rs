@@ -1,10 +1,12 @@
+use std::fmt;
+
 use crate::{EnumTransformation, MetadataSchema, Serializable, serializer::Serializer};
 
 /// Represents a schema for enumerations in the Arri system.
 ///
 /// This struct defines an enumeration schema, including the list of
 /// possible values, optional metadata, transformations, and nullability.
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, PartialEq, Default)]
 pub struct EnumSchema {
     /// A list of possible values for the enumeration.
     pub r#enum: Vec<String>,
@@ -36,21 +38,22 @@ impl EnumSchema {
 }
 
 impl Serializable for EnumSchema {
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("enum", &self.r#enum)
             .set("metadata", &self.metadata)
             .set("isNullable", &self.is_nullable)
-            .build()
-            .into()
+            .finish()
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
-    fn set_nullable(&mut self, is_nullable: bool) {
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), crate::SchemaError> {
         self.is_nullable = Some(is_nullable);
+        Ok(())
     }
 }
 
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::{IdCollision, Serializable};
+
+/// A modifier which can be applied to an exported schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    /// Metadata, such as a description or an id.
+    Metadata,
+    /// Whether `null` is accepted.
+    Nullable,
+    /// A new name for the schema.
+    Rename,
+}
+
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Metadata => "metadata",
+            Self::Nullable => "nullable",
+            Self::Rename => "rename",
+        })
+    }
+}
+
+/// An error which prevents a type from being exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    /// A schema form doesn't support a modifier, e.g. a custom schema without metadata.
+    Unsupported {
+        /// The modifier which was applied.
+        modifier: Modifier,
+        /// The Rust type of the schema the modifier was applied to.
+        schema: &'static str,
+        /// The serialized schema the modifier was applied to.
+        serialized: Option<String>,
+    },
+    /// Two distinct types export with the same id.
+    IdCollision(IdCollision),
+    /// The keys of a map don't have a schema which can be encoded as a string, e.g. a struct.
+    InvalidMapKey {
+        /// The Rust type of the keys.
+        key: &'static str,
+        /// The serialized schema of the keys.
+        serialized: Option<String>,
+    },
+    /// Two implementors of a trait object are registered with the same tag.
+    DuplicateTag {
+        /// The Rust type of the trait object.
+        object: &'static str,
+        /// The tag both implementors are registered with.
+        tag: &'static str,
+        /// The Rust paths of both implementors.
+        implementors: (&'static str, &'static str),
+    },
+    /// The type-level example of a type can't be encoded as JSON.
+    InvalidExample {
+        /// The Rust type of the example.
+        ty: &'static str,
+        /// The error of the serializer.
+        error: String,
+    },
+}
+
+impl SchemaError {
+    /// Creates the error for a modifier which isn't supported by a schema.
+    pub fn unsupported<S: Serializable + ?Sized>(modifier: Modifier, schema: &S) -> Self {
+        Self::Unsupported {
+            modifier,
+            schema: std::any::type_name::<S>(),
+            serialized: schema.serialize(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported {
+                modifier,
+                schema,
+                serialized,
+            } => write!(
+                f,
+                "`{}` can't be applied to a `{}` schema ({})",
+                modifier,
+                schema,
+                serialized.as_deref().unwrap_or("undefined")
+            ),
+            Self::IdCollision(collision) => collision.fmt(f),
+            Self::InvalidMapKey { key, serialized } => write!(
+                f,
+                "`{}` can't be the key of a map, as its schema ({}) can't be encoded as a string",
+                key,
+                serialized.as_deref().unwrap_or("undefined")
+            ),
+            Self::DuplicateTag {
+                object,
+                tag,
+                implementors: (first, second),
+            } => write!(
+                f,
+                "The tag `{}` of `{}` is used by both `{}` and `{}`",
+                tag, object, first, second
+            ),
+            Self::InvalidExample { ty, error } => {
+                write!(f, "The example of `{}` can't be encoded: {}", ty, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unsupported { .. }
+            | Self::InvalidMapKey { .. }
+            | Self::DuplicateTag { .. }
+            | Self::InvalidExample { .. } => None,
+            Self::IdCollision(collision) => Some(collision),
+        }
+    }
+}
+
+impl From<IdCollision> for SchemaError {
+    fn from(collision: IdCollision) -> Self {
+        Self::IdCollision(collision)
+    }
+}
@@ -3,10 +3,12 @@
 /// This module defines the `Exportable` trait, which allows types to be
 /// converted into serializable schemas. It also includes macros and utilities
 /// for handling generic types, type schemas, and feature-specific schemas.
+use crate::context::{self, ExportContext};
 use crate::{
-    EmptySchema, PropertiesSchema, RefSchema, TaggedUnionSchema, ValuesSchema, type_utils,
+    EmptySchema, EnumSchema, KeySchema, PropertiesSchema, RefSchema, TaggedUnionSchema,
+    ValuesSchema, type_utils,
 };
-use crate::{Serializable, TypeSchema, Types, elements::ElementsSchema};
+use crate::{MapKey, SchemaError, Serializable, TypeSchema, Types, elements::ElementsSchema};
 use indexmap::{IndexMap, IndexSet};
 use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
@@ -26,9 +28,111 @@ use std::sync::atomic::{
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant, SystemTime};
 
+/// The direction data flows in for an exported schema.
+///
+/// Fields marked with `#[arri(read_only)]` are only part of the output schema, while fields
+/// marked with `#[arri(write_only)]` are only part of the input schema.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportDirection {
+    /// The schema of data that is sent, e.g. responses. This is the default.
+    #[default]
+    Output,
+    /// The schema of data that is received, e.g. request bodies.
+    Input,
+}
+
+/// Retrieves the direction of the export that is currently in progress.
+pub fn export_direction() -> ExportDirection {
+    context::read(ExportContext::direction)
+}
+
+/// Runs `export` with the given export direction.
+fn with_export_direction(
+    direction: ExportDirection,
+    export: impl FnOnce() -> Box<dyn Serializable>,
+) -> Box<dyn Serializable> {
+    context::modified(|context| context.set_direction(direction), export)
+}
+
+/// Exports the schema of a map, recording the schema of its keys unless they are plain strings.
+///
+/// Keys are encoded as strings, so only strings, numbers, booleans and enums can be keys. Keys
+/// must implement [`MapKey`], and those whose schema is another form, such as a struct with a
+/// hand-written `MapKey` implementation, are reported as a [`SchemaError::InvalidMapKey`]. A
+/// reference is never a valid key: enums without fields can't contain themselves, so it refers
+/// to a type which does, such as a struct.
+fn map_schema<K: MapKey + Exportable, V: Exportable + ?Sized>() -> ValuesSchema {
+    let mut schema = ValuesSchema::new(Box::new(V::export()));
+
+    let mut key = K::export();
+    while key.is::<Box<dyn Serializable>>() {
+        key = *key.downcast::<Box<dyn Serializable>>().unwrap();
+    }
+
+    let key = match key.downcast::<TypeSchema>() {
+        Ok(ty) if *ty == TypeSchema::new(Types::String) => return schema,
+        Ok(ty) => KeySchema::Type(*ty),
+        Err(key) => match key.downcast::<EnumSchema>() {
+            Ok(r#enum) => KeySchema::Enum(*r#enum),
+            Err(key) => {
+                context::report(SchemaError::InvalidMapKey {
+                    key: std::any::type_name::<K>(),
+                    serialized: key.serialize(),
+                });
+                return schema;
+            }
+        },
+    };
+    schema.set_key_schema(key);
+
+    schema
+}
+
 thread_local! {
-    /// Tracks types currently being exported to prevent infinite recursion.
-    static RECURSION_TRACKER: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    /// The types whose directionality is being checked, by their full path.
+    static CHECKING_DIRECTION: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+/// Checks whether `T` is directional (see [`Exportable::is_directional`]), with `nested`
+/// checking the types nested in it.
+///
+/// A recursive type which is already being checked doesn't count as directional, as the check in
+/// progress covers its fields. This is called by `#[derive(Exported)]`.
+pub fn is_directional_with<T: ?Sized>(nested: impl FnOnce() -> bool) -> bool {
+    /// Removes the type from the checks in progress, even if a check panics.
+    struct Guard(&'static str);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CHECKING_DIRECTION.with_borrow_mut(|checking| checking.remove(self.0));
+        }
+    }
+
+    let path = std::any::type_name::<T>();
+    if !CHECKING_DIRECTION.with_borrow_mut(|checking| checking.insert(path)) {
+        return false;
+    }
+    let _guard = Guard(path);
+    nested()
+}
+
+/// Builds the id or type name of `T`, with an `Input` suffix in an input export of a
+/// directional type.
+///
+/// `name` is built as in an output export, so the generic arguments in it don't get a suffix of
+/// their own: `Page<User>` is `PageUserInput` rather than `PageUserInputInput`. This is called by
+/// `#[derive(Exported)]`.
+pub fn directional_name<T: Exportable + ?Sized>(name: impl FnOnce() -> String) -> String {
+    match export_direction() {
+        ExportDirection::Input if T::is_directional() => {
+            let name = context::modified(
+                |context| context.set_direction(ExportDirection::Output),
+                name,
+            );
+            format!("{}Input", name)
+        }
+        _ => name(),
+    }
 }
 
 /// A trait for types that can be exported into Arri schemas.
@@ -38,13 +142,71 @@ pub trait Exportable {
         type_utils::get_type_name::<Self>()
     }
 
+    /// Retrieves the id of the schema of the type, which is also used to reference it.
+    ///
+    /// `#[derive(Exported)]` builds the id with the [`naming`](crate::naming) of the current
+    /// export, other types derive it from [`Exportable::get_type_name`].
+    fn get_schema_id() -> String {
+        type_utils::get_type_name_from(Self::get_type_name())
+    }
+
     /// Exports the type into a serializable schema.
     ///
     /// This method ensures that recursive types are handled correctly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modifier can't be applied to a schema, outside of a fallible export (see
+    /// [`Exportable::try_export`]).
     fn export() -> Box<dyn Serializable> {
         Self::export_with_recursion_check()
     }
 
+    /// Exports the type with the configuration of the current [`ExportContext`], returning an
+    /// error instead of panicking when a modifier can't be applied to a schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`SchemaError`] reported by the export.
+    fn try_export() -> Result<Box<dyn Serializable>, SchemaError> {
+        ExportContext::current().try_export::<Self>()
+    }
+
+    /// Retrieves the serialized schema of the type with the default [`ExportContext`], which is
+    /// only built the first time.
+    ///
+    /// `#[derive(Exported)]` implements this for types without generic parameters, as they have
+    /// a single schema. Other types return `None`, and are exported with [`Exportable::export`]
+    /// instead. Unlike an [`ExportContext`], this ignores the context of an export in progress.
+    fn schema_json() -> Option<&'static str> {
+        None
+    }
+
+    /// Exports the schema of the type as it is received, e.g. in a request body.
+    ///
+    /// Fields marked with `#[arri(read_only)]` are left out, and types with such fields get an
+    /// `Input` suffix in their id (e.g. `UserInput`).
+    fn export_input() -> Box<dyn Serializable> {
+        with_export_direction(ExportDirection::Input, Self::export)
+    }
+
+    /// Exports the schema of the type as it is sent, e.g. in a response.
+    ///
+    /// Fields marked with `#[arri(write_only)]` are left out. This is the same as
+    /// [`Exportable::export`] outside of an input export.
+    fn export_output() -> Box<dyn Serializable> {
+        with_export_direction(ExportDirection::Output, Self::export)
+    }
+
+    /// Whether the input and output schemas of the type differ, because the type or a type nested
+    /// in it has `#[arri(read_only)]` or `#[arri(write_only)]` fields.
+    ///
+    /// Directional types get an `Input` suffix in their id when they are exported as input, see
+    /// [`Exportable::export_input`].
+    fn is_directional() -> bool {
+        false
+    }
+
     /// Internal method for exporting the type.
     ///
     /// This method should be implemented by types to define their specific
@@ -52,34 +214,14 @@ pub trait Exportable {
     fn export_internal() -> impl Serializable;
 
     /// Exports the type with recursion tracking to prevent infinite loops.
+    ///
+    /// Types are tracked by their full path in the [`ExportContext`] of the export, a type which
+    /// is already being exported is referenced by its id instead.
     fn export_with_recursion_check() -> Box<dyn Serializable> {
-        let type_name = Self::get_type_name();
-
-        let is_recursive = RECURSION_TRACKER.with(|tracker| {
-            let mut tracker = tracker.borrow_mut();
-            let is_recursive = tracker.contains(&type_name);
-
-            if !is_recursive {
-                // Add the type to the recursion tracker.
-                tracker.insert(type_name.clone());
-            }
-
-            is_recursive
-        });
-
-        if is_recursive {
-            return Box::new(RefSchema::new(type_utils::get_type_name_from(type_name)));
+        match context::enter(std::any::type_name::<Self>()) {
+            Some(_guard) => Box::new(Self::export_internal()),
+            None => Box::new(RefSchema::new(Self::get_schema_id())),
         }
-
-        let result = Self::export_internal();
-
-        // Remove the type from the recursion tracker after exporting.
-        RECURSION_TRACKER.with(|tracker| {
-            let mut tracker = tracker.borrow_mut();
-            tracker.remove(&type_name);
-        });
-
-        Box::new(result)
     }
 }
 
@@ -160,7 +302,7 @@ macro_rules! exportable {
     // --- Generic implementation parsers ---
     // Generic implementation with expression - with trait bounds
     (@parse_impls $type:ident < $($type_param:ident $(: $trait_bound:path)?),* $(,)? > => $implementation:expr, $($rest:tt)*) => {
-        impl<$($type_param: 'static + Exportable $(+ $trait_bound)?),*> Exportable for $type<$($type_param),*> {
+        impl<$($type_param: Exportable $(+ $trait_bound)?),*> Exportable for $type<$($type_param),*> {
             fn export_internal() -> impl Serializable {
                 $implementation
             }
@@ -170,6 +312,9 @@ macro_rules! exportable {
                     vec![$($type_param::get_type_name()),*].join("")
                 )
             }
+            fn is_directional() -> bool {
+                false $(|| $type_param::is_directional())*
+            }
         }
         exportable!(@parse_impls $($rest)*);
     };
@@ -240,7 +385,13 @@ exportable! {
     },
     generic: {
         // Ignored types
-        Option<T> => T::export(), // Option is a special case, this gets handled in the proc macro
+        // Fields of type `Option` are optional properties instead, the proc macro exports the
+        // type inside the `Option` for them
+        Option<T> => {
+            let mut schema = T::export();
+            schema.set_nullable(true);
+            schema
+        },
         Rc<T> => T::export(),
         Arc<T> => T::export(),
         Cell<T> => T::export(),
@@ -252,15 +403,17 @@ exportable! {
         // General exports
         Box<T> => T::export_with_recursion_check(),
         Result<T, E> => {
+            let shape = context::read(ExportContext::result_shape);
             let mut schema = TaggedUnionSchema::new();
             let mut ok_props = PropertiesSchema::new();
             let mut err_props = PropertiesSchema::new();
 
-            ok_props.set_property("value", Box::new(T::export()));
-            err_props.set_property("value", Box::new(E::export()));
+            ok_props.set_property(shape.ok_property(), Box::new(T::export()));
+            err_props.set_property(shape.err_property(), Box::new(E::export()));
 
-            schema.add_mapping("Ok", Box::new(ok_props));
-            schema.add_mapping("Err", Box::new(err_props));
+            schema.set_discriminator(shape.discriminator());
+            schema.add_mapping(shape.ok(), Box::new(ok_props));
+            schema.add_mapping(shape.err(), Box::new(err_props));
             schema
         },
 
@@ -274,9 +427,9 @@ exportable! {
         BinaryHeap<T> => ElementsSchema::new(Box::new(T::export())),
 
         // Values Schema's
-        HashMap<K: ToString, V> => ValuesSchema::new(Box::new(V::export())),
-        BTreeMap<K: ToString, V> => ValuesSchema::new(Box::new(V::export())),
-        IndexMap<K: ToString, V> => ValuesSchema::new(Box::new(V::export())),
+        HashMap<K: MapKey, V> => map_schema::<K, V>(),
+        BTreeMap<K: MapKey, V> => map_schema::<K, V>(),
+        IndexMap<K: MapKey, V> => map_schema::<K, V>(),
         IndexSet<T> => ElementsSchema::new(Box::new(T::export())),
     },
     features: {
@@ -371,7 +524,7 @@ exportable! {
 
             exportable! {
                 generic: {
-                    DashMap<K: ToString, V> => ValuesSchema::new(Box::new(V::export())),
+                    DashMap<K: MapKey, V> => map_schema::<K, V>(),
                     DashSet<T> => ElementsSchema::new(Box::new(T::export())),
                 }
             }
@@ -0,0 +1,115 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::Serializable;
+
+/// Represents the value of a custom metadata extension.
+///
+/// Extensions attach annotations which Arri doesn't define itself, such as the owning team or a
+/// PII classification, to the metadata of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExtensionValue {
+    /// A string value.
+    String(String),
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A nested schema, such as the schema of the keys of a map. It is shared between threads
+    /// like the rest of the metadata.
+    Schema(Arc<dyn Serializable + Send + Sync>),
+}
+
+impl ExtensionValue {
+    /// Retrieves the nested schema as a `T`, if the value is one.
+    pub fn downcast_schema<T: Serializable>(&self) -> Option<&T> {
+        match self {
+            Self::Schema(schema) => (schema.as_ref() as &dyn Serializable).downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl Serializable for ExtensionValue {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        match self {
+            Self::String(value) => value.write_to(out),
+            Self::Bool(value) => value.write_to(out),
+            Self::Int(value) => value.write_to(out),
+            Self::Float(value) => value.write_to(out),
+            Self::Schema(schema) => schema.write_to(out),
+        }
+    }
+}
+
+impl From<String> for ExtensionValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for ExtensionValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<bool> for ExtensionValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for ExtensionValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<f64> for ExtensionValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EmptySchema, EnumSchema, KeySchema, MetadataSchema, RefSchema, TypeSchema};
+
+    #[test]
+    fn test_schema_nodes_are_thread_safe() {
+        fn assert_thread_safe<T: Send + Sync>() {}
+
+        assert_thread_safe::<ExtensionValue>();
+        assert_thread_safe::<MetadataSchema>();
+        assert_thread_safe::<TypeSchema>();
+        assert_thread_safe::<EnumSchema>();
+        assert_thread_safe::<RefSchema>();
+        assert_thread_safe::<EmptySchema>();
+        assert_thread_safe::<KeySchema>();
+    }
+
+    #[test]
+    fn test_extension_value_serialization() {
+        assert_eq!(
+            ExtensionValue::from("billing").serialize(),
+            Some("\"billing\"".to_string())
+        );
+        assert_eq!(
+            ExtensionValue::from(true).serialize(),
+            Some("true".to_string())
+        );
+        assert_eq!(ExtensionValue::from(-3).serialize(), Some("-3".to_string()));
+        assert_eq!(
+            ExtensionValue::from(0.5).serialize(),
+            Some("0.5".to_string())
+        );
+        assert_eq!(
+            ExtensionValue::from(f64::NAN).serialize(),
+            Some("null".to_string())
+        );
+    }
+}
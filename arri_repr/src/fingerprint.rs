@@ -0,0 +1,129 @@
+//! Semantic comparison and fingerprints of schemas.
+//!
+//! Both are defined over the canonical form of a schema (see
+//! [`OutputOptions::set_canonical`]), so they don't depend on the order properties are declared
+//! in. The fingerprint is stable across platforms and releases of Rust, so a client and a server
+//! can compare the hashes of their schemas during a handshake instead of exchanging the whole
+//! document.
+//!
+//! # Example
+//!
+//! ```
+//! use arri_repr::{Exportable, fingerprint::Fingerprint};
+//!
+//! let fingerprint = Fingerprint::of(&Vec::<String>::export());
+//! assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+//! ```
+
+use std::fmt::{self, Write};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::{OutputOptions, Serializable};
+
+/// The canonical form of a schema, including its documentation.
+const CANONICAL: OutputOptions = OutputOptions::new().set_canonical(true);
+
+/// The canonical form of a schema, without descriptions and deprecation notes.
+const STRUCTURAL: OutputOptions = OutputOptions::new().set_canonical(true).set_stripped(true);
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A stable hash of the canonical form of a schema.
+///
+/// Fingerprints are displayed and parsed as 16 lowercase hexadecimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Computes the fingerprint of a schema, including its documentation.
+    pub fn of(schema: &(impl Serializable + ?Sized)) -> Self {
+        Self::with(CANONICAL, schema)
+    }
+
+    /// Computes the fingerprint of the structure of a schema, ignoring its descriptions and
+    /// deprecation notes. Documentation changes don't affect this fingerprint.
+    pub fn of_structure(schema: &(impl Serializable + ?Sized)) -> Self {
+        Self::with(STRUCTURAL, schema)
+    }
+
+    /// Hashes the schema while it is written, without building the serialized schema.
+    fn with(options: OutputOptions, schema: &(impl Serializable + ?Sized)) -> Self {
+        let mut hasher = Fnv(FNV_OFFSET);
+        // Writing to the hasher can't fail
+        let _ = options.write_to(schema, &mut hasher);
+        Self(hasher.0)
+    }
+
+    /// Retrieves the hash as a number.
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16).map(Self)
+    }
+}
+
+/// A 64-bit FNV-1a hasher, which is fed the serialized schema.
+struct Fnv(u64);
+
+impl Write for Fnv {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+        Ok(())
+    }
+}
+
+/// Compares the structure of two schemas, ignoring descriptions and deprecation notes.
+///
+/// Use `==` on `dyn Serializable` to compare the documentation as well.
+pub fn eq_ignoring_docs(
+    a: &(impl Serializable + ?Sized),
+    b: &(impl Serializable + ?Sized),
+) -> bool {
+    STRUCTURAL.serialize(a) == STRUCTURAL.serialize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv_reference_values() {
+        let hash = |input: &str| {
+            let mut hasher = Fnv(FNV_OFFSET);
+            hasher.write_str(input).unwrap();
+            hasher.0
+        };
+
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash("foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_fingerprint_display() {
+        let fingerprint = Fingerprint(0xab);
+        assert_eq!(fingerprint.to_string(), "00000000000000ab");
+        assert_eq!("00000000000000ab".parse(), Ok(fingerprint));
+        assert!("not a fingerprint".parse::<Fingerprint>().is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use std::any::{TypeId, type_name};
+use std::sync::{Mutex, Once, PoisonError};
+
+use crate::{Exportable, SchemaError, Serializable, TaggedUnionSchema, context};
+
+/// The discriminator of trait objects, unless `#[ronky::polymorphic(discriminator = "...")]`
+/// sets another one.
+pub const DEFAULT_DISCRIMINATOR: &str = "type";
+
+/// A type which can be sent as the trait object `T`, e.g. `dyn Action`.
+///
+/// `#[ronky::implementor(tag = "email")]` implements this for the type of an
+/// `impl Action for SendEmail` block, and registers the type through `inventory`. A
+/// `Box<dyn Action>` then exports as a [`TaggedUnionSchema`] over every registered implementor,
+/// see [`export_implementors`].
+pub trait Implementor<T: ?Sized + 'static>: Exportable + 'static {
+    /// The value of the discriminator for this type.
+    const TAG: &'static str;
+
+    /// Converts the value into the trait object.
+    fn into_object(self: Box<Self>) -> Box<T>;
+}
+
+/// An implementor of a trait object, as it was registered.
+#[derive(Clone, Copy)]
+struct Registration {
+    /// The trait object, e.g. `dyn Action`.
+    object: TypeId,
+    /// The value of the discriminator.
+    tag: &'static str,
+    /// The Rust path of the implementor.
+    implementor: &'static str,
+    /// Exports the schema of the implementor.
+    export: fn() -> Box<dyn Serializable>,
+    /// Whether the input schema of the implementor differs from its output schema.
+    directional: fn() -> bool,
+}
+
+/// The implementors of all trait objects, in the order they were registered.
+static IMPLEMENTORS: Mutex<Vec<Registration>> = Mutex::new(Vec::new());
+
+/// A function registering an implementor, submitted by `#[ronky::implementor]` through
+/// `inventory`. Not part of the public API.
+#[doc(hidden)]
+pub struct SubmittedImplementor(pub fn());
+
+inventory::collect!(SubmittedImplementor);
+
+/// Registers the implementors submitted by `#[ronky::implementor]`, once.
+///
+/// This is called before looking up implementors, so they are registered before they are used.
+#[doc(hidden)]
+pub fn register_submitted_implementors() {
+    static SUBMITTED: Once = Once::new();
+    SUBMITTED.call_once(|| {
+        for submitted in inventory::iter::<SubmittedImplementor> {
+            (submitted.0)();
+        }
+    });
+}
+
+/// Registers `I` as an implementor of the trait object `T`.
+///
+/// This is called by `#[ronky::implementor]`, registering a type twice has no effect.
+pub fn register_implementor<T: ?Sized + 'static, I: Implementor<T>>() {
+    let mut implementors = IMPLEMENTORS.lock().unwrap_or_else(PoisonError::into_inner);
+    let object = TypeId::of::<T>();
+    let implementor = type_name::<I>();
+
+    if !implementors
+        .iter()
+        .any(|r| r.object == object && r.implementor == implementor)
+    {
+        implementors.push(Registration {
+            object,
+            tag: I::TAG,
+            implementor,
+            export: I::export,
+            directional: I::is_directional,
+        });
+    }
+}
+
+/// Retrieves the tags and Rust paths of the registered implementors of the trait object `T`,
+/// sorted by their tag.
+pub fn implementors<T: ?Sized + 'static>() -> Vec<(&'static str, &'static str)> {
+    registrations::<T>()
+        .into_iter()
+        .map(|r| (r.tag, r.implementor))
+        .collect()
+}
+
+/// Whether the input schema of any registered implementor of the trait object `T` differs from
+/// its output schema, see [`Exportable::is_directional`].
+pub fn implementors_are_directional<T: ?Sized + 'static>() -> bool {
+    registrations::<T>()
+        .iter()
+        .any(|registration| (registration.directional)())
+}
+
+/// Exports the trait object `T` as a tagged union over its registered implementors.
+///
+/// The variants are sorted by their tag, as the order of registration isn't stable. Two
+/// implementors with the same tag are reported as a [`SchemaError::DuplicateTag`].
+///
+/// # Arguments
+///
+/// * `discriminator` - The property which holds the tag of the implementor.
+pub fn export_implementors<T: ?Sized + 'static>(discriminator: &str) -> TaggedUnionSchema {
+    let mut schema = TaggedUnionSchema::new();
+    schema.set_discriminator(discriminator);
+
+    // The lock is released before exporting, as implementors may contain the trait object
+    let registrations = registrations::<T>();
+    for (idx, registration) in registrations.iter().enumerate() {
+        if idx > 0 && registrations[idx - 1].tag == registration.tag {
+            context::report(SchemaError::DuplicateTag {
+                object: type_name::<T>(),
+                tag: registration.tag,
+                implementors: (registrations[idx - 1].implementor, registration.implementor),
+            });
+            continue;
+        }
+        schema.add_mapping(registration.tag, (registration.export)());
+    }
+
+    schema
+}
+
+/// Retrieves the registrations of the trait object `T`, sorted by their tag.
+fn registrations<T: ?Sized + 'static>() -> Vec<Registration> {
+    register_submitted_implementors();
+    let object = TypeId::of::<T>();
+    let mut registrations = IMPLEMENTORS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .filter(|r| r.object == object)
+        .copied()
+        .collect::<Vec<_>>();
+    registrations.sort_by_key(|r| (r.tag, r.implementor));
+    registrations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExportContext, TypeSchema, Types};
+
+    trait Shape {}
+
+    struct Circle;
+    struct Square;
+
+    impl Shape for Circle {}
+    impl Shape for Square {}
+
+    impl Exportable for Circle {
+        fn export_internal() -> impl Serializable {
+            TypeSchema::new(Types::Float64)
+        }
+    }
+
+    impl Exportable for Square {
+        fn export_internal() -> impl Serializable {
+            TypeSchema::new(Types::Uint32)
+        }
+    }
+
+    impl Implementor<dyn Shape> for Circle {
+        const TAG: &'static str = "circle";
+
+        fn into_object(self: Box<Self>) -> Box<dyn Shape> {
+            self
+        }
+    }
+
+    impl Implementor<dyn Shape> for Square {
+        const TAG: &'static str = "square";
+
+        fn into_object(self: Box<Self>) -> Box<dyn Shape> {
+            self
+        }
+    }
+
+    #[test]
+    fn test_implementors_are_sorted_by_tag() {
+        register_implementor::<dyn Shape, Square>();
+        register_implementor::<dyn Shape, Circle>();
+        register_implementor::<dyn Shape, Square>();
+
+        assert_eq!(
+            implementors::<dyn Shape>(),
+            [
+                ("circle", type_name::<Circle>()),
+                ("square", type_name::<Square>())
+            ]
+        );
+        assert_eq!(
+            export_implementors::<dyn Shape>("kind").serialize(),
+            Some(
+                r#"{"discriminator":"kind","mapping":{"circle":{"type":"float64"},"square":{"type":"uint32"}}}"#
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_duplicate_tags_are_reported() {
+        trait Figure {}
+        impl Figure for Circle {}
+        impl Figure for Square {}
+
+        impl Implementor<dyn Figure> for Circle {
+            const TAG: &'static str = "round";
+
+            fn into_object(self: Box<Self>) -> Box<dyn Figure> {
+                self
+            }
+        }
+
+        impl Implementor<dyn Figure> for Square {
+            const TAG: &'static str = "round";
+
+            fn into_object(self: Box<Self>) -> Box<dyn Figure> {
+                self
+            }
+        }
+
+        struct Figures;
+
+        impl Exportable for Figures {
+            fn export_internal() -> impl Serializable {
+                export_implementors::<dyn Figure>(DEFAULT_DISCRIMINATOR)
+            }
+        }
+
+        register_implementor::<dyn Figure, Circle>();
+        register_implementor::<dyn Figure, Square>();
+
+        assert_eq!(
+            ExportContext::new().try_export::<Figures>().unwrap_err(),
+            SchemaError::DuplicateTag {
+                object: type_name::<dyn Figure>(),
+                tag: "round",
+                implementors: (type_name::<Circle>(), type_name::<Square>()),
+            }
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::{Serializable, serializer::Serializer};
+
+/// The metadata extension which lists the discriminants of an integer-backed enum.
+pub const DISCRIMINANTS_EXTENSION: &str = "discriminants";
+
+/// An enum which is sent as the integer discriminant of its variants.
+///
+/// `#[derive(Exported)]` implements this for enums with `#[arri(repr = "int")]`, which export as
+/// the integer type of their `#[repr(...)]`. [`IntEnum::from_repr`] accepts exactly the values
+/// [`IntEnum::to_repr`] produces.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::IntEnum;
+///
+/// #[derive(Debug, PartialEq)]
+/// #[repr(u8)]
+/// enum Priority {
+///     Low = 1,
+///     High = 5,
+/// }
+///
+/// impl IntEnum for Priority {
+///     type Repr = u8;
+///
+///     fn to_repr(&self) -> u8 {
+///         match self {
+///             Self::Low => 1,
+///             Self::High => 5,
+///         }
+///     }
+///
+///     fn from_repr(value: u8) -> Option<Self> {
+///         match value {
+///             1 => Some(Self::Low),
+///             5 => Some(Self::High),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Priority::from_repr(Priority::High.to_repr()), Some(Priority::High));
+/// assert_eq!(Priority::from_repr(2), None);
+/// ```
+pub trait IntEnum: Sized {
+    /// The integer type of the discriminants.
+    type Repr;
+
+    /// Encodes the variant as its discriminant.
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Decodes a discriminant, returning `None` if no variant has it.
+    fn from_repr(value: Self::Repr) -> Option<Self>;
+}
+
+/// The names and discriminants of the variants of an integer-backed enum, in declaration order.
+///
+/// Serialized as `[{"name": "Low", "value": 1}, ...]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Discriminants(Vec<Discriminant>);
+
+/// A variant of an integer-backed enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Discriminant {
+    name: String,
+    value: i128,
+}
+
+impl Discriminants {
+    /// Creates an empty listing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variant to the listing.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the variant.
+    /// * `value` - The discriminant of the variant.
+    pub fn add_variant(&mut self, name: impl ToString, value: impl Into<i128>) -> &mut Self {
+        self.0.push(Discriminant {
+            name: name.to_string(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Iterates over the names and discriminants of the variants.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i128)> {
+        self.0
+            .iter()
+            .map(|variant| (variant.name.as_str(), variant.value))
+    }
+}
+
+impl Serializable for Discriminants {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.0.write_to(out)
+    }
+}
+
+impl Serializable for Discriminant {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
+            .set("name", &self.name)
+            .set("value", &self.value)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TypeSchema, Types};
+
+    #[test]
+    fn test_discriminants_serialization() {
+        let mut discriminants = Discriminants::new();
+        discriminants.add_variant("Low", 1).add_variant("Debt", -5);
+
+        let mut schema = TypeSchema::new(Types::Int8);
+        schema.set_discriminants(discriminants.clone());
+
+        assert_eq!(schema.discriminants(), Some(&discriminants));
+        assert_eq!(
+            schema.serialize(),
+            Some(
+                r#"{"type":"int8","metadata":{"x-extensions":{"discriminants":[{"name":"Low","value":1},{"name":"Debt","value":-5}]}}}"#
+                    .to_string()
+            )
+        );
+        assert_eq!(TypeSchema::new(Types::Int8).discriminants(), None);
+    }
+}
@@ -20,36 +20,82 @@
 //! Import the necessary schema types and utilities from this crate to define, manipulate,
 //! serialize, or deserialize Arri schemas in your Rust projects.
 
+mod cache;
+mod constraints;
+mod context;
+mod doc_link;
 mod elements;
 mod empty;
 mod r#enum;
+mod error;
 mod exportable;
+mod extension;
+pub mod fingerprint;
+mod implementor;
+mod int_enum;
+mod map_key;
 mod metadata;
+mod naming;
+mod output;
 mod properties;
+mod raw_json;
 mod r#ref;
+mod registry;
+mod result_shape;
 mod serializable;
 mod serializer;
 mod tagged_union;
 mod r#type;
+mod untagged;
+#[cfg(feature = "validation")]
+pub mod validation;
 #[cfg(feature = "any")]
 mod value;
 mod values;
 
 pub use arri_common::EnumTransformation;
+pub use cache::ExportCache;
+pub use constraints::ConstraintsSchema;
+pub use context::{ExportContext, property_name, report};
+#[doc(hidden)]
+pub use doc_link::{DocLink, ExportableDocLink, NamedDocLink};
 pub use elements::ElementsSchema;
 pub use empty::EmptySchema;
 pub use r#enum::EnumSchema;
-pub use exportable::Exportable;
+pub use error::{Modifier, SchemaError};
+pub use exportable::{
+    ExportDirection, Exportable, directional_name, export_direction, is_directional_with,
+};
+pub use extension::ExtensionValue;
+pub use implementor::{
+    DEFAULT_DISCRIMINATOR, Implementor, export_implementors, implementors,
+    implementors_are_directional, register_implementor,
+};
+#[doc(hidden)]
+pub use implementor::{SubmittedImplementor, register_submitted_implementors};
+pub use int_enum::{DISCRIMINANTS_EXTENSION, Discriminants, IntEnum};
+#[doc(hidden)]
+pub use inventory;
+pub use map_key::{KeyError, MapKey};
 pub use metadata::MetadataSchema;
+pub use naming::{GenericIds, Naming, naming, with_naming};
+pub use output::OutputOptions;
 pub use properties::PropertiesSchema;
+pub use raw_json::RawJson;
 pub use r#ref::RefSchema;
+pub use registry::{IdCollision, Registry, define_id};
+pub use result_shape::{ResultShape, with_result_shape};
 pub use serializable::Serializable;
 pub use serializer::Serializer;
 pub use tagged_union::TaggedUnionSchema;
 pub use r#type::{TypeSchema, Types};
+pub use untagged::{CANDIDATES_EXTENSION, Candidates, UntaggedSchema};
 #[cfg(feature = "any")]
 pub use value::{NumberValue, Value};
-pub use values::ValuesSchema;
+pub use values::{KEY_SCHEMA_EXTENSION, KeySchema, ValuesSchema};
+
+/// The version of the Arri schema specification the schemas are exported for by default.
+pub static SCHEMA_VERSION: &str = "v0.0.8";
 
 pub mod type_utils {
     /// Extracts a type name from a string representation.
@@ -0,0 +1,103 @@
+use std::fmt;
+
+/// A type which can be the key of a map.
+///
+/// Arri maps are always keyed by strings, so other keys are encoded as strings.
+/// [`MapKey::from_key`] parses exactly what [`MapKey::to_key`] produces. The schema of the keys
+/// is recorded on the map, see [`ValuesSchema::key_schema`](crate::ValuesSchema::key_schema).
+///
+/// `#[derive(Exported)]` implements this for enums without fields, using the values of their
+/// [`EnumSchema`](crate::EnumSchema). Other values are rejected when parsing.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::MapKey;
+///
+/// assert_eq!(42u32.to_key(), "42");
+/// assert_eq!(u32::from_key("42"), Ok(42));
+/// assert!(u32::from_key("-1").is_err());
+/// ```
+pub trait MapKey: Sized {
+    /// Encodes the key as a string.
+    fn to_key(&self) -> String;
+
+    /// Parses a key encoded by [`MapKey::to_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if the string isn't a valid key, e.g. an unknown enum value.
+    fn from_key(key: &str) -> Result<Self, KeyError>;
+}
+
+/// A string which isn't a valid key of a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyError {
+    /// The key which was parsed.
+    pub key: String,
+    /// The Rust type the key was parsed as.
+    pub expected: &'static str,
+}
+
+impl KeyError {
+    /// Creates the error for a key which isn't a valid `T`.
+    pub fn new<T: ?Sized>(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            expected: std::any::type_name::<T>(),
+        }
+    }
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` isn't a valid `{}` key", self.key, self.expected)
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// Implements [`MapKey`] for types which are encoded with `Display` and parsed with `FromStr`.
+macro_rules! display_map_key {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl MapKey for $ty {
+                fn to_key(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_key(key: &str) -> Result<Self, KeyError> {
+                    key.parse().map_err(|_| KeyError::new::<Self>(key))
+                }
+            }
+        )*
+    };
+}
+
+display_map_key!(
+    String, bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,
+);
+
+#[cfg(feature = "uuid")]
+display_map_key!(uuid::Uuid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_round_trip() {
+        assert_eq!(i64::from_key(&(-7i64).to_key()), Ok(-7));
+        assert_eq!(bool::from_key(&true.to_key()), Ok(true));
+        assert_eq!(char::from_key(&'a'.to_key()), Ok('a'));
+        assert_eq!(String::from_key("any key"), Ok("any key".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_keys_are_rejected() {
+        let error = u8::from_key("256").unwrap_err();
+        assert_eq!(error, KeyError::new::<u8>("256"));
+        assert_eq!(error.to_string(), "`256` isn't a valid `u8` key");
+        assert!(bool::from_key("yes").is_err());
+    }
+}
@@ -1,6 +1,9 @@
+use std::fmt;
 use std::ops::BitOr;
 
-use ronky_derive::Serializable as SerializableDerive;
+use indexmap::IndexMap;
+
+use crate::{ConstraintsSchema, ExtensionValue, RawJson, Serializable, Serializer, output};
 
 /// Macro to merge fields from one struct into another.
 ///
@@ -21,8 +24,10 @@ macro_rules! merge_fields {
 ///
 /// This struct defines the metadata schema, including optional fields
 /// such as `id`, `description`, and deprecation-related information.
-#[derive(Default, Debug, Clone, PartialEq, Eq, SerializableDerive)]
-#[arri_disable(metadata, nullable)]
+///
+/// New fields are added as Arri and ronky grow, and default to `None`, so struct literals should
+/// end with `..Default::default()`.
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct MetadataSchema {
     /// Unique identifier for the metadata schema.
     pub id: Option<String>,
@@ -34,9 +39,78 @@ pub struct MetadataSchema {
     pub deprecated_since: Option<String>,
     /// Message explaining the deprecation.
     pub deprecated_message: Option<String>,
+    /// Alternative names that are accepted in place of the canonical name.
+    pub aliases: Option<Vec<String>>,
+    /// Validation constraints which can't be expressed by the schema itself.
+    pub constraints: Option<ConstraintsSchema>,
+    /// An example of a valid value, encoded as JSON.
+    pub example: Option<RawJson>,
+    /// Custom annotations which aren't part of Arri, serialized under `x-extensions`.
+    pub extensions: Option<IndexMap<String, ExtensionValue>>,
+}
+
+impl Serializable for MetadataSchema {
+    /// Writes the metadata as JSON into `out`, without the descriptions and deprecation notes
+    /// in stripped output.
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_fields(out, |serializer| {
+            serializer.set("x-extensions", &self.extensions);
+        })
+    }
+
+    /// Metadata which only documents the schema is left out entirely in stripped output.
+    fn is_absent(&self) -> bool {
+        output::current().is_stripped()
+            && self.id.is_none()
+            && self.is_deprecated.is_none()
+            && self.aliases.is_none()
+            && self.constraints.is_none()
+            && self.example.is_none()
+            && self.extensions.is_none()
+    }
 }
 
 impl MetadataSchema {
+    /// Writes the fields of the metadata, with `extensions` writing the custom extensions.
+    fn write_fields(
+        &self,
+        out: &mut dyn fmt::Write,
+        extensions: impl FnOnce(&mut Serializer),
+    ) -> fmt::Result {
+        let mut serializer = Serializer::new(out);
+        serializer
+            .set("id", &self.id)
+            .set_documentation("description", &self.description)
+            .set("isDeprecated", &self.is_deprecated)
+            .set_documentation("deprecatedSince", &self.deprecated_since)
+            .set_documentation("deprecatedNote", &self.deprecated_message)
+            .set("aliases", &self.aliases)
+            .set("constraints", &self.constraints)
+            .set("example", &self.example);
+        extensions(&mut serializer);
+        serializer.finish()
+    }
+
+    /// Writes the metadata with an extension which isn't stored in it, as its value can't be
+    /// shared between threads like the rest of the metadata. The metadata is written even if it
+    /// would be absent on its own.
+    pub(crate) fn write_with_extension(
+        &self,
+        out: &mut dyn fmt::Write,
+        key: &str,
+        value: &impl Serializable,
+    ) -> fmt::Result {
+        self.write_fields(out, |serializer| {
+            serializer.set_with("x-extensions", |out| {
+                let mut extensions = Serializer::new(out);
+                for (key, value) in self.extensions.iter().flatten() {
+                    extensions.set(key, value);
+                }
+                extensions.set(key, value).finish()
+            });
+        })
+    }
+
     /// Creates a new, empty `MetadataSchema`.
     pub fn new() -> Self {
         Self::default()
@@ -92,6 +166,70 @@ impl MetadataSchema {
         self
     }
 
+    /// Adds an alias to the `aliases` field of the metadata schema.
+    ///
+    /// Aliases are alternative names which are accepted when decoding, for
+    /// example while a property is being renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - A value that can be converted to a `String` representing the alias.
+    pub fn add_alias(&mut self, alias: impl ToString) -> &mut Self {
+        self.aliases
+            .get_or_insert_with(Vec::new)
+            .push(alias.to_string());
+        self
+    }
+
+    /// Sets the `constraints` field of the metadata schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `constraints` - The validation constraints of the value.
+    pub fn set_constraints(&mut self, constraints: ConstraintsSchema) -> &mut Self {
+        self.constraints = Some(constraints);
+        self
+    }
+
+    /// Sets the `example` field of the metadata schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - A value that can be converted to a `String` containing the example encoded as
+    ///   JSON.
+    pub fn set_example(&mut self, json: impl ToString) -> &mut Self {
+        self.example = Some(RawJson::new(json));
+        self
+    }
+
+    /// Sets a custom extension of the metadata schema, replacing any previous value of `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A value that can be converted to a `String` naming the extension.
+    /// * `value` - The value of the extension.
+    pub fn set_extension(
+        &mut self,
+        key: impl ToString,
+        value: impl Into<ExtensionValue>,
+    ) -> &mut Self {
+        self.extensions
+            .get_or_insert_with(IndexMap::new)
+            .insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Returns the value of a custom extension, if it is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the extension.
+    pub fn extension(&self, key: &str) -> Option<&ExtensionValue> {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get(key))
+    }
+
     /// Merges another `MetadataSchema` into this one.
     ///
     /// Fields in the other schema take precedence if they are `Some`.
@@ -110,7 +248,10 @@ impl MetadataSchema {
             id,
             description,
             deprecated_since,
-            deprecated_message
+            deprecated_message,
+            aliases,
+            constraints,
+            example
         );
 
         // Handle non-String fields separately
@@ -118,10 +259,26 @@ impl MetadataSchema {
             self.is_deprecated = Some(is_deprecated);
         }
 
+        // Extensions are merged per key, so annotations from both sides are kept
+        for (key, value) in other.extensions.iter().flatten() {
+            self.set_extension(key, value.clone());
+        }
+
         self
     }
 }
 
+impl MetadataSchema {
+    /// Merges metadata into the metadata of a schema, keeping what was set before unless it is
+    /// overridden.
+    pub(crate) fn merge_into(current: &mut Option<Self>, metadata: Self) {
+        *current = Some(match current.take() {
+            Some(current) => current | metadata,
+            None => metadata,
+        });
+    }
+}
+
 impl BitOr for MetadataSchema {
     type Output = Self;
 
@@ -143,6 +300,10 @@ mod tests {
         assert_eq!(schema.is_deprecated, None);
         assert_eq!(schema.deprecated_since, None);
         assert_eq!(schema.deprecated_message, None);
+        assert_eq!(schema.aliases, None);
+        assert_eq!(schema.constraints, None);
+        assert_eq!(schema.example, None);
+        assert_eq!(schema.extensions, None);
     }
 
     #[test]
@@ -205,6 +366,78 @@ mod tests {
         assert!(serialized_str.contains("\"deprecatedNote\":\"Deprecated for testing\""));
     }
 
+    #[test]
+    fn test_metadata_schema_aliases() {
+        let mut schema = MetadataSchema::new();
+        schema.add_alias("oldName").add_alias("legacyName");
+
+        assert_eq!(
+            schema.aliases,
+            Some(vec!["oldName".to_string(), "legacyName".to_string()])
+        );
+
+        let serialized = schema.serialize().unwrap();
+        assert!(serialized.contains("\"aliases\":[\"oldName\",\"legacyName\"]"));
+    }
+
+    #[test]
+    fn test_metadata_schema_constraints() {
+        let mut schema = MetadataSchema::new();
+        schema
+            .set_description("The age of the user")
+            .set_constraints(crate::ConstraintsSchema::new().set_min(0.0).to_owned());
+
+        let merged = schema.merge(
+            MetadataSchema::new().set_constraints(
+                crate::ConstraintsSchema::new()
+                    .set_min(18.0)
+                    .set_max(130.0)
+                    .to_owned(),
+            ),
+        );
+
+        assert_eq!(merged.description, Some("The age of the user".to_string()));
+        let serialized = merged.serialize().unwrap();
+        assert!(serialized.contains("\"constraints\":{\"min\":18,\"max\":130}"));
+    }
+
+    #[test]
+    fn test_metadata_schema_extensions() {
+        let mut schema1 = MetadataSchema::new();
+        schema1
+            .set_extension("owner", "billing")
+            .set_extension("pii", false);
+
+        let mut schema2 = MetadataSchema::new();
+        schema2
+            .set_extension("pii", true)
+            .set_extension("weight", 3);
+
+        let merged = schema1 | schema2;
+
+        assert_eq!(
+            merged.extension("owner"),
+            Some(&ExtensionValue::from("billing"))
+        );
+        assert_eq!(merged.extension("pii"), Some(&ExtensionValue::from(true)));
+        assert_eq!(merged.extension("missing"), None);
+
+        let serialized = merged.serialize().unwrap();
+        assert!(
+            serialized
+                .contains("\"x-extensions\":{\"owner\":\"billing\",\"pii\":true,\"weight\":3}")
+        );
+    }
+
+    #[test]
+    fn test_metadata_schema_example() {
+        let mut schema = MetadataSchema::new();
+        schema.set_example(r#"{"name":"Ronky","age":14}"#);
+
+        let serialized = schema.serialize().unwrap();
+        assert!(serialized.contains(r#""example":{"name":"Ronky","age":14}"#));
+    }
+
     #[test]
     fn test_metadata_schema_bitor_operator() {
         let mut schema1 = MetadataSchema::new();
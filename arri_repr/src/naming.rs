@@ -0,0 +1,285 @@
+use std::hash::{Hash, Hasher};
+
+use crate::context::{self, ExportContext};
+
+/// The strategy used to build the ids of instantiations of generic types, e.g. `Page<User>`.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum GenericIds {
+    /// Appends the ids of the arguments to the id of the type, e.g. `PageUser`. This is the
+    /// default.
+    #[default]
+    Concatenated,
+    /// Joins the ids with underscores, e.g. `Page_User`.
+    Underscored,
+    /// Joins the ids with `Of` and `And`, e.g. `PageOfUser` or `MapOfStringAndUser`.
+    Of,
+    /// Builds the id with a custom function, which receives the id of the type and the ids of
+    /// its arguments.
+    ///
+    /// Custom strategies are told apart by their name, e.g. by the cache of an
+    /// [`ExportContext`], so distinct functions need distinct names.
+    Custom {
+        /// The name of the strategy.
+        name: &'static str,
+        /// Builds the id.
+        build: fn(&str, &[String]) -> String,
+    },
+}
+
+impl PartialEq for GenericIds {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom { name: a, .. }, Self::Custom { name: b, .. }) => a == b,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for GenericIds {}
+
+impl Hash for GenericIds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Self::Custom { name, .. } = self {
+            name.hash(state);
+        }
+    }
+}
+
+/// Determines how the ids of exported types are built.
+///
+/// The naming is set for an export with [`ExportContext::set_naming`] or [`with_naming`], and
+/// defaults to the name of the type with the ids of its generic arguments appended.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Naming {
+    /// The strategy for the ids of generic types.
+    generics: GenericIds,
+    /// Whether ids are prefixed with the module path of the type.
+    qualified: bool,
+    /// Whether primitive generic arguments add their Rust name to the id.
+    primitive_arguments: bool,
+    /// Whether the ids of generic arguments are capitalized.
+    capitalized_arguments: bool,
+}
+
+impl Naming {
+    /// Creates the default naming strategy.
+    pub const fn new() -> Self {
+        Self {
+            generics: GenericIds::Concatenated,
+            qualified: false,
+            primitive_arguments: false,
+            capitalized_arguments: false,
+        }
+    }
+
+    /// Sets the strategy for the ids of generic types.
+    pub const fn set_generics(mut self, generics: GenericIds) -> Self {
+        self.generics = generics;
+        self
+    }
+
+    /// Sets whether ids are prefixed with the module path of the type (without the crate), e.g.
+    /// `BillingError` for `my_crate::billing::Error`.
+    pub const fn set_qualified(mut self, qualified: bool) -> Self {
+        self.qualified = qualified;
+        self
+    }
+
+    /// Sets whether primitive generic arguments, which have no id of their own, add their Rust
+    /// name to the id, e.g. `Linku8` rather than `Link` for `Link<u8>`.
+    pub const fn set_primitive_arguments(mut self, primitive: bool) -> Self {
+        self.primitive_arguments = primitive;
+        self
+    }
+
+    /// Sets whether the ids of generic arguments are capitalized, so primitives read like the
+    /// other types, e.g. `LinkU8` rather than `Linku8` for `Link<u8>` with primitive arguments.
+    pub const fn set_capitalized_arguments(mut self, capitalized: bool) -> Self {
+        self.capitalized_arguments = capitalized;
+        self
+    }
+
+    /// Builds the id of a type from its name and the module it is defined in.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_path` - The module path of the type, as returned by `module_path!()`.
+    /// * `name` - The name of the type.
+    pub fn type_id(&self, module_path: &str, name: &str) -> String {
+        if !self.qualified {
+            return name.to_string();
+        }
+
+        module_path
+            .split("::")
+            .skip(1)
+            .flat_map(|module| module.split('_'))
+            .map(capitalize)
+            .chain(std::iter::once(name.to_string()))
+            .collect()
+    }
+
+    /// Builds the id a generic argument adds to the id of a generic type.
+    ///
+    /// Primitive arguments have no id of their own, so they only add their Rust name if the
+    /// naming sets [`Naming::set_primitive_arguments`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the argument.
+    /// * `type_name` - The Rust type name of the argument, as returned by
+    ///   `std::any::type_name`.
+    pub fn argument_id(&self, id: String, type_name: &str) -> String {
+        match id.is_empty() && self.primitive_arguments {
+            true => type_name
+                .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+                .find(|word| !word.is_empty())
+                .unwrap_or_default()
+                .to_string(),
+            false => id,
+        }
+    }
+
+    /// Builds the id of an instantiation of a generic type.
+    ///
+    /// Arguments without an id, such as primitives, are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the generic type itself.
+    /// * `arguments` - The ids of the generic arguments.
+    pub fn generic_id(&self, id: &str, arguments: &[String]) -> String {
+        let arguments = arguments
+            .iter()
+            .filter(|argument| !argument.is_empty())
+            .map(|argument| match self.capitalized_arguments {
+                true => capitalize(argument),
+                false => argument.clone(),
+            })
+            .collect::<Vec<_>>();
+        if arguments.is_empty() {
+            return id.to_string();
+        }
+
+        match self.generics {
+            GenericIds::Concatenated => format!("{}{}", id, arguments.concat()),
+            GenericIds::Underscored => format!("{}_{}", id, arguments.join("_")),
+            GenericIds::Of => format!("{}Of{}", id, arguments.join("And")),
+            GenericIds::Custom { build, .. } => build(id, &arguments),
+        }
+    }
+}
+
+/// Uppercases the first character of a word.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the naming strategy of the export that is currently in progress.
+pub fn naming() -> Naming {
+    context::read(ExportContext::naming)
+}
+
+/// Runs `export` with the given naming strategy, in the context of the current export.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::{Exportable, GenericIds, Naming, with_naming};
+///
+/// let naming = Naming::new().set_generics(GenericIds::Of);
+/// let id = with_naming(naming, || Vec::<String>::get_schema_id());
+/// ```
+pub fn with_naming<R>(naming: Naming, export: impl FnOnce() -> R) -> R {
+    context::modified(|context| context.set_naming(naming), export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_id() {
+        assert_eq!(Naming::new().type_id("my_crate::billing", "Error"), "Error");
+        assert_eq!(
+            Naming::new()
+                .set_qualified(true)
+                .type_id("my_crate::billing_api::v2", "Error"),
+            "BillingApiV2Error"
+        );
+        assert_eq!(
+            Naming::new()
+                .set_qualified(true)
+                .type_id("my_crate", "Error"),
+            "Error"
+        );
+    }
+
+    #[test]
+    fn test_generic_id() {
+        let arguments = ["String".to_string(), "User".to_string()];
+
+        assert_eq!(Naming::new().generic_id("Map", &arguments), "MapStringUser");
+        assert_eq!(
+            Naming::new()
+                .set_generics(GenericIds::Underscored)
+                .generic_id("Map", &arguments),
+            "Map_String_User"
+        );
+        assert_eq!(
+            Naming::new()
+                .set_generics(GenericIds::Of)
+                .generic_id("Map", &arguments),
+            "MapOfStringAndUser"
+        );
+        assert_eq!(
+            Naming::new()
+                .set_generics(GenericIds::Custom {
+                    name: "angle_brackets",
+                    build: |id, arguments| format!("{}<{}>", id, arguments.join(",")),
+                })
+                .generic_id("Map", &arguments),
+            "Map<String,User>"
+        );
+        assert_eq!(Naming::new().generic_id("Page", &[]), "Page");
+        assert_eq!(
+            Naming::new()
+                .set_generics(GenericIds::Underscored)
+                .generic_id("Page", &[String::new()]),
+            "Page"
+        );
+    }
+
+    #[test]
+    fn test_primitive_arguments() {
+        let primitive = Naming::new().set_primitive_arguments(true);
+        assert_eq!(Naming::new().argument_id(String::new(), "u8"), "");
+        assert_eq!(primitive.argument_id(String::new(), "u8"), "u8");
+        assert_eq!(primitive.argument_id(String::new(), "&str"), "str");
+        assert_eq!(
+            primitive.argument_id("User".to_string(), "crate::User"),
+            "User"
+        );
+
+        let arguments = ["u8".to_string(), "User".to_string()];
+        assert_eq!(Naming::new().generic_id("Map", &arguments), "Mapu8User");
+        assert_eq!(
+            Naming::new()
+                .set_capitalized_arguments(true)
+                .generic_id("Map", &arguments),
+            "MapU8User"
+        );
+    }
+
+    #[test]
+    fn test_with_naming_restores_previous() {
+        let qualified = Naming::new().set_qualified(true);
+        with_naming(qualified, || assert!(naming().qualified));
+        assert!(!naming().qualified);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cell::Cell;
+use std::fmt::{self, Write};
+
+use crate::Serializable;
+
+thread_local! {
+    /// The options of the serialization in progress, and the depth of the value being written.
+    static OUTPUT: Cell<(OutputOptions, usize)> = const { Cell::new((OutputOptions::new(), 0)) };
+}
+
+/// Determines how schemas are written by [`OutputOptions::serialize`].
+///
+/// The default output is compact, keeps the insertion order of properties, and includes all
+/// documentation.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::{ElementsSchema, OutputOptions, TypeSchema, Types};
+///
+/// let schema = ElementsSchema::new(Box::new(TypeSchema::new(Types::String)));
+///
+/// let pretty = OutputOptions::new().set_indent(2).serialize(&schema).unwrap();
+/// assert_eq!(pretty, "{\n  \"elements\": {\n    \"type\": \"string\"\n  }\n}");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutputOptions {
+    /// The number of spaces to indent nested values with, or `None` for compact output.
+    indent: Option<usize>,
+    /// Whether the keys of objects are sorted.
+    canonical: bool,
+    /// Whether descriptions and deprecation notes are left out.
+    stripped: bool,
+}
+
+impl OutputOptions {
+    /// Creates the default options: compact output in insertion order, with documentation.
+    pub const fn new() -> Self {
+        Self {
+            indent: None,
+            canonical: false,
+            stripped: false,
+        }
+    }
+
+    /// Pretty prints the output, indenting nested values with the given number of spaces.
+    pub const fn set_indent(mut self, indent: usize) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Sets whether the keys of objects are sorted, so the output only depends on the content of
+    /// the schema. This is suitable for hashing schemas or committing them to git.
+    pub const fn set_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Sets whether descriptions and deprecation notes are left out, e.g. for production
+    /// bundles. Deprecated schemas are still marked as deprecated.
+    pub const fn set_stripped(mut self, stripped: bool) -> Self {
+        self.stripped = stripped;
+        self
+    }
+
+    /// Retrieves the number of spaces nested values are indented with, if pretty printed.
+    pub const fn indent(&self) -> Option<usize> {
+        self.indent
+    }
+
+    /// Retrieves whether the keys of objects are sorted.
+    pub const fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Retrieves whether descriptions and deprecation notes are left out.
+    pub const fn is_stripped(&self) -> bool {
+        self.stripped
+    }
+
+    /// Writes a value into `out` with these options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write_to(
+        &self,
+        value: &(impl Serializable + ?Sized),
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let previous = OUTPUT.with(|output| output.replace((*self, 0)));
+        let _guard = OutputGuard(previous);
+        value.write_to(out)
+    }
+
+    /// Serializes a value with these options.
+    ///
+    /// # Returns
+    ///
+    /// The serialized value, or `None` if the value is absent or serialization fails.
+    pub fn serialize(&self, value: &(impl Serializable + ?Sized)) -> Option<String> {
+        if value.is_absent() {
+            return None;
+        }
+
+        let mut out = String::new();
+        self.write_to(value, &mut out).ok()?;
+        (!out.is_empty()).then_some(out)
+    }
+}
+
+/// Restores the options of the previous serialization when dropped, even if writing panics.
+struct OutputGuard((OutputOptions, usize));
+
+impl Drop for OutputGuard {
+    fn drop(&mut self) {
+        OUTPUT.with(|output| output.set(self.0));
+    }
+}
+
+/// Retrieves the options of the serialization in progress.
+pub(crate) fn current() -> OutputOptions {
+    OUTPUT.with(|output| output.get().0)
+}
+
+/// Writes the opening delimiter of an object or array, and enters it.
+pub(crate) fn open(out: &mut dyn Write, delimiter: char) -> fmt::Result {
+    OUTPUT.with(|output| {
+        let (options, depth) = output.get();
+        output.set((options, depth + 1));
+    });
+    out.write_char(delimiter)
+}
+
+/// Separates an entry of an object or array from the previous one, and indents it when
+/// pretty printing.
+pub(crate) fn separate(out: &mut dyn Write, first: bool) -> fmt::Result {
+    if !first {
+        out.write_char(',')?;
+    }
+    newline(out)
+}
+
+/// Writes the separator between the key and value of an object entry.
+pub(crate) fn colon(out: &mut dyn Write) -> fmt::Result {
+    match current().indent {
+        Some(_) => out.write_str(": "),
+        None => out.write_char(':'),
+    }
+}
+
+/// Leaves an object or array, and writes its closing delimiter on its own line if it has
+/// entries and is pretty printed.
+pub(crate) fn close(out: &mut dyn Write, delimiter: char, empty: bool) -> fmt::Result {
+    OUTPUT.with(|output| {
+        let (options, depth) = output.get();
+        output.set((options, depth.saturating_sub(1)));
+    });
+    if !empty {
+        newline(out)?;
+    }
+    out.write_char(delimiter)
+}
+
+/// Writes a value preceded by `prefix`, such as the separator and key of an entry, unless the
+/// value writes nothing. Values which implement [`Serializable::serialize`] rather than
+/// [`Serializable::write_to`] are only known to be absent once they are written.
+///
+/// # Returns
+///
+/// Whether the value was written.
+pub(crate) fn write_prefixed(
+    out: &mut dyn Write,
+    prefix: &str,
+    value: impl FnOnce(&mut dyn Write) -> fmt::Result,
+) -> Result<bool, fmt::Error> {
+    let mut prefixed = Prefixed {
+        out,
+        prefix: Some(prefix),
+    };
+    value(&mut prefixed)?;
+    Ok(prefixed.prefix.is_none())
+}
+
+/// An output which writes a prefix before the first output written to it.
+struct Prefixed<'a, 'b> {
+    out: &'a mut dyn Write,
+    prefix: Option<&'b str>,
+}
+
+impl Write for Prefixed<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some(prefix) = self.prefix.take() {
+            self.out.write_str(prefix)?;
+        }
+        self.out.write_str(s)
+    }
+}
+
+/// Starts a new line at the current depth when pretty printing.
+fn newline(out: &mut dyn Write) -> fmt::Result {
+    let (options, depth) = OUTPUT.with(Cell::get);
+    match options.indent {
+        Some(indent) => write!(out, "\n{:width$}", "", width = indent * depth),
+        None => Ok(()),
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use indexmap::IndexMap;
 
 use crate::{MetadataSchema, Serializable, serializer::Serializer};
@@ -6,7 +8,7 @@ use crate::{MetadataSchema, Serializable, serializer::Serializer};
 ///
 /// This struct defines the properties, optional properties, and metadata
 /// associated with a schema. It also includes flags for strictness and nullability.
-#[derive(Default, Debug, PartialEq, Eq)]
+#[derive(Default, Debug, PartialEq)]
 pub struct PropertiesSchema {
     /// A map of required properties, where the key is the property name
     /// and the value is a serializable object. Uses IndexMap to preserve insertion order.
@@ -84,20 +86,15 @@ impl PropertiesSchema {
 }
 
 impl Serializable for PropertiesSchema {
-    /// Serializes the `PropertiesSchema` into a string representation.
-    ///
-    /// # Returns
-    ///
-    /// An `Option<String>` containing the serialized schema, or `None` if serialization fails.
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    /// Writes the `PropertiesSchema` as JSON into `out`.
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("properties", &self.properties)
             .set("optionalProperties", &self.optional_properties)
             .set("isStrict", &self.is_strict)
             .set("metadata", &self.metadata)
             .set("isNullable", &self.is_nullable)
-            .build()
-            .into()
+            .finish()
     }
 
     /// Sets the metadata for the schema.
@@ -105,12 +102,9 @@ impl Serializable for PropertiesSchema {
     /// # Arguments
     ///
     /// * `metadata` - A `MetadataSchema` object to be merged with the existing metadata.
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(if let Some(current) = &self.metadata {
-            current.clone() | metadata
-        } else {
-            metadata
-        });
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
     /// Sets the nullability flag for the schema.
@@ -118,8 +112,9 @@ impl Serializable for PropertiesSchema {
     /// # Arguments
     ///
     /// * `is_nullable` - A boolean indicating whether null values are allowed.
-    fn set_nullable(&mut self, is_nullable: bool) {
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), crate::SchemaError> {
         self.is_nullable = Some(is_nullable);
+        Ok(())
     }
 }
 
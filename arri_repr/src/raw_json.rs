@@ -0,0 +1,45 @@
+use std::fmt;
+
+use crate::Serializable;
+
+/// A JSON document which is serialized as-is.
+///
+/// Used for values which are already encoded, such as the example payloads of a schema.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RawJson(String);
+
+impl RawJson {
+    /// Creates a new `RawJson` from an encoded JSON document.
+    ///
+    /// The document is not validated, it must already be valid JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - A value that can be converted to a `String` containing the JSON document.
+    pub fn new(json: impl ToString) -> Self {
+        Self(json.to_string())
+    }
+
+    /// Returns the encoded JSON document.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Serializable for RawJson {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_json_is_not_escaped() {
+        let json = RawJson::new(r#"{"name":"Ronky"}"#);
+        assert_eq!(json.serialize(), Some(r#"{"name":"Ronky"}"#.to_string()));
+        assert_eq!(json.as_str(), r#"{"name":"Ronky"}"#);
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::context::{self, ExportContext};
+use crate::{ExportDirection, Exportable, SchemaError, Serializable, Serializer};
+
+/// Retrieves the id of `T` for the definition of its schema.
+///
+/// While a type is being registered in a [`Registry`], the id is recorded, so distinct types
+/// with the same id can be detected. This is called by `#[derive(Exported)]`.
+pub fn define_id<T: Exportable + ?Sized>() -> String {
+    let id = T::get_schema_id();
+    context::record_id(&id, std::any::type_name::<T>());
+    id
+}
+
+/// Two distinct Rust types which export with the same id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdCollision {
+    /// The id both types export with.
+    pub id: String,
+    /// The Rust path of the type which was registered first.
+    pub existing: String,
+    /// The Rust path of the type which collides with it.
+    pub colliding: String,
+}
+
+impl fmt::Display for IdCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The id `{}` is used by both `{}` and `{}`, give one of them another id with #[arri(id = \"...\")]",
+            self.id, self.existing, self.colliding
+        )
+    }
+}
+
+impl std::error::Error for IdCollision {}
+
+/// Collects the schemas of exported types by their id.
+///
+/// Registering a type also checks every type nested in it, so two types which would end up
+/// with the same id in a generated client are reported instead of silently overwriting each
+/// other.
+///
+/// The registry is serialized as a document with the schema version of its context and the
+/// registered schemas, e.g. `{"schemaVersion":"v0.0.8","definitions":{"User":{...}}}`.
+#[derive(Debug, Default)]
+pub struct Registry {
+    /// The context the types are exported with.
+    context: ExportContext,
+    /// The Rust path of the type behind every id.
+    paths: IndexMap<String, &'static str>,
+    /// The schemas of the registered types, by id.
+    schemas: IndexMap<String, Box<dyn Serializable>>,
+}
+
+impl Registry {
+    /// Creates an empty registry, which exports types with the default [`ExportContext`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty registry, which exports types with the given context.
+    pub fn with_context(context: ExportContext) -> Self {
+        Self {
+            context,
+            ..Self::default()
+        }
+    }
+
+    /// Exports `T` and adds it to the registry.
+    ///
+    /// Types whose input schema differs from their output schema (see
+    /// [`Exportable::is_directional`]) are registered in both directions, each with its own id.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SchemaError::IdCollision`] if `T`, or a type nested in it, has the same id as
+    /// a distinct type which was registered before, or the error of an export which failed (see
+    /// [`ExportContext::try_export`]). The registry is left unchanged in that case.
+    pub fn register<T: Exportable + ?Sized>(&mut self) -> Result<&mut Self, SchemaError> {
+        let contexts = match T::is_directional() {
+            true => vec![
+                self.context.clone().set_direction(ExportDirection::Output),
+                self.context.clone().set_direction(ExportDirection::Input),
+            ],
+            false => vec![self.context.clone()],
+        };
+
+        let mut paths = self.paths.clone();
+        let mut schemas = Vec::with_capacity(contexts.len());
+        for context in contexts {
+            let (schema, defined) = context.export_collecting_ids::<T>()?;
+            for (id, path) in defined {
+                match paths.get(&id) {
+                    Some(existing) if *existing != path => {
+                        return Err(SchemaError::IdCollision(IdCollision {
+                            id,
+                            existing: existing.to_string(),
+                            colliding: path.to_string(),
+                        }));
+                    }
+                    _ => {
+                        paths.insert(id, path);
+                    }
+                }
+            }
+
+            let id = context::modified(|_| context, T::get_schema_id);
+            schemas.push((id, schema));
+        }
+
+        self.paths = paths;
+        self.schemas.extend(schemas);
+        Ok(self)
+    }
+
+    /// Retrieves the schema of a registered type by its id.
+    pub fn get(&self, id: &str) -> Option<&dyn Serializable> {
+        self.schemas.get(id).map(|schema| schema.as_ref())
+    }
+
+    /// Retrieves the Rust path of the type behind an id, including nested types.
+    pub fn path_of(&self, id: &str) -> Option<&'static str> {
+        self.paths.get(id).copied()
+    }
+
+    /// Iterates over the ids of the registered types, in order of registration.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.schemas.keys().map(String::as_str)
+    }
+}
+
+impl Serializable for Registry {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
+            .set("schemaVersion", &self.context.schema_version())
+            .set("definitions", &self.schemas)
+            .finish()
+    }
+}
@@ -0,0 +1,122 @@
+use crate::context;
+
+/// Determines how `Result<T, E>` is represented, as a tagged union of its two variants.
+///
+/// The default shape is `{"type": "Ok", "value": ...}` and `{"type": "Err", "value": ...}`. The
+/// shape is set for an export with
+/// [`ExportContext::set_result_shape`](crate::ExportContext::set_result_shape) or
+/// [`with_result_shape`], or for a single field with `#[arri(result = SHAPE)]`.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::ResultShape;
+///
+/// // {"status": "success", "data": ...} or {"status": "error", "error": ...}
+/// const API_RESULT: ResultShape = ResultShape::new()
+///     .set_discriminator("status")
+///     .set_variants("success", "error")
+///     .set_properties("data", "error");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResultShape {
+    /// The property which holds the name of the variant.
+    discriminator: &'static str,
+    /// The name of the `Ok` variant.
+    ok: &'static str,
+    /// The name of the `Err` variant.
+    err: &'static str,
+    /// The property which holds the value of the `Ok` variant.
+    ok_property: &'static str,
+    /// The property which holds the error of the `Err` variant.
+    err_property: &'static str,
+}
+
+impl Default for ResultShape {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResultShape {
+    /// Creates the default shape: a `type` discriminator, `Ok` and `Err` variants, and the
+    /// payload in `value`.
+    pub const fn new() -> Self {
+        Self {
+            discriminator: "type",
+            ok: "Ok",
+            err: "Err",
+            ok_property: "value",
+            err_property: "value",
+        }
+    }
+
+    /// Sets the property which holds the name of the variant.
+    pub const fn set_discriminator(mut self, discriminator: &'static str) -> Self {
+        self.discriminator = discriminator;
+        self
+    }
+
+    /// Sets the names of the `Ok` and `Err` variants.
+    pub const fn set_variants(mut self, ok: &'static str, err: &'static str) -> Self {
+        self.ok = ok;
+        self.err = err;
+        self
+    }
+
+    /// Sets the properties which hold the value of the `Ok` variant and the error of the `Err`
+    /// variant.
+    pub const fn set_properties(mut self, ok: &'static str, err: &'static str) -> Self {
+        self.ok_property = ok;
+        self.err_property = err;
+        self
+    }
+
+    /// Retrieves the property which holds the name of the variant.
+    pub const fn discriminator(&self) -> &'static str {
+        self.discriminator
+    }
+
+    /// Retrieves the name of the `Ok` variant.
+    pub const fn ok(&self) -> &'static str {
+        self.ok
+    }
+
+    /// Retrieves the name of the `Err` variant.
+    pub const fn err(&self) -> &'static str {
+        self.err
+    }
+
+    /// Retrieves the property which holds the value of the `Ok` variant.
+    pub const fn ok_property(&self) -> &'static str {
+        self.ok_property
+    }
+
+    /// Retrieves the property which holds the error of the `Err` variant.
+    pub const fn err_property(&self) -> &'static str {
+        self.err_property
+    }
+}
+
+/// Runs `export` with the given shape of `Result`, in the context of the current export.
+///
+/// This is used by `#[derive(Exported)]` for fields with `#[arri(result = SHAPE)]`.
+pub fn with_result_shape<R>(shape: ResultShape, export: impl FnOnce() -> R) -> R {
+    context::modified(|context| context.set_result_shape(shape), export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExportContext;
+
+    #[test]
+    fn test_result_shape_is_scoped() {
+        let shape = ResultShape::new().set_discriminator("status");
+
+        with_result_shape(shape, || {
+            assert_eq!(ExportContext::current().result_shape(), shape)
+        });
+        assert_eq!(ExportContext::current().result_shape(), ResultShape::new());
+    }
+}
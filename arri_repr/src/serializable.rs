@@ -1,11 +1,18 @@
 // TODO: create a macro which automatically generates this implementation with a derive
-use std::{any::type_name, collections::HashMap, fmt::Debug};
+use std::{
+    any::{TypeId, type_name},
+    cell::Cell,
+    collections::HashMap,
+    fmt::{self, Debug, Write},
+};
 
 use downcast_rs::{Downcast, impl_downcast};
 
-use crate::{MetadataSchema, serializer::Serializer};
+use crate::{
+    MetadataSchema, Modifier, OutputOptions, SchemaError, context, output, serializer::Serializer,
+};
 
-/// Escapes special characters in a string for JSON output.
+/// Writes a string as a JSON string, escaping special characters.
 ///
 /// Follows the JSON specification for string escaping:
 /// - `"` -> `\"`
@@ -17,116 +24,177 @@ use crate::{MetadataSchema, serializer::Serializer};
 /// - Carriage return -> `\r`
 /// - Tab -> `\t`
 /// - Control characters and other special Unicode -> `\uXXXX`
-fn escape_json_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() + 16);
-    result.push('"');
-
-    for ch in s.chars() {
-        match ch {
-            '"' => result.push_str("\\\""),
-            '\\' => result.push_str("\\\\"),
-            '/' => result.push_str("\\/"),
-            '\u{0008}' => result.push_str("\\b"), // backspace
-            '\u{000C}' => result.push_str("\\f"), // form feed
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            ch if ch.is_control() => {
-                // Escape other control characters as \uXXXX
-                result.push_str(&format!("\\u{:04x}", ch as u32));
-            }
-            ch => result.push(ch),
+///
+/// Runs of characters which don't need escaping are written at once.
+pub(crate) fn write_json_string(out: &mut dyn Write, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+
+    let mut start = 0;
+    for (index, ch) in s.char_indices() {
+        let escaped = match ch {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '/' => "\\/",
+            '\u{0008}' => "\\b", // backspace
+            '\u{000C}' => "\\f", // form feed
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            ch if ch.is_control() => "",
+            _ => continue,
+        };
+
+        out.write_str(&s[start..index])?;
+        match escaped.is_empty() {
+            // Escape other control characters as \uXXXX
+            true => write!(out, "\\u{:04x}", ch as u32)?,
+            false => out.write_str(escaped)?,
         }
+        start = index + ch.len_utf8();
     }
 
-    result.push('"');
-    result
+    out.write_str(&s[start..])?;
+    out.write_char('"')
 }
 
-/// Triggers a panic with a detailed error message, including the type, serialized data,
-/// and value that caused the issue. This function is intended to report bugs.
-///
-/// # Parameters
-/// - `message`: A message describing the context of the panic.
-/// - `serialized`: The serialized representation of the data.
-/// - `value`: The original value that caused the issue.
-///
-/// # Panics
-/// This function always panics with a formatted message containing the provided details.
-///
-/// # Note
-/// The panic message includes a link to report bugs, encouraging users to provide feedback.
-fn do_panic<T>(message: impl std::fmt::Display, serialized: impl Debug, value: impl Debug) -> !
-where
-    T: ?Sized,
-{
-    panic!(
-        "{}!\n\
-        This is a bug, please report it @ <https://github.com/Arthurdw/ronky/issues>\n\
-        Type: {:?}\n\
-        Serialized: {:?}\n\
-        Value: {:?}",
-        message,
-        type_name::<T>(),
-        serialized,
-        value
-    );
+thread_local! {
+    /// The object being written by the default [`Serializable::serialize`], which the default
+    /// [`Serializable::write_to`] would call back if neither is implemented.
+    static DEFAULT_SERIALIZE: Cell<Option<(*const (), TypeId)>> = const { Cell::new(None) };
+}
+
+/// Identifies an object by its address and type, as nested objects may share an address.
+fn object_key<T: Serializable + ?Sized>(object: &T) -> (*const (), TypeId) {
+    ((object as *const T).cast::<()>(), TypeId::of::<T>())
+}
+
+/// Restores the object of the previous default [`Serializable::serialize`] when dropped.
+struct DefaultSerializeGuard(Option<(*const (), TypeId)>);
+
+impl Drop for DefaultSerializeGuard {
+    fn drop(&mut self) {
+        DEFAULT_SERIALIZE.set(self.0);
+    }
 }
 
 /// A trait for types that can be serialized into a string representation.
 ///
-/// This trait also provides default implementations for setting metadata,
-/// nullability, and renaming, which trigger a panic if not implemented.
+/// Implementations write their JSON representation with [`Serializable::write_to`], which
+/// streams nested values into the same output instead of building a string per value.
+/// [`Serializable::serialize`] is built on top of it. Implementing `serialize` instead still
+/// works, `write_to` then writes the string it builds. One of them must be implemented, as
+/// serializing a type which implements neither panics.
+///
+/// Schema forms which support metadata, nullability or renaming implement the corresponding
+/// `try_set_*` method, the default implementations return a [`SchemaError`].
 pub trait Serializable: Downcast {
+    /// Writes the JSON representation of the object into `out`.
+    ///
+    /// Absent values (see [`Serializable::is_absent`]) write nothing.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `out` fails.
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        if DEFAULT_SERIALIZE.get() == Some(object_key(self)) {
+            panic!(
+                "`Serializable` for `{}` implements neither `write_to` nor `serialize`",
+                type_name::<Self>()
+            );
+        }
+
+        match self.serialize() {
+            Some(serialized) => out.write_str(&serialized),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns whether the object is absent, in which case it is left out of objects and arrays.
+    fn is_absent(&self) -> bool {
+        false
+    }
+
     /// Serializes the object into an optional string representation.
     ///
     /// # Returns
-    /// An `Option<String>` containing the serialized representation, or `None` if serialization fails.
-    fn serialize(&self) -> Option<String>;
+    /// An `Option<String>` containing the serialized representation, or `None` if the object is
+    /// absent or serialization fails.
+    fn serialize(&self) -> Option<String> {
+        if self.is_absent() {
+            return None;
+        }
+
+        let previous = DEFAULT_SERIALIZE.replace(Some(object_key(self)));
+        let _guard = DefaultSerializeGuard(previous);
+
+        let mut out = String::new();
+        self.write_to(&mut out).ok()?;
+        (!out.is_empty()).then_some(out)
+    }
 
     /// Sets metadata for the object.
     ///
-    /// # Arguments
-    /// - `metadata`: The metadata to set.
+    /// # Errors
+    /// Returns [`SchemaError::Unsupported`] if the schema can't carry metadata.
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), SchemaError> {
+        let _ = metadata;
+        Err(SchemaError::unsupported(Modifier::Metadata, self))
+    }
+
+    /// Sets the nullability of the object.
+    ///
+    /// # Errors
+    /// Returns [`SchemaError::Unsupported`] if the schema can't be nullable.
+    fn try_set_nullable(&mut self, nullable: bool) -> Result<(), SchemaError> {
+        let _ = nullable;
+        Err(SchemaError::unsupported(Modifier::Nullable, self))
+    }
+
+    /// Renames the object.
+    ///
+    /// # Errors
+    /// Returns [`SchemaError::Unsupported`] if the schema can't be renamed.
+    fn try_set_rename(&mut self, new_name: &str) -> Result<(), SchemaError> {
+        let _ = new_name;
+        Err(SchemaError::unsupported(Modifier::Rename, self))
+    }
+
+    /// Sets metadata for the object, see [`Serializable::try_set_metadata`].
+    ///
+    /// An unsupported modifier fails the [`ExportContext::try_export`](crate::ExportContext)
+    /// in progress.
     ///
     /// # Panics
-    /// This method panics if not implemented for the type.
+    /// Panics if the modifier is unsupported outside of a fallible export.
     fn set_metadata(&mut self, metadata: MetadataSchema) {
-        do_panic::<Self>(
-            "set_metadata is not implemented for this type",
-            self.serialize(),
-            metadata,
-        );
+        if let Err(error) = self.try_set_metadata(metadata) {
+            context::report(error);
+        }
     }
 
-    /// Sets the nullability of the object.
+    /// Sets the nullability of the object, see [`Serializable::try_set_nullable`].
     ///
-    /// # Arguments
-    /// - `nullable`: A boolean indicating whether the object is nullable.
+    /// An unsupported modifier fails the [`ExportContext::try_export`](crate::ExportContext)
+    /// in progress.
     ///
     /// # Panics
-    /// This method panics if not implemented for the type.
+    /// Panics if the modifier is unsupported outside of a fallible export.
     fn set_nullable(&mut self, nullable: bool) {
-        do_panic::<Self>(
-            "set_nullable is not implemented for this type",
-            self.serialize(),
-            nullable,
-        );
+        if let Err(error) = self.try_set_nullable(nullable) {
+            context::report(error);
+        }
     }
 
-    /// Renames the object.
+    /// Renames the object, see [`Serializable::try_set_rename`].
     ///
-    /// # Arguments
-    /// - `new_name`: The new name to assign to the object.
+    /// An unsupported modifier fails the [`ExportContext::try_export`](crate::ExportContext)
+    /// in progress.
     ///
     /// # Panics
-    /// This method panics if not implemented for the type.
+    /// Panics if the modifier is unsupported outside of a fallible export.
     fn set_rename(&mut self, new_name: &str) {
-        do_panic::<Self>(
-            "set_rename is not implemented for this type",
-            self.serialize(),
-            new_name,
-        );
+        if let Err(error) = self.try_set_rename(new_name) {
+            context::report(error);
+        }
     }
 }
 
@@ -134,43 +202,79 @@ impl_downcast!(Serializable);
 
 impl Debug for dyn Serializable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.serialize().unwrap_or("undefined".to_string()))
+        match self.is_absent() {
+            true => f.write_str("undefined"),
+            false => self.write_to(f),
+        }
+    }
+}
+
+impl Debug for dyn Serializable + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn Serializable).fmt(f)
     }
 }
 
+/// Schemas are equal when their canonical forms are, so the order of properties doesn't matter
+/// but their metadata does. See [`eq_ignoring_docs`](crate::fingerprint::eq_ignoring_docs) to ignore
+/// descriptions and deprecation notes.
 impl PartialEq for dyn Serializable {
     fn eq(&self, other: &Self) -> bool {
-        self.serialize() == other.serialize()
+        let canonical = OutputOptions::new().set_canonical(true);
+        canonical.serialize(self) == canonical.serialize(other)
     }
 }
 
 impl Eq for dyn Serializable {}
 
+impl PartialEq for dyn Serializable + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        (self as &dyn Serializable) == (other as &dyn Serializable)
+    }
+}
+
 impl Serializable for &'static str {
-    fn serialize(&self) -> Option<String> {
-        escape_json_string(self).into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        write_json_string(out, self)
     }
 }
 
 impl Serializable for String {
-    fn serialize(&self) -> Option<String> {
-        escape_json_string(self).into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        write_json_string(out, self)
     }
 }
 
 impl Serializable for bool {
-    fn serialize(&self) -> Option<String> {
-        self.to_string().into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        write!(out, "{}", self)
     }
 }
 
-// Macro to generate Serializable implementations for numeric types
-macro_rules! impl_serializable_for_numeric {
+// Macro to generate Serializable implementations for integer types
+macro_rules! impl_serializable_for_integer {
     ($($type:ty),* $(,)?) => {
         $(
             impl Serializable for $type {
-                fn serialize(&self) -> Option<String> {
-                    self.to_string().into()
+                fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+                    write!(out, "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+// Macro to generate Serializable implementations for floating point types
+macro_rules! impl_serializable_for_float {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Serializable for $type {
+                fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+                    // JSON has no representation for NaN or infinity
+                    match self.is_finite() {
+                        true => write!(out, "{}", self),
+                        false => out.write_str("null"),
+                    }
                 }
             }
         )*
@@ -178,102 +282,128 @@ macro_rules! impl_serializable_for_numeric {
 }
 
 // Numeric type implementations
-impl_serializable_for_numeric!(
-    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+impl_serializable_for_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,
 );
+impl_serializable_for_float!(f32, f64);
 
 impl Serializable for () {
-    fn serialize(&self) -> Option<String> {
-        "null".to_string().into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        out.write_str("null")
     }
 }
 
 impl<T: Serializable> Serializable for Vec<T> {
-    fn serialize(&self) -> Option<String> {
-        let serialized_elements: Vec<String> = self.iter().filter_map(|e| e.serialize()).collect();
-        format!("[{}]", serialized_elements.join(",")).into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        output::open(out, '[')?;
+        let mut empty = true;
+        for element in self.iter().filter(|e| !e.is_absent()) {
+            let mut separator = String::new();
+            output::separate(&mut separator, empty)?;
+            empty &= !output::write_prefixed(out, &separator, |out| element.write_to(out))?;
+        }
+        output::close(out, ']', empty)
     }
 }
 
 impl<T: Serializable> Serializable for Option<T> {
-    fn serialize(&self) -> Option<String> {
-        self.as_ref().and_then(|value| value.serialize())
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        match self {
+            Some(value) => value.write_to(out),
+            None => Ok(()),
+        }
+    }
+
+    fn is_absent(&self) -> bool {
+        self.as_ref().is_none_or(Serializable::is_absent)
     }
 }
 
 impl<T: Serializable> Serializable for HashMap<String, T> {
-    fn serialize(&self) -> Option<String> {
-        self.iter()
-            .fold(Serializer::builder(), |mut builder, (key, value)| {
-                builder.set(key, value);
-                builder
-            })
-            .build()
-            .into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        // The iteration order of a HashMap is random, so the keys are sorted
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(key, _)| *key);
+
+        let mut serializer = Serializer::new(out);
+        for (key, value) in entries {
+            serializer.set(key, value);
+        }
+        serializer.finish()
     }
 }
 
 impl<T: Serializable> Serializable for indexmap::IndexMap<String, T> {
-    fn serialize(&self) -> Option<String> {
-        self.iter()
-            .fold(Serializer::builder(), |mut builder, (key, value)| {
-                builder.set(key, value);
-                builder
-            })
-            .build()
-            .into()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        let mut serializer = Serializer::new(out);
+        for (key, value) in self {
+            serializer.set(key, value);
+        }
+        serializer.finish()
     }
 }
 
 impl<T: Serializable> Serializable for Box<T> {
-    fn serialize(&self) -> Option<String> {
-        self.as_ref().serialize()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        self.as_ref().write_to(out)
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.as_mut().set_metadata(metadata)
+    fn is_absent(&self) -> bool {
+        self.as_ref().is_absent()
     }
 
-    fn set_nullable(&mut self, nullable: bool) {
-        self.as_mut().set_nullable(nullable)
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), SchemaError> {
+        self.as_mut().try_set_metadata(metadata)
     }
 
-    fn set_rename(&mut self, new_name: &str) {
-        self.as_mut().set_rename(new_name)
+    fn try_set_nullable(&mut self, nullable: bool) -> Result<(), SchemaError> {
+        self.as_mut().try_set_nullable(nullable)
+    }
+
+    fn try_set_rename(&mut self, new_name: &str) -> Result<(), SchemaError> {
+        self.as_mut().try_set_rename(new_name)
     }
 }
 
 impl Serializable for Box<dyn Serializable> {
-    fn serialize(&self) -> Option<String> {
-        self.as_ref().serialize()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        self.as_ref().write_to(out)
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.as_mut().set_metadata(metadata)
+    fn is_absent(&self) -> bool {
+        self.as_ref().is_absent()
     }
 
-    fn set_nullable(&mut self, nullable: bool) {
-        self.as_mut().set_nullable(nullable)
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), SchemaError> {
+        self.as_mut().try_set_metadata(metadata)
     }
 
-    fn set_rename(&mut self, new_name: &str) {
-        self.as_mut().set_rename(new_name)
+    fn try_set_nullable(&mut self, nullable: bool) -> Result<(), SchemaError> {
+        self.as_mut().try_set_nullable(nullable)
+    }
+
+    fn try_set_rename(&mut self, new_name: &str) -> Result<(), SchemaError> {
+        self.as_mut().try_set_rename(new_name)
     }
 }
 
 #[cfg(feature = "chrono")]
 impl Serializable for chrono::DateTime<chrono::FixedOffset> {
-    fn serialize(&self) -> Option<String> {
-        self.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
-            .serialize()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        write_json_string(
+            out,
+            &self.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        )
     }
 }
 
 #[cfg(feature = "chrono")]
 impl Serializable for chrono::DateTime<chrono::Utc> {
-    fn serialize(&self) -> Option<String> {
-        self.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
-            .serialize()
+    fn write_to(&self, out: &mut dyn Write) -> fmt::Result {
+        write_json_string(
+            out,
+            &self.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        )
     }
 }
 
@@ -324,6 +454,31 @@ mod tests {
         assert_eq!(&mock1 as &dyn Serializable, &mock2 as &dyn Serializable);
     }
 
+    #[test]
+    fn test_write_to_skips_absent_elements() {
+        let mut out = String::new();
+        vec![Some(1), None, Some(2)].write_to(&mut out).unwrap();
+        assert_eq!(out, "[1,2]");
+    }
+
+    #[test]
+    fn test_write_json_string_escapes() {
+        let mut out = String::new();
+        write_json_string(&mut out, "tab\there \"quoted\" \u{1} é/").unwrap();
+        assert_eq!(out, r#""tab\there \"quoted\" \u0001 é\/""#);
+        assert_eq!(
+            serde_json::from_str::<String>(&out).unwrap(),
+            "tab\there \"quoted\" \u{1} é/"
+        );
+    }
+
+    #[test]
+    fn test_serialize_non_finite_floats() {
+        assert_eq!(f64::NAN.serialize(), Some("null".to_string()));
+        assert_eq!(f32::NEG_INFINITY.serialize(), Some("null".to_string()));
+        assert_eq!(0.5f64.serialize(), Some("0.5".to_string()));
+    }
+
     #[test]
     fn test_serialize_str() {
         let value = "Hello, world!";
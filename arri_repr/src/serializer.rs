@@ -1,27 +1,95 @@
-use crate::Serializable;
+use std::fmt::{self, Write};
 
-/// A serializer for building JSON-like string representations.
+use crate::{Serializable, output, serializable::write_json_string};
+
+/// A serializer which streams a JSON object into an output.
+///
+/// The object is written while its entries are set, so nested schemas are written into the
+/// same output instead of being built as separate strings. The layout follows the
+/// [`OutputOptions`](crate::OutputOptions) of the serialization in progress: canonical output
+/// collects the entries and writes them sorted by key when the object is finished.
+///
+/// # Example
+///
+/// ```
+/// use arri_repr::Serializer;
 ///
-/// This struct provides methods to construct a serialized string
-/// with key-value pairs, following a JSON-like format.
-pub struct Serializer {
-    /// The output string being constructed.
-    pub(crate) out: String,
+/// let mut out = String::new();
+/// Serializer::new(&mut out)
+///     .set("type", &"string")
+///     .set("isNullable", &Some(true))
+///     .finish()
+///     .unwrap();
+/// assert_eq!(out, r#"{"type":"string","isNullable":true}"#);
+/// ```
+pub struct Serializer<'a> {
+    /// The output the object is written to.
+    out: Output<'a>,
+    /// Whether an entry has been written, so the next one is preceded by a comma.
+    has_entries: bool,
+    /// The first error which occurred while writing, after which nothing is written.
+    result: fmt::Result,
+    /// The entries with their serialized values, which are sorted before being written in
+    /// canonical output.
+    entries: Option<Vec<(String, String)>>,
+    /// Whether the closing brace has been written.
+    finished: bool,
+}
+
+/// The output of a [`Serializer`], which owns its output when created with the deprecated
+/// [`Serializer::builder`].
+enum Output<'a> {
+    Borrowed(&'a mut dyn Write),
+    Owned(String),
+}
+
+impl Output<'_> {
+    /// Retrieves the output to write to.
+    fn get(&mut self) -> &mut dyn Write {
+        match self {
+            Self::Borrowed(out) => *out,
+            Self::Owned(out) => out,
+        }
+    }
 }
 
-impl Serializer {
-    /// Creates a new `Serializer` instance with an initial opening brace `{`.
+impl<'a> Serializer<'a> {
+    /// Starts an object in `out`, by writing its opening brace `{`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Serializer` instance.
+    pub fn new(out: &'a mut dyn Write) -> Self {
+        Self::start(Output::Borrowed(out))
+    }
+
+    /// Starts an object in a string owned by the serializer, which is returned by
+    /// [`Serializer::build`].
     ///
     /// # Returns
     ///
     /// A new `Serializer` instance.
-    pub fn builder() -> Self {
+    #[deprecated(note = "write into an output with `Serializer::new` and `Serializer::finish`")]
+    pub fn builder() -> Serializer<'static> {
+        Serializer::start(Output::Owned(String::new()))
+    }
+
+    /// Starts an object in `out`, by writing its opening brace `{`.
+    fn start(mut out: Output<'a>) -> Self {
+        let canonical = output::current().is_canonical();
+        let result = output::open(out.get(), '{');
         Self {
-            out: String::from("{"),
+            out,
+            has_entries: false,
+            result,
+            entries: canonical.then(Vec::new),
+            finished: false,
         }
     }
 
-    /// Adds a key-value pair to the serialized output.
+    /// Writes a key-value pair to the object, unless the value is absent.
+    ///
+    /// The key is escaped as a JSON string.
     ///
     /// # Arguments
     ///
@@ -32,37 +100,115 @@ impl Serializer {
     ///
     /// A mutable reference to the `Serializer` instance.
     pub fn set(&mut self, key: &str, value: &impl Serializable) -> &mut Self {
-        if let Some(value) = value.serialize() {
-            self.out.push_str(&format!("\"{}\":{},", key, value));
+        match value.is_absent() {
+            true => self,
+            false => self.set_with(key, |out| value.write_to(out)),
+        }
+    }
+
+    /// Writes a key-value pair to the object with a function writing the value, unless it
+    /// writes nothing.
+    pub(crate) fn set_with(
+        &mut self,
+        key: &str,
+        value: impl FnOnce(&mut dyn Write) -> fmt::Result,
+    ) -> &mut Self {
+        if self.result.is_err() {
+            return self;
         }
 
+        self.result = match &mut self.entries {
+            Some(entries) => {
+                let mut serialized = String::new();
+                value(&mut serialized).map(|_| {
+                    if !serialized.is_empty() {
+                        entries.push((key.to_string(), serialized))
+                    }
+                })
+            }
+            None => self.write_entry(key, value),
+        };
         self
     }
 
-    /// Finalizes the serialized output by closing the JSON-like structure.
+    /// Writes a key-value pair which documents the schema, such as a description. These are
+    /// left out of stripped output.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `Serializer` instance.
+    pub fn set_documentation(&mut self, key: &str, value: &impl Serializable) -> &mut Self {
+        match output::current().is_stripped() {
+            true => self,
+            false => self.set(key, value),
+        }
+    }
+
+    /// Writes a single entry, preceded by a comma if needed, unless the value writes nothing.
+    fn write_entry(
+        &mut self,
+        key: &str,
+        value: impl FnOnce(&mut dyn Write) -> fmt::Result,
+    ) -> fmt::Result {
+        let mut prefix = String::new();
+        output::separate(&mut prefix, !self.has_entries)?;
+        write_json_string(&mut prefix, key)?;
+        output::colon(&mut prefix)?;
+
+        self.has_entries |= output::write_prefixed(self.out.get(), &prefix, value)?;
+        Ok(())
+    }
+
+    /// Finishes the object by writing its closing brace `}`.
+    ///
+    /// # Errors
     ///
-    /// If the output ends with a trailing comma, it is removed before appending
-    /// the closing brace `}`.
+    /// Returns the first error which occurred while writing the object.
+    pub fn finish(&mut self) -> fmt::Result {
+        self.result?;
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        if let Some(mut entries) = self.entries.take() {
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (key, value) in entries {
+                self.write_entry(&key, |out| out.write_str(&value))?;
+            }
+        }
+
+        output::close(self.out.get(), '}', !self.has_entries)
+    }
+
+    /// Finishes the object and returns the output of a serializer created with
+    /// [`Serializer::builder`].
     ///
     /// # Returns
     ///
-    /// A `String` containing the complete serialized output.
+    /// A `String` containing the complete serialized output, which is empty for a serializer
+    /// created with [`Serializer::new`].
+    #[deprecated(note = "write into an output with `Serializer::new` and `Serializer::finish`")]
     pub fn build(&mut self) -> String {
-        if self.out.ends_with(',') {
-            self.out.pop();
+        let _ = self.finish();
+        match &self.out {
+            Output::Owned(out) => out.clone(),
+            Output::Borrowed(_) => String::new(),
         }
-
-        self.out.clone() + "}"
     }
 }
 
-impl From<Serializer> for String {
-    fn from(mut serializer: Serializer) -> Self {
+/// Finishes the object of a serializer created with the deprecated [`Serializer::builder`],
+/// see [`Serializer::build`].
+impl From<Serializer<'_>> for String {
+    fn from(mut serializer: Serializer<'_>) -> Self {
+        #[allow(deprecated)]
         serializer.build()
     }
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::Serializer;
     use crate::Serializable;
@@ -106,6 +252,31 @@ mod tests {
         assert_eq!(Serializer::builder().build(), "{}");
     }
 
+    #[test]
+    fn test_serializer_escapes_keys() {
+        let mut out = String::new();
+        Serializer::new(&mut out)
+            .set("say \"hi\"\n", &1)
+            .finish()
+            .unwrap();
+
+        let serialized: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(serialized, serde_json::json!({ "say \"hi\"\n": 1 }));
+    }
+
+    #[test]
+    fn test_serializer_non_finite_floats() {
+        let mut out = String::new();
+        Serializer::new(&mut out)
+            .set("nan", &f64::NAN)
+            .set("infinity", &f32::INFINITY)
+            .set("finite", &1.5)
+            .finish()
+            .unwrap();
+
+        assert_eq!(out, r#"{"nan":null,"infinity":null,"finite":1.5}"#);
+    }
+
     #[test]
     fn test_serializer_option() {
         let mock = MockSerializable {
@@ -149,4 +320,51 @@ mod tests {
             })
         );
     }
+
+    /// Implements `serialize` only, leaving `is_absent` to its default.
+    struct Legacy(Option<u8>);
+
+    impl Serializable for Legacy {
+        fn serialize(&self) -> Option<String> {
+            self.0.map(|value| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_serializer_skips_legacy_absent_values() {
+        let mut out = String::new();
+        Serializer::new(&mut out)
+            .set("a", &Legacy(None))
+            .set("b", &Legacy(Some(1)))
+            .finish()
+            .unwrap();
+        assert_eq!(out, r#"{"b":1}"#);
+
+        let values = vec![Legacy(None), Legacy(Some(2)), Legacy(None), Legacy(Some(3))];
+        assert_eq!(values.serialize().unwrap(), "[2,3]");
+        assert_eq!(Legacy(None).serialize(), None);
+
+        let canonical = crate::OutputOptions::new().set_canonical(true);
+        let mut out = String::new();
+        canonical
+            .write_to(
+                &MockSerializable {
+                    value1: Legacy(None),
+                    value2: Legacy(Some(1)),
+                },
+                &mut out,
+            )
+            .unwrap();
+        assert_eq!(out, r#"{"value2":1}"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "implements neither `write_to` nor `serialize`")]
+    fn test_serializable_without_implementation() {
+        struct Empty;
+
+        impl Serializable for Empty {}
+
+        Empty.serialize();
+    }
 }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use indexmap::IndexMap;
 
 use crate::{EnumTransformation, MetadataSchema, Serializable, serializer::Serializer};
@@ -5,7 +7,7 @@ use crate::{EnumTransformation, MetadataSchema, Serializable, serializer::Serial
 ///
 /// This struct defines the discriminator, mapping of variants, optional metadata,
 /// and transformations associated with a tagged union.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub struct TaggedUnionSchema {
     /// The discriminator used to identify the variant in the tagged union.
     pub discriminator: String,
@@ -17,6 +19,9 @@ pub struct TaggedUnionSchema {
     /// Optional metadata associated with the tagged union schema.
     pub metadata: Option<MetadataSchema>,
 
+    /// Indicates whether the tagged union allows null values.
+    pub is_nullable: Option<bool>,
+
     /// A list of transformations applied to the variant names.
     pub transformations: Vec<EnumTransformation>,
 }
@@ -71,23 +76,30 @@ impl Default for TaggedUnionSchema {
             discriminator: "type".to_string(),
             mapping: IndexMap::new(),
             metadata: None,
+            is_nullable: None,
             transformations: Vec::new(),
         }
     }
 }
 
 impl Serializable for TaggedUnionSchema {
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("discriminator", &self.discriminator)
             .set("mapping", &self.mapping)
             .set("metadata", &self.metadata)
-            .build()
-            .into()
+            .set("isNullable", &self.is_nullable)
+            .finish()
+    }
+
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), crate::SchemaError> {
+        self.is_nullable = Some(is_nullable);
+        Ok(())
     }
 }
 
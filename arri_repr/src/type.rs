@@ -1,11 +1,14 @@
-use crate::{MetadataSchema, Serializable};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{DISCRIMINANTS_EXTENSION, Discriminants, ExtensionValue, MetadataSchema, Serializable};
 use ronky_derive::Serializable as SerializableDerive;
 
 /// Represents a schema for a type in an Arri schema.
 ///
 /// This struct defines the type, optional metadata, and nullability
 /// associated with the schema.
-#[derive(Debug, PartialEq, Eq, SerializableDerive)]
+#[derive(Debug, PartialEq, SerializableDerive)]
 pub struct TypeSchema {
     /// The type of the schema.
     r#type: Types,
@@ -35,6 +38,34 @@ impl TypeSchema {
             is_nullable: None,
         }
     }
+
+    /// Retrieves the type of the schema.
+    pub fn r#type(&self) -> &Types {
+        &self.r#type
+    }
+
+    /// Records the variants of an integer-backed enum, under the [`DISCRIMINANTS_EXTENSION`] of
+    /// the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `discriminants` - The names and discriminants of the variants.
+    pub fn set_discriminants(&mut self, discriminants: Discriminants) {
+        let mut metadata = MetadataSchema::new();
+        metadata.set_extension(
+            DISCRIMINANTS_EXTENSION,
+            ExtensionValue::Schema(Arc::new(discriminants)),
+        );
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+    }
+
+    /// Retrieves the variants of an integer-backed enum, if the schema is one.
+    pub fn discriminants(&self) -> Option<&Discriminants> {
+        self.metadata
+            .as_ref()?
+            .extension(DISCRIMINANTS_EXTENSION)?
+            .downcast_schema()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -55,7 +86,7 @@ pub enum Types {
 }
 
 impl Serializable for Types {
-    fn serialize(&self) -> Option<String> {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
         (match self {
             Self::String => "string",
             Self::Boolean => "boolean",
@@ -71,6 +102,6 @@ impl Serializable for Types {
             Self::Int64 => "int64",
             Self::Uint64 => "uint64",
         })
-        .serialize()
+        .write_to(out)
     }
 }
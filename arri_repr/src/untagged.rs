@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::{MetadataSchema, SchemaError, Serializable, serializer::Serializer};
+
+/// The metadata extension which lists the candidates of an untagged union.
+pub const CANDIDATES_EXTENSION: &str = "candidates";
+
+/// The schema of an untagged union, which `#[arri(untagged)]` enums export as.
+///
+/// Arri has no untagged unions, so the schema is written as an empty schema (which accepts any
+/// value) that lists the schemas of the variants under the [`CANDIDATES_EXTENSION`] of its
+/// metadata. The candidates are kept apart from the metadata, as their schemas can't be shared
+/// between threads like the metadata.
+#[derive(Debug, PartialEq)]
+pub struct UntaggedSchema {
+    /// The variants of the union.
+    candidates: Candidates,
+
+    /// Optional metadata associated with the schema.
+    metadata: Option<MetadataSchema>,
+
+    /// Indicates whether the schema allows null values.
+    is_nullable: Option<bool>,
+}
+
+impl UntaggedSchema {
+    /// Creates the schema of an untagged union, which is nullable unless stated otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The names and schemas of the variants, in the order they are tried.
+    pub fn new(candidates: Candidates) -> Self {
+        Self {
+            candidates,
+            metadata: None,
+            is_nullable: Some(true),
+        }
+    }
+
+    /// Retrieves the variants of the union.
+    pub fn candidates(&self) -> &Candidates {
+        &self.candidates
+    }
+}
+
+impl Serializable for UntaggedSchema {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        let metadata = self.metadata.clone().unwrap_or_default();
+        Serializer::new(out)
+            .set_with("metadata", |out| {
+                metadata.write_with_extension(out, CANDIDATES_EXTENSION, &self.candidates)
+            })
+            .set("isNullable", &self.is_nullable)
+            .finish()
+    }
+
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
+    }
+
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), SchemaError> {
+        self.is_nullable = Some(is_nullable);
+        Ok(())
+    }
+}
+
+/// The variants of an untagged union, in the order they are tried when decoding.
+///
+/// Serialized as `[{"name": "Text", "schema": {"type": "string"}}, ...]`, see
+/// [`UntaggedSchema`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Candidates(Vec<Candidate>);
+
+/// A variant of an untagged union.
+#[derive(Debug)]
+struct Candidate {
+    name: String,
+    schema: Box<dyn Serializable>,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.schema.as_ref() == other.schema.as_ref()
+    }
+}
+
+impl Eq for Candidate {}
+
+impl Candidates {
+    /// Creates an empty listing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variant to the listing, after the ones which were added before.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the variant.
+    /// * `schema` - The schema of the value of the variant.
+    pub fn add_variant(&mut self, name: impl ToString, schema: Box<dyn Serializable>) -> &mut Self {
+        self.0.push(Candidate {
+            name: name.to_string(),
+            schema,
+        });
+        self
+    }
+
+    /// Iterates over the names and schemas of the variants, in the order they are tried.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn Serializable)> {
+        self.0
+            .iter()
+            .map(|candidate| (candidate.name.as_str(), candidate.schema.as_ref()))
+    }
+}
+
+impl Serializable for Candidates {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.0.write_to(out)
+    }
+}
+
+impl Serializable for Candidate {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
+            .set("name", &self.name)
+            .set("schema", &self.schema)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OutputOptions, TypeSchema, Types};
+
+    fn schema() -> UntaggedSchema {
+        let mut candidates = Candidates::new();
+        candidates
+            .add_variant("Text", Box::new(TypeSchema::new(Types::String)))
+            .add_variant("Number", Box::new(TypeSchema::new(Types::Float64)));
+        UntaggedSchema::new(candidates)
+    }
+
+    #[test]
+    fn test_candidates_serialization() {
+        let schema = schema();
+
+        let names = schema
+            .candidates()
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Text", "Number"]);
+        assert_eq!(
+            schema.serialize(),
+            Some(
+                r#"{"metadata":{"x-extensions":{"candidates":[{"name":"Text","schema":{"type":"string"}},{"name":"Number","schema":{"type":"float64"}}]}},"isNullable":true}"#
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_candidates_alongside_metadata() {
+        let mut schema = schema();
+        schema.set_metadata(
+            MetadataSchema::new()
+                .set_id("Amount")
+                .set_extension("team", "billing")
+                .to_owned(),
+        );
+
+        let canonical = OutputOptions::new().set_canonical(true).set_indent(1);
+        assert_eq!(
+            canonical.serialize(&schema).unwrap(),
+            r#"{
+ "isNullable": true,
+ "metadata": {
+  "id": "Amount",
+  "x-extensions": {
+   "candidates": [
+    {
+     "name": "Text",
+     "schema": {
+      "type": "string"
+     }
+    },
+    {
+     "name": "Number",
+     "schema": {
+      "type": "float64"
+     }
+    }
+   ],
+   "team": "billing"
+  }
+ }
+}"#
+        );
+    }
+}
@@ -0,0 +1,558 @@
+//! Runtime validation of the constraints declared with `#[arri(min = ..., pattern = "...")]`.
+//!
+//! Arri schemas can't express ranges or patterns, so the derive records them in the metadata of
+//! the exported schema and generates a [`Validate`] implementation which enforces them. The
+//! helpers in this module are used by the generated code, but can be used to implement
+//! [`Validate`] by hand as well.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display},
+    net::{Ipv4Addr, Ipv6Addr},
+    sync::{LazyLock, OnceLock},
+};
+
+use regex::Regex;
+
+/// A constraint that was violated by a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The path to the offending value, using the names of the exported schema
+    /// (e.g. `address.city` or `tags[2]`). Empty for the root value.
+    pub path: String,
+    /// A human readable description of the violated constraint.
+    pub message: String,
+}
+
+impl ValidationError {
+    /// Creates a new `ValidationError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the offending value.
+    /// * `message` - A description of the violated constraint.
+    pub fn new(path: impl ToString, message: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A trait for values whose constraints can be checked at runtime.
+///
+/// `#[derive(Exported)]` implements this trait when the `validation` feature is enabled.
+pub trait Validate {
+    /// Checks the constraints of the value and pushes every violation to `errors`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of this value, used as prefix for the paths of the errors.
+    /// * `errors` - The collected violations.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    /// Checks the constraints of the value.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if all constraints hold, otherwise all violations.
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_at("", &mut errors);
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(value) = self {
+            value.validate_at(path, errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.as_ref().validate_at(path, errors);
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (idx, value) in self.iter().enumerate() {
+            value.validate_at(&index_path(path, idx), errors);
+        }
+    }
+}
+
+impl<K: Display, V: Validate, S> Validate for HashMap<K, V, S> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (key, value) in self.iter() {
+            value.validate_at(&join_path(path, &key.to_string()), errors);
+        }
+    }
+}
+
+impl<K: Display, V: Validate> Validate for BTreeMap<K, V> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (key, value) in self.iter() {
+            value.validate_at(&join_path(path, &key.to_string()), errors);
+        }
+    }
+}
+
+impl<K: Display, V: Validate, S> Validate for indexmap::IndexMap<K, V, S> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (key, value) in self.iter() {
+            value.validate_at(&join_path(path, &key.to_string()), errors);
+        }
+    }
+}
+
+/// Appends a property name to a path.
+pub fn join_path(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.to_string(),
+        false => format!("{}.{}", path, name),
+    }
+}
+
+/// Appends an element index to a path.
+pub fn index_path(path: &str, idx: usize) -> String {
+    format!("{}[{}]", path, idx)
+}
+
+/// Values that can be checked against `min` and `max`.
+///
+/// Integers are compared with the bound exactly, also beyond the 2^53 up to which `f64` holds
+/// every integer. Absent values (`None`) are not checked.
+pub trait Bounded {
+    /// Compares the value with a bound, or returns `None` if it is absent (or NaN).
+    fn compare(&self, bound: f64) -> Option<Ordering>;
+}
+
+macro_rules! impl_bounded_for_integer {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Bounded for $type {
+                fn compare(&self, bound: f64) -> Option<Ordering> {
+                    // Both limits are powers of two, so they are exact as `f64`, and the whole
+                    // part of a bound between them is exact as the integer type.
+                    let (lower, upper) = (<$type>::MIN as f64, <$type>::MAX as f64 + 1.0);
+                    if bound.is_nan() {
+                        None
+                    } else if bound < lower {
+                        Some(Ordering::Greater)
+                    } else if bound >= upper {
+                        Some(Ordering::Less)
+                    } else {
+                        let whole = bound.trunc();
+                        Some(
+                            self.cmp(&(whole as $type))
+                                .then(0.0.partial_cmp(&(bound - whole))?),
+                        )
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded_for_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,
+);
+
+impl Bounded for f32 {
+    fn compare(&self, bound: f64) -> Option<Ordering> {
+        (*self as f64).partial_cmp(&bound)
+    }
+}
+
+impl Bounded for f64 {
+    fn compare(&self, bound: f64) -> Option<Ordering> {
+        self.partial_cmp(&bound)
+    }
+}
+
+/// Values that can be checked against `min_length` and `max_length`.
+///
+/// Strings are measured in characters, collections in elements. Absent values (`None`) are not
+/// checked.
+pub trait Measured {
+    /// Returns the length of the value, or `None` if it is absent.
+    fn length(&self) -> Option<usize>;
+}
+
+impl Measured for String {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl Measured for &str {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> Measured for Vec<T> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V, S> Measured for HashMap<K, V, S> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V> Measured for BTreeMap<K, V> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V, S> Measured for indexmap::IndexMap<K, V, S> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Values that can be checked against a `pattern` or `format`.
+///
+/// Absent values (`None`) are not checked.
+pub trait Textual {
+    /// Returns the text of the value, or `None` if it is absent.
+    fn as_text(&self) -> Option<&str>;
+}
+
+impl Textual for String {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Textual for &str {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+macro_rules! impl_for_wrappers {
+    ($($trait:ident => $method:ident($($arg:ident: $arg_type:ty),*) -> $output:ty),* $(,)?) => {
+        $(
+            impl<T: $trait> $trait for Option<T> {
+                fn $method(&self, $($arg: $arg_type),*) -> Option<$output> {
+                    self.as_ref().and_then(|value| value.$method($($arg),*))
+                }
+            }
+
+            impl<T: $trait> $trait for Box<T> {
+                fn $method(&self, $($arg: $arg_type),*) -> Option<$output> {
+                    self.as_ref().$method($($arg),*)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_wrappers!(
+    Bounded => compare(bound: f64) -> Ordering,
+    Measured => length() -> usize,
+);
+
+impl<T: Textual> Textual for Option<T> {
+    fn as_text(&self) -> Option<&str> {
+        self.as_ref().and_then(Textual::as_text)
+    }
+}
+
+impl<T: Textual> Textual for Box<T> {
+    fn as_text(&self) -> Option<&str> {
+        self.as_ref().as_text()
+    }
+}
+
+/// A regular expression which is compiled on first use.
+///
+/// The derive checks the expression at compile time, so it is always valid when generated.
+#[derive(Debug)]
+pub struct Pattern {
+    source: &'static str,
+    regex: OnceLock<Regex>,
+}
+
+impl Pattern {
+    /// Creates a new `Pattern` from a regular expression.
+    pub const fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// Returns the regular expression of the pattern.
+    pub fn as_str(&self) -> &'static str {
+        self.source
+    }
+
+    /// Checks whether the pattern matches the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the regular expression is invalid.
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex
+            .get_or_init(|| Regex::new(self.source).expect("a valid regular expression"))
+            .is_match(value)
+    }
+}
+
+pub use arri_common::FORMATS;
+
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").unwrap());
+static UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+        .unwrap()
+});
+static URI: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*:\S+$").unwrap());
+static DATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])$").unwrap());
+static DATE_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])[Tt ]([01]\d|2[0-3]):[0-5]\d:([0-5]\d|60)(\.\d+)?([Zz]|[+-]([01]\d|2[0-3]):[0-5]\d)$",
+    )
+    .unwrap()
+});
+
+/// Checks whether the value follows one of the [`FORMATS`]. Unknown formats always match.
+pub fn matches_format(value: &str, format: &str) -> bool {
+    match format {
+        "email" => EMAIL.is_match(value),
+        "uuid" => UUID.is_match(value),
+        "uri" => URI.is_match(value),
+        "date" => DATE.is_match(value),
+        "date-time" => DATE_TIME.is_match(value),
+        "ipv4" => value.parse::<Ipv4Addr>().is_ok(),
+        "ipv6" => value.parse::<Ipv6Addr>().is_ok(),
+        _ => true,
+    }
+}
+
+/// Checks that the value is at least `min`.
+pub fn check_min(value: &impl Bounded, min: f64, path: &str, errors: &mut Vec<ValidationError>) {
+    if value.compare(min) == Some(Ordering::Less) {
+        errors.push(ValidationError::new(
+            path,
+            format!("must be at least {}", min),
+        ));
+    }
+}
+
+/// Checks that the value is at most `max`.
+pub fn check_max(value: &impl Bounded, max: f64, path: &str, errors: &mut Vec<ValidationError>) {
+    if value.compare(max) == Some(Ordering::Greater) {
+        errors.push(ValidationError::new(
+            path,
+            format!("must be at most {}", max),
+        ));
+    }
+}
+
+/// Checks that the length of the value is at least `min_length`.
+pub fn check_min_length(
+    value: &impl Measured,
+    min_length: usize,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(length) = value.length()
+        && length < min_length
+    {
+        errors.push(ValidationError::new(
+            path,
+            format!("must have a length of at least {}", min_length),
+        ));
+    }
+}
+
+/// Checks that the length of the value is at most `max_length`.
+pub fn check_max_length(
+    value: &impl Measured,
+    max_length: usize,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(length) = value.length()
+        && length > max_length
+    {
+        errors.push(ValidationError::new(
+            path,
+            format!("must have a length of at most {}", max_length),
+        ));
+    }
+}
+
+/// Checks that the value matches the pattern.
+pub fn check_pattern(
+    value: &impl Textual,
+    pattern: &Pattern,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(text) = value.as_text()
+        && !pattern.is_match(text)
+    {
+        errors.push(ValidationError::new(
+            path,
+            format!("must match the pattern `{}`", pattern.as_str()),
+        ));
+    }
+}
+
+/// Checks that the value follows the format.
+pub fn check_format(
+    value: &impl Textual,
+    format: &str,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(text) = value.as_text()
+        && !matches_format(text, format)
+    {
+        errors.push(ValidationError::new(
+            path,
+            format!("must be a valid {}", format),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut errors = Vec::new();
+        check_min(&5u8, 10.0, "age", &mut errors);
+        check_max(&5u8, 10.0, "age", &mut errors);
+        check_max(&Some(11.5f64), 10.0, "score", &mut errors);
+        check_min(&None::<i32>, 10.0, "missing", &mut errors);
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new("age", "must be at least 10"),
+                ValidationError::new("score", "must be at most 10"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounds_compare_large_integers_exactly() {
+        let mut errors = Vec::new();
+        check_max(
+            &(u64::MAX - 1),
+            18_446_744_073_709_549_568.0,
+            "u64",
+            &mut errors,
+        );
+        check_min(
+            &9_007_199_254_740_993i64,
+            9_007_199_254_740_992.0,
+            "i64",
+            &mut errors,
+        );
+        check_max(
+            &9_007_199_254_740_993i64,
+            9_007_199_254_740_992.0,
+            "i64",
+            &mut errors,
+        );
+        check_max(&u128::MAX, 1e40, "u128", &mut errors);
+        check_min(&-3i8, -3.5, "i8", &mut errors);
+        check_max(&-3i8, -3.5, "i8", &mut errors);
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new("u64", "must be at most 18446744073709550000"),
+                ValidationError::new("i64", "must be at most 9007199254740992"),
+                ValidationError::new("i8", "must be at most -3.5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lengths_count_characters() {
+        let mut errors = Vec::new();
+        check_max_length(&"héé".to_string(), 3, "name", &mut errors);
+        check_min_length(&vec![1, 2], 3, "tags", &mut errors);
+
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "tags",
+                "must have a length of at least 3"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_formats() {
+        assert!(matches_format("cat@ronky.dev", "email"));
+        assert!(!matches_format("cat at ronky.dev", "email"));
+        assert!(matches_format(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "uuid"
+        ));
+        assert!(!matches_format("67e55044-10b1-426f", "uuid"));
+        assert!(matches_format("https://ronky.dev/docs", "uri"));
+        assert!(!matches_format("ronky.dev", "uri"));
+        assert!(matches_format("2024-02-29", "date"));
+        assert!(!matches_format("2024-13-01", "date"));
+        assert!(matches_format("2024-02-29T12:30:00.123+01:00", "date-time"));
+        assert!(!matches_format("2024-02-29 25:30:00Z", "date-time"));
+        assert!(matches_format("127.0.0.1", "ipv4"));
+        assert!(!matches_format("256.0.0.1", "ipv4"));
+        assert!(matches_format("::1", "ipv6"));
+        assert!(!matches_format("127.0.0.1", "ipv6"));
+    }
+
+    #[test]
+    fn test_pattern_and_paths() {
+        static SLUG: Pattern = Pattern::new("^[a-z-]+$");
+
+        let mut errors = Vec::new();
+        check_pattern(&"Not A Slug".to_string(), &SLUG, "slug", &mut errors);
+        check_pattern(&"a-slug".to_string(), &SLUG, "slug", &mut errors);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].to_string(),
+            "slug: must match the pattern `^[a-z-]+$`"
+        );
+        assert_eq!(join_path("", "tags"), "tags");
+        assert_eq!(join_path("user", "tags"), "user.tags");
+        assert_eq!(index_path("user.tags", 2), "user.tags[2]");
+    }
+}
@@ -71,27 +71,34 @@ pub enum NumberValue {
 ///
 /// According to the Arri Type Definition spec, the "Empty Schema Form" is just `{}`,
 /// which accepts any value and rejects nothing. This is equivalent to `any` in TypeScript.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub(crate) struct AnySchema {
     /// Optional metadata (description, deprecation, etc.)
     metadata: Option<crate::MetadataSchema>,
+    /// Whether null is accepted explicitly, the empty form accepts it regardless
+    is_nullable: Option<bool>,
 }
 
 impl Serializable for AnySchema {
-    fn serialize(&self) -> Option<String> {
+    fn write_to(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
         // If there's metadata, include it in the output
-        if let Some(ref metadata) = self.metadata {
-            crate::Serializer::builder()
-                .set("metadata", metadata)
-                .build()
-                .into()
-        } else {
-            Some("{}".to_string())
-        }
+        crate::Serializer::new(out)
+            .set("metadata", &self.metadata)
+            .set("isNullable", &self.is_nullable)
+            .finish()
+    }
+
+    fn try_set_metadata(
+        &mut self,
+        metadata: crate::MetadataSchema,
+    ) -> Result<(), crate::SchemaError> {
+        crate::MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
-    fn set_metadata(&mut self, metadata: crate::MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_nullable(&mut self, is_nullable: bool) -> Result<(), crate::SchemaError> {
+        self.is_nullable = Some(is_nullable);
+        Ok(())
     }
 }
 
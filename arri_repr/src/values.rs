@@ -1,4 +1,44 @@
-use crate::{MetadataSchema, Serializable, serializer::Serializer};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{
+    EnumSchema, ExtensionValue, MetadataSchema, Serializable, TypeSchema, serializer::Serializer,
+};
+
+/// The metadata extension which holds the schema of the keys of a map.
+pub const KEY_SCHEMA_EXTENSION: &str = "keySchema";
+
+/// The schema of the keys of a map which aren't plain strings.
+///
+/// Keys are encoded as strings, so only the forms which describe a string encoding can be keys.
+#[derive(Debug, PartialEq)]
+pub enum KeySchema {
+    /// Keys encoded from a primitive type, such as integers or booleans.
+    Type(TypeSchema),
+    /// Keys which are the values of an enum.
+    Enum(EnumSchema),
+}
+
+impl Serializable for KeySchema {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        match self {
+            Self::Type(schema) => schema.write_to(out),
+            Self::Enum(schema) => schema.write_to(out),
+        }
+    }
+}
+
+impl From<TypeSchema> for KeySchema {
+    fn from(schema: TypeSchema) -> Self {
+        Self::Type(schema)
+    }
+}
+
+impl From<EnumSchema> for KeySchema {
+    fn from(schema: EnumSchema) -> Self {
+        Self::Enum(schema)
+    }
+}
 
 /// Represents a schema for values in an Arri schema.
 ///
@@ -34,21 +74,50 @@ impl ValuesSchema {
             is_nullable: None,
         }
     }
-}
 
-impl Serializable for ValuesSchema {
-    /// Serializes the `ValuesSchema` into a string representation.
+    /// Records the schema of the keys of the map, under the [`KEY_SCHEMA_EXTENSION`] of the
+    /// metadata.
     ///
-    /// # Returns
+    /// Arri maps are always keyed by strings, so this tells clients how to parse the keys, e.g.
+    /// as integers or as the values of an enum.
     ///
-    /// An `Option<String>` containing the serialized schema, or `None` if serialization fails.
-    fn serialize(&self) -> Option<String> {
-        Serializer::builder()
+    /// # Arguments
+    ///
+    /// * `key` - The schema of the keys, which are encoded as strings.
+    pub fn set_key_schema(&mut self, key: impl Into<KeySchema>) {
+        let mut metadata = MetadataSchema::new();
+        metadata.set_extension(
+            KEY_SCHEMA_EXTENSION,
+            ExtensionValue::Schema(Arc::new(key.into())),
+        );
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+    }
+
+    /// Retrieves the schema of the keys of the map, if they aren't plain strings.
+    pub fn key_schema(&self) -> Option<&KeySchema> {
+        self.metadata
+            .as_ref()?
+            .extension(KEY_SCHEMA_EXTENSION)?
+            .downcast_schema()
+    }
+}
+
+impl PartialEq for ValuesSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.values.as_ref() == other.values.as_ref()
+            && self.metadata == other.metadata
+            && self.is_nullable == other.is_nullable
+    }
+}
+
+impl Serializable for ValuesSchema {
+    /// Writes the `ValuesSchema` as JSON into `out`.
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        Serializer::new(out)
             .set("values", &self.values)
             .set("metadata", &self.metadata)
             .set("isNullable", &self.is_nullable)
-            .build()
-            .into()
+            .finish()
     }
 
     /// Sets the metadata for the schema.
@@ -56,8 +125,9 @@ impl Serializable for ValuesSchema {
     /// # Arguments
     ///
     /// * `metadata` - A `MetadataSchema` object to associate with the schema.
-    fn set_metadata(&mut self, metadata: MetadataSchema) {
-        self.metadata = Some(metadata);
+    fn try_set_metadata(&mut self, metadata: MetadataSchema) -> Result<(), crate::SchemaError> {
+        MetadataSchema::merge_into(&mut self.metadata, metadata);
+        Ok(())
     }
 
     /// Sets the nullability flag for the schema.
@@ -65,7 +135,8 @@ impl Serializable for ValuesSchema {
     /// # Arguments
     ///
     /// * `nullable` - A boolean indicating whether null values are allowed.
-    fn set_nullable(&mut self, nullable: bool) {
+    fn try_set_nullable(&mut self, nullable: bool) -> Result<(), crate::SchemaError> {
         self.is_nullable = Some(nullable);
+        Ok(())
     }
 }
@@ -0,0 +1,293 @@
+//! Checks that the examples attached with `#[arri(example = ...)]` match the exported schema.
+//!
+//! `#[derive(Exported)]` generates a test calling [`assert_examples`] for every non-generic type
+//! with examples, so an example that drifts from its type fails `cargo test`.
+
+use sonic_rs::{JsonContainerTrait, JsonValueTrait, Value};
+
+use crate::{Exportable, Serializable};
+
+/// Validates every example in the exported schema of `T` against the schema it is attached to.
+///
+/// # Returns
+///
+/// `Ok(())` if all examples are valid, otherwise a description of every violation, prefixed
+/// with the location of the example in the schema and the path in the example.
+pub fn check_examples<T: Exportable>() -> Result<(), Vec<String>> {
+    let schema = T::export().serialize().unwrap_or_default();
+    let schema: Value = sonic_rs::from_str(&schema)
+        .map_err(|err| vec![format!("The schema is not valid JSON: {}", err)])?;
+
+    let mut errors = Vec::new();
+    check_node(&schema, &schema, "#", &mut errors);
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors),
+    }
+}
+
+/// Panics with all violations if any example of `T` doesn't match its schema.
+///
+/// # Panics
+///
+/// Panics if [`check_examples`] reports any violation.
+pub fn assert_examples<T: Exportable>() {
+    if let Err(errors) = check_examples::<T>() {
+        panic!(
+            "The examples of {} don't match its schema:\n{}",
+            T::get_type_name(),
+            errors.join("\n")
+        );
+    }
+}
+
+/// Validates a JSON document against a serialized Arri schema.
+///
+/// # Arguments
+///
+/// * `schema` - The serialized schema, which also resolves `ref`s to the types defined in it.
+/// * `document` - The document to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the document is valid, otherwise every violation prefixed with its path.
+pub fn validate_document(schema: &Value, document: &Value) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    validate(schema, schema, document, "", &mut errors);
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors),
+    }
+}
+
+/// Checks the example of a schema node, and of all nodes nested in it.
+fn check_node(root: &Value, node: &Value, location: &str, errors: &mut Vec<String>) {
+    if let Some(example) = node
+        .get("metadata")
+        .and_then(|metadata| metadata.get("example"))
+    {
+        let mut violations = Vec::new();
+        validate(root, node, example, "", &mut violations);
+        errors.extend(
+            violations
+                .into_iter()
+                .map(|violation| format!("example at {}: {}", location, violation)),
+        );
+    }
+
+    for key in ["properties", "optionalProperties", "mapping"] {
+        if let Some(children) = node.get(key).and_then(|children| children.as_object()) {
+            for (name, child) in children.iter() {
+                check_node(
+                    root,
+                    child,
+                    &format!("{}/{}/{}", location, key, name),
+                    errors,
+                );
+            }
+        }
+    }
+
+    for key in ["elements", "values"] {
+        if let Some(child) = node.get(key) {
+            check_node(root, child, &format!("{}/{}", location, key), errors);
+        }
+    }
+}
+
+/// Finds the definition of a referenced type, which is the node with a matching `metadata.id`.
+fn find_definition<'a>(node: &'a Value, id: &str) -> Option<&'a Value> {
+    let is_definition = node.get("ref").is_none()
+        && node
+            .get("metadata")
+            .and_then(|metadata| metadata.get("id"))
+            .and_then(|node_id| node_id.as_str())
+            == Some(id);
+
+    if is_definition {
+        return Some(node);
+    }
+
+    ["properties", "optionalProperties", "mapping"]
+        .into_iter()
+        .filter_map(|key| node.get(key).and_then(|children| children.as_object()))
+        .flat_map(|children| children.iter().map(|(_, child)| child))
+        .chain(
+            ["elements", "values"]
+                .into_iter()
+                .filter_map(|key| node.get(key)),
+        )
+        .find_map(|child| find_definition(child, id))
+}
+
+/// Pushes a violation, prefixed with the path in the document.
+fn push(errors: &mut Vec<String>, path: &str, message: impl std::fmt::Display) {
+    match path.is_empty() {
+        true => errors.push(message.to_string()),
+        false => errors.push(format!("{}: {}", path, message)),
+    }
+}
+
+/// Validates a document against a schema node.
+fn validate(root: &Value, schema: &Value, document: &Value, path: &str, errors: &mut Vec<String>) {
+    if document.is_null() && schema.get("isNullable").and_then(|n| n.as_bool()) == Some(true) {
+        return;
+    }
+
+    if let Some(id) = schema.get("ref").and_then(|id| id.as_str()) {
+        match find_definition(root, id) {
+            Some(definition) => validate(root, definition, document, path, errors),
+            None => push(
+                errors,
+                path,
+                format!("the referenced type {} is not defined", id),
+            ),
+        }
+        return;
+    }
+
+    if let Some(ty) = schema.get("type").and_then(|ty| ty.as_str()) {
+        if let Err(expected) = validate_type(ty, document) {
+            push(errors, path, format!("expected {}", expected));
+        }
+    } else if let Some(variants) = schema.get("enum").and_then(|e| e.as_array()) {
+        let valid = document.as_str().is_some_and(|value| {
+            variants
+                .iter()
+                .any(|variant| variant.as_str() == Some(value))
+        });
+        if !valid {
+            push(errors, path, "expected one of the enum variants");
+        }
+    } else if let Some(elements) = schema.get("elements") {
+        match document.as_array() {
+            Some(array) => {
+                for (idx, element) in array.iter().enumerate() {
+                    validate(
+                        root,
+                        elements,
+                        element,
+                        &format!("{}[{}]", path, idx),
+                        errors,
+                    );
+                }
+            }
+            None => push(errors, path, "expected an array"),
+        }
+    } else if let Some(values) = schema.get("values") {
+        match document.as_object() {
+            Some(object) => {
+                for (key, value) in object.iter() {
+                    validate(root, values, value, &join(path, key), errors);
+                }
+            }
+            None => push(errors, path, "expected an object"),
+        }
+    } else if let Some(discriminator) = schema.get("discriminator").and_then(|d| d.as_str()) {
+        let tag = document.get(discriminator).and_then(|tag| tag.as_str());
+        match tag.and_then(|tag| schema.get("mapping").and_then(|mapping| mapping.get(tag))) {
+            Some(variant) => {
+                validate_properties(root, variant, document, path, Some(discriminator), errors)
+            }
+            None => push(
+                errors,
+                path,
+                format!("expected `{}` to name one of the variants", discriminator),
+            ),
+        }
+    } else if schema.get("properties").is_some() || schema.get("optionalProperties").is_some() {
+        validate_properties(root, schema, document, path, None, errors);
+    }
+}
+
+/// Validates an object against the `properties` and `optionalProperties` of a schema node.
+fn validate_properties(
+    root: &Value,
+    schema: &Value,
+    document: &Value,
+    path: &str,
+    discriminator: Option<&str>,
+    errors: &mut Vec<String>,
+) {
+    let Some(object) = document.as_object() else {
+        push(errors, path, "expected an object");
+        return;
+    };
+
+    let properties = schema.get("properties").and_then(|p| p.as_object());
+    let optional_properties = schema.get("optionalProperties").and_then(|p| p.as_object());
+
+    for (name, property) in properties.into_iter().flat_map(|p| p.iter()) {
+        match object.get(&name) {
+            Some(value) => validate(root, property, value, &join(path, name), errors),
+            None => push(errors, path, format!("missing the property `{}`", name)),
+        }
+    }
+
+    for (name, property) in optional_properties.into_iter().flat_map(|p| p.iter()) {
+        if let Some(value) = object.get(&name) {
+            validate(root, property, value, &join(path, name), errors);
+        }
+    }
+
+    if schema.get("isStrict").and_then(|s| s.as_bool()) == Some(true) {
+        for (name, _) in object.iter() {
+            let is_known = Some(name) == discriminator
+                || properties.is_some_and(|p| p.contains_key(&name))
+                || optional_properties.is_some_and(|p| p.contains_key(&name));
+            if !is_known {
+                push(errors, path, format!("unknown property `{}`", name));
+            }
+        }
+    }
+}
+
+/// Validates a document against a primitive Arri type.
+///
+/// # Returns
+///
+/// `Err` with a description of the expected value if the document doesn't match.
+fn validate_type(ty: &str, document: &Value) -> Result<(), &'static str> {
+    let in_range = |min: i64, max: i64| document.as_i64().is_some_and(|v| (min..=max).contains(&v));
+
+    let valid = match ty {
+        "string" | "timestamp" => document.is_str(),
+        "boolean" => document.is_boolean(),
+        "float32" | "float64" => document.is_number(),
+        "int8" => in_range(i8::MIN.into(), i8::MAX.into()),
+        "uint8" => in_range(0, u8::MAX.into()),
+        "int16" => in_range(i16::MIN.into(), i16::MAX.into()),
+        "uint16" => in_range(0, u16::MAX.into()),
+        "int32" => in_range(i32::MIN.into(), i32::MAX.into()),
+        "uint32" => in_range(0, u32::MAX.into()),
+        // 64-bit integers may be encoded as strings, as not every client can represent them
+        "int64" => {
+            document.as_i64().is_some()
+                || document.as_str().is_some_and(|v| v.parse::<i64>().is_ok())
+        }
+        "uint64" => {
+            document.as_u64().is_some()
+                || document.as_str().is_some_and(|v| v.parse::<u64>().is_ok())
+        }
+        _ => true,
+    };
+
+    match (valid, ty) {
+        (true, _) => Ok(()),
+        (false, "string") => Err("a string"),
+        (false, "timestamp") => Err("a timestamp string"),
+        (false, "boolean") => Err("a boolean"),
+        (false, "float32" | "float64") => Err("a number"),
+        (false, _) => Err("an integer within the range of the type"),
+    }
+}
+
+/// Appends a property name to a path in a document.
+fn join(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.to_string(),
+        false => format!("{}.{}", path, name),
+    }
+}
@@ -116,7 +116,7 @@
 //! // Just add water (and a derive macro)
 //! #[derive(Exported)]
 //! #[arri(transform = "uppercase")] // LOUD NOISES
-//! enum Result<T: Exportable, E: Exportable> {
+//! enum Result<T, E> {
 //!     /// When things go right (rarely, if you're me)
 //!     Ok(T),
 //!     /// When things go wrong (my default state)
@@ -148,7 +148,7 @@
 //!
 //! /// Metadata about things (and sometimes other things)
 //! #[derive(Exported)]
-//! struct About<T: Exportable> {
+//! struct About<T> {
 //!     /// What we called it before marketing got involved
 //!     #[deprecated(since = "1.0.0", note = "Use `firstName` and `lastName` instead")]
 //!     name: String,
@@ -282,10 +282,44 @@
 //! ### Attribute Options
 //!
 //! - `#[arri(strict)]` - No extra properties allowed
+//! - `#[arri(bound = "T: Exportable")]` - Replace the `Exportable` bounds which are
+//!   inferred for the type parameters and associated types used in fields
+//! - `#[arri(partial)]` - Generate a `{Name}Patch` companion with every property optional
 //! - `#[arri(transform = "snake_case")]` - Transform enum variant names
 //! - `#[arri(discriminator = "type")]` - Set discriminator field name
+//! - `#[arri(repr = "int")]` - Export an enum with an integer `#[repr(...)]` as its discriminants,
+//!   see `IntEnum` and `ronky::int_enum` for the matching serialization
+//! - `#[arri(untagged)]` - Export an enum as an untagged union of its variants, see
+//!   `ronky::untagged` for the matching serialization
+//! - `#[ronky::polymorphic]` / `#[ronky::implementor(tag = "email")]` - Export `Box<dyn Trait>`
+//!   as a tagged union of the registered implementors, see `Implementor`
 //! - `#[arri(rename = "newName")]` - Rename a field or variant
 //! - `#[arri(nullable)]` - Mark a field as nullable
+//! - `#[arri(alias = "oldName")]` - Accept an additional name for a field or variant
+//! - `#[arri(read_only)]` / `#[arri(write_only)]` - Only export a field in the output or input
+//!   schema, see `Exportable::export_output` and `Exportable::export_input`
+//! - `#[arri(min = 1, max = 100, min_length = 1, max_length = 64)]` - Numeric and length bounds
+//! - `#[arri(pattern = "^[a-z]+$", format = "email")]` - String patterns and formats
+//! - `#[arri(validate)]` - Validate the constraints of a nested value, see the `validation`
+//!   feature and `validation::Validate`
+//! - `#[arri(meta(owner = "billing", pii = true))]` - Custom metadata extensions, serialized under
+//!   `x-extensions`
+//! - `#[arri(result = API_RESULT)]` - Export the `Result`s in a field with a `ResultShape`, see
+//!   `ronky::result` for the matching serialization
+//! - `#[arri(example = "Ghent")]` - An example value, literals on fields and expressions on types.
+//!   With the `serialization` feature `#[arri(test_examples)]` generates a test which checks them
+//!   against the schema, see `examples`
+//! - `#[arri(description = "...")]` / `#[arri(description_file = "docs/x.md")]` - A description
+//!   which overrides the doc comments
+//! - `#[arri(deprecated(since = "2.0.0", note = "..."))]` - A deprecation which only applies to the
+//!   schema, and takes precedence over `#[deprecated]`
+//! - `#[arri(id = "Account")]` - An explicit schema id, see `Naming` and `Registry` for module
+//!   qualified ids, generic id strategies and collision detection. An `ExportContext` bundles
+//!   the naming with the direction, default field casing, schema version and output options of
+//!   an export
+//! - `#[arri(docs(summary, strip = ["Examples"]))]` - Only keep the first paragraph of the doc
+//!   comments, or strip sections from them. Intra-doc links to types become their schema ids,
+//!   other intra-doc links the name of the linked item
 //!
 //! ## 🌟 Final Thought
 //!
@@ -296,6 +330,8 @@
 //! Now go pet your cat (or dog, or rubber duck) – they've been waiting patiently while you
 //! read this documentation. ❤️
 
+#[cfg(feature = "serialization")]
+pub mod examples;
 #[cfg(feature = "serialization")]
 mod serialization;
 
@@ -303,15 +339,23 @@ mod serialization;
 extern crate ronky_derive;
 
 #[cfg(feature = "derive")]
-pub use ronky_derive::{Exported, Serializable as SerializableDerive};
+pub use ronky_derive::{Exported, Serializable as SerializableDerive, implementor, polymorphic};
 
 extern crate arri_repr;
 pub use arri_repr::*;
 
 #[cfg(feature = "serialization")]
-pub use serialization::{ExportedDeserialize, ExportedSerialize};
+pub use serialization::{
+    BufferedValue, ExportedDeserialize, ExportedSerialize, double_option, int_enum, map_keys,
+    result, trait_object, untagged,
+};
 
-pub static SCHEMA_VERSION: &str = "v0.0.8";
+/// Re-exports used by the code generated by `ronky_derive`. Not part of the public API.
+#[cfg(feature = "serialization")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
 
 // TODO: implement conversion from ATD to Rust types
 // | ATD Type | Rust Type |
@@ -1,3 +1,7 @@
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Deserializer};
 use sonic_rs::Error as SonicError;
 
 /// Trait for types that can be serialized to JSON
@@ -29,3 +33,754 @@ where
         sonic_rs::from_str(json)
     }
 }
+
+/// A buffered value, which is decoded once the type it holds is known, e.g. the variants of an
+/// untagged enum in turn or the payload of a `Result` whose discriminator comes last.
+///
+/// It deserializes from any self-describing serde format, e.g.
+/// `serde_json::from_str::<BufferedValue>("12")`, and decodes like the value it buffered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufferedValue(sonic_rs::Value);
+
+impl BufferedValue {
+    /// Decodes the buffered value as a `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the deserialization of `T`.
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, SonicError> {
+        sonic_rs::from_value(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for BufferedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor).map(Self)
+    }
+}
+
+/// Buffers any self-describing value, as the `Deserialize` of `sonic_rs::Value` only
+/// supports the deserializer of sonic-rs.
+struct ValueVisitor;
+
+impl<'de> de::Visitor<'de> for ValueVisitor {
+    type Value = sonic_rs::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        sonic_rs::Value::new_f64(value)
+            .ok_or_else(|| E::custom(format!("{} isn't a valid JSON number", value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(sonic_rs::Value::new_null())
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(sonic_rs::Value::new_null())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = sonic_rs::Array::new();
+        while let Some(BufferedValue(value)) = seq.next_element()? {
+            array.push(value);
+        }
+        Ok(array.into())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut object = sonic_rs::Object::new();
+        while let Some((key, BufferedValue(value))) = map.next_entry::<String, _>()? {
+            object.insert(&key, value);
+        }
+        Ok(object.into())
+    }
+}
+
+/// Serde helpers for `Option<Option<T>>` fields, preserving the difference between an absent
+/// value (`None`), an explicit `null` (`Some(None)`) and a present value (`Some(Some(value))`).
+///
+/// The outer `Option` tracks presence, so the field must default to `None` when missing and must
+/// be skipped when serializing `None`:
+///
+/// ```rust,ignore
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct UserPatch {
+///     #[serde(default, skip_serializing_if = "Option::is_none", with = "ronky::double_option")]
+///     nickname: Option<Option<String>>,
+/// }
+/// ```
+pub mod double_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `Some(None)` as `null` and `Some(Some(value))` as `value`.
+    ///
+    /// `None` should never reach this function, as the field is expected to be skipped with
+    /// `skip_serializing_if = "Option::is_none"`. It is serialized as `null` if it does.
+    pub fn serialize<T, S>(value: &Option<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(Some(value)) => serializer.serialize_some(value),
+            Some(None) | None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes a present field, mapping `null` to `Some(None)` and a value to
+    /// `Some(Some(value))`. Absent fields are handled by `#[serde(default)]`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Some)
+    }
+}
+
+/// Serde helpers for maps, encoding their keys with [`MapKey`](crate::MapKey).
+///
+/// Keys are written as the strings of their Arri encoding, and parsing a key rejects what
+/// `MapKey::from_key` rejects, e.g. values which aren't part of the `EnumSchema` of an enum key.
+/// Any map which can be iterated by reference and collected from its entries is supported:
+///
+/// ```rust,ignore
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct Inventory {
+///     #[serde(with = "ronky::map_keys")]
+///     stock: BTreeMap<Fruit, u32>,
+/// }
+/// ```
+pub mod map_keys {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::de::{self, MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::MapKey;
+
+    /// Serializes the map with the encoded keys.
+    pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+        K: MapKey + 'a,
+        V: Serialize + 'a,
+        S: Serializer,
+    {
+        serializer.collect_map(map.into_iter().map(|(key, value)| (key.to_key(), value)))
+    }
+
+    /// Deserializes a map, parsing every key with `MapKey::from_key`.
+    pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: FromIterator<(K, V)>,
+        K: MapKey,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EntriesVisitor(PhantomData))
+    }
+
+    /// Collects the entries of a map, parsing their keys.
+    struct EntriesVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+    impl<'de, M, K, V> Visitor<'de> for EntriesVisitor<M, K, V>
+    where
+        M: FromIterator<(K, V)>,
+        K: MapKey,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut entries = Vec::with_capacity(access.size_hint().unwrap_or_default());
+            while let Some((key, value)) = access.next_entry::<String, V>()? {
+                entries.push((K::from_key(&key).map_err(de::Error::custom)?, value));
+            }
+            Ok(entries.into_iter().collect())
+        }
+    }
+}
+
+/// Serde helpers for integer-backed enums, encoding them as their discriminant with
+/// [`IntEnum`](crate::IntEnum).
+///
+/// This matches the export of enums with `#[arri(repr = "int")]`, and rejects integers which
+/// aren't the discriminant of a variant:
+///
+/// ```rust,ignore
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct Ticket {
+///     #[serde(with = "ronky::int_enum")]
+///     priority: Priority,
+/// }
+/// ```
+pub mod int_enum {
+    use std::fmt;
+
+    use serde::de;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::IntEnum;
+
+    /// Serializes the discriminant of the variant.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: IntEnum,
+        T::Repr: Serialize,
+        S: Serializer,
+    {
+        value.to_repr().serialize(serializer)
+    }
+
+    /// Deserializes a discriminant, parsing it with `IntEnum::from_repr`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: IntEnum,
+        T::Repr: Deserialize<'de> + Copy + fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let value = T::Repr::deserialize(deserializer)?;
+        T::from_repr(value).ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "`{}` isn't a discriminant of `{}`",
+                value,
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+}
+
+/// Serde helpers for enums with `#[arri(untagged)]`, which are encoded as the value of their
+/// variant.
+///
+/// The derive implements [`Untagged`](untagged::Untagged) for these enums. Decoding tries the
+/// variants in the order they are declared and picks the first one which parses, and passes
+/// validation with the `validation` feature. When none does, the [`UntaggedError`] reports why
+/// every variant was rejected:
+///
+/// ```rust,ignore
+/// #[derive(Exported)]
+/// #[arri(untagged)]
+/// enum StringOrNumber {
+///     Text(String),
+///     Number(f64),
+/// }
+///
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct Legacy {
+///     #[serde(with = "ronky::untagged")]
+///     amount: StringOrNumber,
+/// }
+/// ```
+///
+/// [`UntaggedError`]: untagged::UntaggedError
+pub mod untagged {
+    use std::fmt;
+
+    use serde::de::{self, DeserializeOwned};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::BufferedValue;
+
+    /// An enum which is encoded as the value of its variant, without a tag.
+    pub trait Untagged: Sized {
+        /// Serializes the value of the variant.
+        fn serialize_untagged<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        /// Decodes the first variant which accepts the value.
+        ///
+        /// # Errors
+        ///
+        /// Returns an [`UntaggedError`] with the reason of every variant if none accepts it.
+        fn decode_untagged(value: &BufferedValue) -> Result<Self, UntaggedError>;
+    }
+
+    /// A variant of an untagged enum which rejected a value.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Failure {
+        /// The exported name of the variant.
+        pub variant: &'static str,
+        /// Why the variant rejected the value, e.g. a parse error or failed validation.
+        pub reason: String,
+    }
+
+    impl Failure {
+        /// Creates the failure of a variant.
+        pub fn new(variant: &'static str, reason: impl ToString) -> Self {
+            Self {
+                variant,
+                reason: reason.to_string(),
+            }
+        }
+    }
+
+    /// A value which none of the variants of an untagged enum accepts.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct UntaggedError {
+        /// The Rust type of the enum.
+        pub expected: &'static str,
+        /// The failures of the variants, in the order they were tried.
+        pub failures: Vec<Failure>,
+    }
+
+    impl UntaggedError {
+        /// Creates the error for a value which isn't a valid `T`.
+        pub fn new<T: ?Sized>(failures: Vec<Failure>) -> Self {
+            Self {
+                expected: std::any::type_name::<T>(),
+                failures,
+            }
+        }
+    }
+
+    impl fmt::Display for UntaggedError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "no variant of `{}` matched", self.expected)?;
+            for (idx, failure) in self.failures.iter().enumerate() {
+                let separator = if idx == 0 { ":" } else { ";" };
+                write!(f, "{} `{}`: {}", separator, failure.variant, failure.reason)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl std::error::Error for UntaggedError {}
+
+    /// Serializes the value of the variant.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Untagged,
+        S: Serializer,
+    {
+        value.serialize_untagged(serializer)
+    }
+
+    /// Deserializes a value, decoding it with `Untagged::decode_untagged`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Untagged,
+        D: Deserializer<'de>,
+    {
+        let value = BufferedValue::deserialize(deserializer)?;
+        T::decode_untagged(&value).map_err(de::Error::custom)
+    }
+
+    /// Parses the value of a variant. Used by the code generated by `#[derive(Exported)]`.
+    #[doc(hidden)]
+    pub fn decode_variant<T: DeserializeOwned>(value: &BufferedValue) -> Result<T, String> {
+        value.decode().map_err(|err| err.to_string())
+    }
+}
+
+/// Serde support for trait objects, dispatching to their registered implementors.
+///
+/// `#[ronky::polymorphic]` on a trait implements `Serialize` for `dyn Trait` and `Deserialize`
+/// for `Box<dyn Trait>` with these functions, and `#[ronky::implementor(tag = "...")]` registers
+/// the implementors. A trait object is written as the object of its implementor, with the tag
+/// under the discriminator, which matches the exported tagged union:
+///
+/// ```rust,ignore
+/// #[ronky::polymorphic]
+/// trait Action {}
+///
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct SendEmail {
+///     to: String,
+/// }
+///
+/// // {"type": "email", "to": "..."}
+/// #[ronky::implementor(tag = "email")]
+/// impl Action for SendEmail {}
+/// ```
+pub mod trait_object {
+    use std::any::{Any, TypeId, type_name};
+    use std::sync::{Mutex, PoisonError};
+
+    use serde::de::{self, DeserializeOwned};
+    use serde::ser::{self, SerializeMap};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use sonic_rs::{JsonContainerTrait, JsonValueTrait, Value};
+
+    use super::BufferedValue;
+    use crate::{Implementor, register_submitted_implementors};
+
+    /// The encoding of an implementor of a trait object.
+    #[derive(Clone, Copy)]
+    struct Codec {
+        /// The trait object, e.g. `dyn Action`.
+        object: TypeId,
+        /// The implementor, e.g. `SendEmail`.
+        implementor: TypeId,
+        /// The value of the discriminator.
+        tag: &'static str,
+        /// Encodes the implementor, which is passed as `&dyn Any`.
+        encode: fn(&dyn Any) -> Result<Value, sonic_rs::Error>,
+        /// Decodes the implementor into an `Option<Box<T>>`, which is passed as `&mut dyn Any`.
+        decode: fn(&Value, &mut dyn Any) -> Result<(), sonic_rs::Error>,
+    }
+
+    /// The codecs of all implementors, in the order they were registered.
+    static CODECS: Mutex<Vec<Codec>> = Mutex::new(Vec::new());
+
+    /// Registers the encoding of `I` as an implementor of the trait object `T`.
+    ///
+    /// This is called by `#[ronky::implementor]` with the `serialization` feature, registering a
+    /// type twice has no effect.
+    pub fn register<T, I>()
+    where
+        T: ?Sized + 'static,
+        I: Implementor<T> + Serialize + DeserializeOwned,
+    {
+        let mut codecs = CODECS.lock().unwrap_or_else(PoisonError::into_inner);
+        let implementor = TypeId::of::<I>();
+        let object = TypeId::of::<T>();
+        if !codecs
+            .iter()
+            .any(|codec| codec.object == object && codec.implementor == implementor)
+        {
+            codecs.push(Codec {
+                object,
+                implementor,
+                tag: I::TAG,
+                encode: encode::<I>,
+                decode: decode::<T, I>,
+            });
+        }
+    }
+
+    /// Encodes an implementor, which the registry guarantees to be an `I`.
+    fn encode<I: Serialize + 'static>(value: &dyn Any) -> Result<Value, sonic_rs::Error> {
+        // Encoded through text, which keeps the discriminator first in nested trait objects
+        let value = value.downcast_ref::<I>().expect("a registered implementor");
+        sonic_rs::from_str(&sonic_rs::to_string(value)?)
+    }
+
+    /// Decodes an `I`, storing it as a trait object in `out`, an `Option<Box<T>>`.
+    fn decode<T, I>(value: &Value, out: &mut dyn Any) -> Result<(), sonic_rs::Error>
+    where
+        T: ?Sized + 'static,
+        I: Implementor<T> + DeserializeOwned,
+    {
+        // Decoding from the text, as the values of sonic-rs can't be decoded from its values
+        let implementor = Box::new(sonic_rs::from_str::<I>(&value.to_string())?);
+        if let Some(out) = out.downcast_mut::<Option<Box<T>>>() {
+            *out = Some(implementor.into_object());
+        }
+        Ok(())
+    }
+
+    /// Serializes an implementor of the trait object `T`, passed as `&dyn Any`, as its object
+    /// with its tag under `discriminator`.
+    pub fn serialize<T, S>(
+        value: &dyn Any,
+        discriminator: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + 'static,
+        S: Serializer,
+    {
+        let object = TypeId::of::<T>();
+        let implementor = value.type_id();
+        register_submitted_implementors();
+        // The lock is released before encoding, as implementors may contain the trait object
+        let codec = CODECS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|codec| codec.object == object && codec.implementor == implementor)
+            .copied()
+            .ok_or_else(|| {
+                ser::Error::custom(format_args!(
+                    "the value isn't a registered implementor of `{}`",
+                    type_name::<T>()
+                ))
+            })?;
+
+        let encoded = (codec.encode)(value).map_err(ser::Error::custom)?;
+        let Some(fields) = encoded.as_object() else {
+            return Err(ser::Error::custom(format_args!(
+                "the implementor `{}` of `{}` isn't serialized as an object",
+                codec.tag,
+                type_name::<T>()
+            )));
+        };
+
+        let mut map = serializer.serialize_map(Some(fields.len() + 1))?;
+        map.serialize_entry(discriminator, codec.tag)?;
+        for (key, value) in fields.iter().filter(|(key, _)| *key != discriminator) {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+
+    /// Deserializes an implementor of the trait object `T`, picking it by the tag under
+    /// `discriminator`.
+    pub fn deserialize<'de, T, D>(discriminator: &str, deserializer: D) -> Result<Box<T>, D::Error>
+    where
+        T: ?Sized + 'static,
+        D: Deserializer<'de>,
+    {
+        let mut fields = BufferedValue::deserialize(deserializer)?
+            .0
+            .into_object()
+            .ok_or_else(|| de::Error::custom("expected an object"))?;
+        let tag = fields.remove(&discriminator).ok_or_else(|| {
+            de::Error::custom(format_args!("missing discriminator `{}`", discriminator))
+        })?;
+        let tag = tag
+            .as_str()
+            .ok_or_else(|| de::Error::custom(format_args!("`{}` isn't a string", discriminator)))?;
+
+        let object = TypeId::of::<T>();
+        register_submitted_implementors();
+        let codecs = CODECS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|codec| codec.object == object && codec.tag == tag)
+            .copied()
+            .collect::<Vec<_>>();
+        let codec = match codecs.as_slice() {
+            [codec] => codec,
+            [] => {
+                return Err(de::Error::custom(format_args!(
+                    "unknown tag `{}` of `{}`",
+                    tag,
+                    type_name::<T>()
+                )));
+            }
+            _ => {
+                return Err(de::Error::custom(format_args!(
+                    "the tag `{}` of `{}` is used by multiple implementors",
+                    tag,
+                    type_name::<T>()
+                )));
+            }
+        };
+
+        let mut out: Option<Box<T>> = None;
+        (codec.decode)(&Value::from(fields), &mut out).map_err(de::Error::custom)?;
+        out.ok_or_else(|| de::Error::custom("the implementor wasn't decoded"))
+    }
+}
+
+/// Serde helpers for `Result<T, E>` fields, following a [`ResultShape`](crate::ResultShape).
+///
+/// `#[serde(with = "ronky::result")]` uses the default shape, which matches the default export
+/// of `Result`. For another shape, [`result_module!`](crate::result_module) generates a module
+/// for a shape constant, which is shared with the `#[arri(result = ...)]` of the field:
+///
+/// ```rust,ignore
+/// const API_RESULT: ResultShape = ResultShape::new()
+///     .set_discriminator("status")
+///     .set_variants("success", "error")
+///     .set_properties("data", "error");
+///
+/// ronky::result_module!(api_result = API_RESULT);
+///
+/// #[derive(Exported, Serialize, Deserialize)]
+/// struct Response {
+///     #[arri(result = API_RESULT)]
+///     #[serde(with = "api_result")]
+///     outcome: Result<User, ApiError>,
+/// }
+/// ```
+pub mod result {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::de::{self, DeserializeOwned, MapAccess, Visitor};
+    use serde::ser::SerializeMap;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use super::BufferedValue;
+    use crate::ResultShape;
+
+    /// Serializes the result with the default shape.
+    pub fn serialize<T, E, S>(value: &Result<T, E>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        E: Serialize,
+        S: Serializer,
+    {
+        serialize_with_shape(ResultShape::new(), value, serializer)
+    }
+
+    /// Deserializes a result with the default shape.
+    pub fn deserialize<'de, T, E, D>(deserializer: D) -> Result<Result<T, E>, D::Error>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_shape(ResultShape::new(), deserializer)
+    }
+
+    /// Serializes the result as the discriminator and the payload of its variant.
+    pub fn serialize_with_shape<T, E, S>(
+        shape: ResultShape,
+        value: &Result<T, E>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        E: Serialize,
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        match value {
+            Ok(value) => {
+                map.serialize_entry(shape.discriminator(), shape.ok())?;
+                map.serialize_entry(shape.ok_property(), value)?;
+            }
+            Err(error) => {
+                map.serialize_entry(shape.discriminator(), shape.err())?;
+                map.serialize_entry(shape.err_property(), error)?;
+            }
+        }
+        map.end()
+    }
+
+    /// Deserializes a result, in which the discriminator may follow the payload.
+    pub fn deserialize_with_shape<'de, T, E, D>(
+        shape: ResultShape,
+        deserializer: D,
+    ) -> Result<Result<T, E>, D::Error>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ResultVisitor(shape, PhantomData))
+    }
+
+    /// Reads the discriminator and buffers the payloads, until the variant is known.
+    struct ResultVisitor<T, E>(ResultShape, PhantomData<(T, E)>);
+
+    impl<'de, T, E> Visitor<'de> for ResultVisitor<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        type Value = Result<T, E>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a map with a `{}` discriminator",
+                self.0.discriminator()
+            )
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let shape = self.0;
+            let mut variant: Option<String> = None;
+            let mut payloads = Vec::new();
+            while let Some(key) = access.next_key::<String>()? {
+                if key == shape.discriminator() {
+                    variant = Some(access.next_value()?);
+                } else {
+                    payloads.push((key, access.next_value::<BufferedValue>()?));
+                }
+            }
+
+            let variant = variant.ok_or_else(|| de::Error::missing_field(shape.discriminator()))?;
+            let payload = |property: &'static str| {
+                payloads
+                    .iter()
+                    .find(|(key, _)| key == property)
+                    .map(|(_, payload)| payload)
+                    .ok_or_else(|| de::Error::missing_field(property))
+            };
+
+            if variant == shape.ok() {
+                payload(shape.ok_property())?
+                    .decode()
+                    .map(Ok)
+                    .map_err(de::Error::custom)
+            } else if variant == shape.err() {
+                payload(shape.err_property())?
+                    .decode()
+                    .map(Err)
+                    .map_err(de::Error::custom)
+            } else {
+                Err(de::Error::custom(format_args!(
+                    "unknown variant `{}`, expected `{}` or `{}`",
+                    variant,
+                    shape.ok(),
+                    shape.err()
+                )))
+            }
+        }
+    }
+}
+
+/// Generates a module of serde helpers for `Result<T, E>` fields with the given
+/// [`ResultShape`](crate::ResultShape), to be used with `#[serde(with = "...")]`.
+///
+/// See [`result`](crate::result) for an example.
+#[macro_export]
+macro_rules! result_module {
+    ($vis:vis $name:ident = $shape:expr) => {
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Serializes the result with the shape of the module.
+            pub fn serialize<T, E, S>(
+                value: &::std::result::Result<T, E>,
+                serializer: S,
+            ) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                T: $crate::__private::serde::Serialize,
+                E: $crate::__private::serde::Serialize,
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::result::serialize_with_shape($shape, value, serializer)
+            }
+
+            /// Deserializes a result with the shape of the module.
+            pub fn deserialize<'de, T, E, D>(
+                deserializer: D,
+            ) -> ::std::result::Result<::std::result::Result<T, E>, D::Error>
+            where
+                T: $crate::__private::serde::de::DeserializeOwned,
+                E: $crate::__private::serde::de::DeserializeOwned,
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::result::deserialize_with_shape($shape, deserializer)
+            }
+        }
+    };
+}
@@ -0,0 +1,253 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Field, GenericArgument, Ident, PathArguments, ReturnType, Type,
+    TypeParamBound, WhereClause, WherePredicate,
+};
+
+use crate::parsers::attributes::{Bound, enum_variants, properties};
+
+/// Retrieves the bounds of the `Exportable` implementation of a derived type.
+///
+/// Unless they are replaced with `#[arri(bound = "...")]`, every type parameter used in a field
+/// (such as `T` in `Vec<T>`) and every associated type of a type parameter (such as `I::Item`)
+/// must be `Exportable`. The type itself doesn't need these bounds.
+///
+/// The bounds don't require `'static`, so types with borrowed type parameters are exportable
+/// as well.
+pub(crate) fn exportable_bounds(input: &DeriveInput) -> Bound {
+    if let Some(bound) = explicit_bound(input) {
+        return bound;
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect::<Vec<_>>(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    used_type_bounds(input, fields, quote!(ronky::Exportable))
+}
+
+/// Retrieves the bounds of the `Validate` implementation of a derived type.
+///
+/// Every type parameter and associated type used in a field which is validated recursively
+/// (such as `T` in `#[arri(validate)] items: Vec<T>`) must be `Validate`.
+#[cfg(feature = "validation")]
+pub(crate) fn validate_bounds<'a>(
+    input: &DeriveInput,
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> Bound {
+    used_type_bounds(input, fields, quote!(ronky::validation::Validate))
+}
+
+/// Bounds every type parameter and associated type used in the fields with a trait.
+fn used_type_bounds<'a>(
+    input: &DeriveInput,
+    fields: impl IntoIterator<Item = &'a Field>,
+    bound: TokenStream,
+) -> Bound {
+    let params = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+
+    let mut used = Vec::new();
+    for field in fields {
+        collect_types(&field.ty, &params, &mut used);
+    }
+
+    // The bounds follow the order of the type parameters, as they make up the id of the schema
+    params
+        .iter()
+        .flat_map(|param| used.iter().filter(move |(root, _)| root == *param))
+        .map(|(_, ty)| -> WherePredicate { syn::parse_quote!(#ty: #bound) })
+        .collect()
+}
+
+/// Retrieves the type names and the ids of the type parameters, which make up the type name and
+/// the id of the schema of a generic type.
+///
+/// Type parameters which are bound to be `Exportable` (by the inferred bounds, `#[arri(bound)]`
+/// or the type itself) are named after their schema, the others after their Rust type. Every
+/// type parameter is part of the id, whatever the bounds, and primitive arguments are named as
+/// the naming of the export decides.
+pub(crate) fn type_param_names(
+    input: &DeriveInput,
+    bounds: &Bound,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    let own_bounds = input
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates);
+
+    input
+        .generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            let bounded = param.bounds.iter().any(is_exportable)
+                || bounds
+                    .iter()
+                    .chain(own_bounds.clone())
+                    .any(|predicate| match predicate {
+                        WherePredicate::Type(predicate) => {
+                            param_of(&predicate.bounded_ty, &[ident]).is_some()
+                                && predicate.bounds.iter().any(is_exportable)
+                        }
+                        _ => false,
+                    });
+
+            let (type_name, id) = match bounded {
+                true => (
+                    quote!(<#ident as ronky::Exportable>::get_type_name()),
+                    quote!(<#ident as ronky::Exportable>::get_schema_id()),
+                ),
+                false => (
+                    quote!(ronky::type_utils::get_type_name::<#ident>()),
+                    quote!(ronky::type_utils::get_type_name::<#ident>()),
+                ),
+            };
+            let id = quote! {
+                ronky::naming().argument_id(#id, std::any::type_name::<#ident>())
+            };
+            (type_name, id)
+        })
+        .unzip()
+}
+
+/// Checks whether a bound is the `Exportable` trait.
+fn is_exportable(bound: &TypeParamBound) -> bool {
+    match bound {
+        TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Exportable"),
+        _ => false,
+    }
+}
+
+/// Builds the where clause of an implementation for a derived type, keeping the bounds which
+/// are written on the type itself.
+pub(crate) fn where_clause(input: &DeriveInput, bounds: &Bound) -> WhereClause {
+    let mut where_clause = input
+        .generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    where_clause.predicates.extend(bounds.iter().cloned());
+    where_clause
+}
+
+/// Retrieves the `#[arri(bound = "...")]` of a struct or enum, if any.
+///
+/// Invalid attributes are reported by the export itself, so they are ignored here.
+fn explicit_bound(input: &DeriveInput) -> Option<Bound> {
+    match input.data {
+        Data::Enum(_) => enum_variants::extract(&input.attrs)
+            .ok()?
+            .into_iter()
+            .rev()
+            .find_map(|attr| attr.bound),
+        _ => properties::extract(&input.attrs)
+            .ok()?
+            .into_iter()
+            .rev()
+            .find_map(|attr| attr.bound),
+    }
+}
+
+/// Renders the `#[arri(bound = "...")]` of a struct, to pass it on to a generated struct.
+pub(crate) fn bound_attr(input: &DeriveInput) -> Option<proc_macro2::TokenStream> {
+    explicit_bound(input).map(|bound| {
+        let bound = quote!(#bound).to_string();
+        quote!(#[arri(bound = #bound)])
+    })
+}
+
+/// Collects the type parameters and associated types used in a type, with the type parameter
+/// they belong to.
+fn collect_types(ty: &Type, params: &[&Ident], used: &mut Vec<(Ident, Type)>) {
+    match ty {
+        Type::Path(path) => {
+            // `<T as Trait>::Assoc`
+            if let Some(qself) = &path.qself {
+                match param_of(&qself.ty, params) {
+                    Some(root) => return push_type(used, root, ty),
+                    None => collect_types(&qself.ty, params, used),
+                }
+            } else if path.path.leading_colon.is_none()
+                && let Some(root) = params
+                    .iter()
+                    .find(|param| path.path.segments[0].ident == ***param)
+            {
+                // `T` itself, or an associated type such as `T::Assoc`
+                return push_type(used, root, ty);
+            }
+
+            for segment in &path.path.segments {
+                match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => {
+                        for argument in &arguments.args {
+                            match argument {
+                                GenericArgument::Type(ty) => collect_types(ty, params, used),
+                                GenericArgument::AssocType(assoc) => {
+                                    collect_types(&assoc.ty, params, used)
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    PathArguments::Parenthesized(arguments) => {
+                        for input in &arguments.inputs {
+                            collect_types(input, params, used);
+                        }
+                        if let ReturnType::Type(_, output) = &arguments.output {
+                            collect_types(output, params, used);
+                        }
+                    }
+                    PathArguments::None => {}
+                }
+            }
+        }
+        Type::Reference(reference) => collect_types(&reference.elem, params, used),
+        Type::Ptr(ptr) => collect_types(&ptr.elem, params, used),
+        Type::Slice(slice) => collect_types(&slice.elem, params, used),
+        Type::Array(array) => collect_types(&array.elem, params, used),
+        Type::Paren(paren) => collect_types(&paren.elem, params, used),
+        Type::Group(group) => collect_types(&group.elem, params, used),
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                collect_types(elem, params, used);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Adds a used type, unless it was already used.
+fn push_type(used: &mut Vec<(Ident, Type)>, root: &Ident, ty: &Type) {
+    if !used.iter().any(|(_, existing)| existing == ty) {
+        used.push((root.clone(), ty.clone()));
+    }
+}
+
+/// Retrieves the type parameter a type consists of, if it is a bare type parameter.
+fn param_of<'a>(ty: &Type, params: &[&'a Ident]) -> Option<&'a Ident> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let ident = path.path.get_ident()?;
+            params.iter().find(|param| ident == **param).copied()
+        }
+        Type::Group(group) => param_of(&group.elem, params),
+        Type::Paren(paren) => param_of(&paren.elem, params),
+        _ => None,
+    }
+}
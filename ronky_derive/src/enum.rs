@@ -1,14 +1,20 @@
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{DeriveInput, Fields, Variant, punctuated::Punctuated, spanned::Spanned, token::Comma};
+use syn::{
+    DeriveInput, Expr, ExprLit, ExprUnary, Fields, Lit, LitStr, UnOp, Variant,
+    punctuated::Punctuated, spanned::Spanned, token::Comma,
+};
+
+use arri_common::EnumTransformation;
 
 use crate::{
-    metadata,
+    metadata::{self, TypeMetadata},
     named_struct::export_struct_fields,
     parsers::{
         ParsedField,
         attributes::{
-            enum_variants::{self, enum_transformation_to_tokens},
+            Description, DocOptions,
+            enum_variants::{self, EnumVariants, enum_transformation_to_tokens},
             fields,
         },
         parse_field,
@@ -26,10 +32,44 @@ use crate::{
 ///
 /// Returns a `TokenStream` that defines the schema for the enum.
 pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -> TokenStream {
-    let metadata: proc_macro2::TokenStream = metadata::extract(&input.attrs).into();
+    // The transformations are applied at runtime, but are needed upfront to validate aliases.
+    let (transforms, type_metadata, repr, untagged) = enum_variants::extract(&input.attrs)
+        .map(|attrs| {
+            attrs.into_iter().fold(
+                (Vec::new(), TypeMetadata::default(), None, false),
+                |(mut transforms, mut metadata, repr, untagged), attr| {
+                    transforms.extend(attr.transform);
+                    metadata.meta.extend(attr.meta);
+                    metadata.example = attr.example.or(metadata.example);
+                    metadata.description = attr.description.or(metadata.description);
+                    metadata.deprecated = attr.deprecated.or(metadata.deprecated);
+                    metadata.docs = attr.docs.or(metadata.docs);
+                    (
+                        transforms,
+                        metadata,
+                        attr.repr.or(repr),
+                        attr.untagged.unwrap_or(untagged),
+                    )
+                },
+            )
+        })
+        .unwrap_or_default();
+    let docs = type_metadata.docs.clone().unwrap_or_default();
+    let metadata: proc_macro2::TokenStream = metadata::extract(&input.attrs, type_metadata).into();
+
+    if untagged {
+        if let Some(repr) = repr {
+            return quote_spanned!(repr.span() =>
+                compile_error!("`repr = \"int\"` can't be combined with `untagged`.");
+            )
+            .into();
+        }
+        return export_untagged(input, variants, &transforms, metadata, &docs);
+    }
 
     let mut is_tagged_union = false;
     let mut exported = Vec::new();
+    let mut names = Vec::new();
 
     for (idx, variant) in variants.iter().enumerate() {
         // Ensure all variants are either tagged union or regular enum variants
@@ -50,26 +90,37 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
             Err(e) => return e,
         };
 
-        let variant_name = attrs
-            .iter()
-            .rev()
-            .find(|a| a.rename.is_some())
-            .and_then(|a| a.rename.as_ref())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| {
-                // Strip the r# prefix if present (for raw identifiers like r#type)
-                let name = variant.ident.to_string();
-                name.strip_prefix("r#").unwrap_or(&name).to_string()
-            });
+        let variant_name = variant_name(variant, &attrs);
 
         is_tagged_union = !variant.fields.is_empty();
 
+        let aliases = attrs
+            .iter()
+            .flat_map(|a| a.aliases.clone())
+            .collect::<Vec<_>>();
+
+        if !is_tagged_union && let Some((attribute, span)) = unit_variant_metadata(variant, &attrs)
+        {
+            let message = format!(
+                "`#[arri({})]` is only supported on tagged union variants, as Arri enums cannot carry metadata per variant.",
+                attribute
+            );
+            return quote_spanned!(span => compile_error!(#message);).into();
+        }
+
+        names.push((
+            transforms
+                .iter()
+                .fold(variant_name.clone(), |acc, transform| transform.apply(&acc)),
+            aliases,
+        ));
+
         if is_tagged_union {
             match variant.fields {
                 Fields::Named(ref fields) => {
                     // Handle named fields in tagged union variants
                     let metadata: Option<proc_macro2::TokenStream> =
-                        metadata::extract_attrs(&variant.attrs).map(|ts| {
+                        metadata::extract_attrs(&variant.attrs, &attrs, &docs).map(|ts| {
                             let ts: proc_macro2::TokenStream = ts.into();
                             quote! {
                                 use ronky::Serializable;
@@ -77,7 +128,7 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
                             }
                         });
                     let struct_export: proc_macro2::TokenStream =
-                        export_struct_fields(&fields.named, &None).into();
+                        export_struct_fields(&fields.named, &None, &docs).into();
 
                     exported.push(quote! {
                         schema.add_mapping(#variant_name, Box::new({
@@ -98,7 +149,7 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
                     }
 
                     let metadata: Option<proc_macro2::TokenStream> =
-                        metadata::extract_attrs(&variant.attrs).map(|ts| {
+                        metadata::extract_attrs(&variant.attrs, &attrs, &docs).map(|ts| {
                             let ts: proc_macro2::TokenStream = ts.into();
                             quote! {
                                 use ronky::Serializable;
@@ -109,10 +160,10 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
                     let (field_stream, field_metadata) = match parse_field(
                         fields.unnamed.first().unwrap(),
                     ) {
-                        Ok(ParsedField::Required(field, stream, ..)) => {
+                        Ok(ParsedField::Required(field, stream, args)) => {
                             let stream: proc_macro2::TokenStream = stream.into();
                             let field_metadata: Option<proc_macro2::TokenStream> =
-                                metadata::extract_from_field(field).map(|ts| {
+                                metadata::extract_from_field(field, &args, &docs).map(|ts| {
                                     let ts: proc_macro2::TokenStream = ts.into();
                                     quote! {
                                         use ronky::Serializable;
@@ -158,6 +209,39 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
         }
     }
 
+    if let Err(err) = fields::validate_aliases(&names) {
+        return err.into_compile_error().into();
+    }
+
+    // Integer-backed enums are sent as their discriminants
+    if let Some(repr) = repr {
+        let int_repr = match int_repr(input, variants, &repr) {
+            Ok(int_repr) => int_repr,
+            Err(err) => return err.into_compile_error().into(),
+        };
+        let schema_type = &int_repr.schema_type;
+        let names = names.iter().map(|(name, _)| name);
+        let values = int_repr.variants.iter().map(|(_, _, value)| {
+            let literal = proc_macro2::Literal::i128_suffixed(value.unsigned_abs() as i128);
+            match *value < 0 {
+                true => quote!(-#literal),
+                false => quote!(#literal),
+            }
+        });
+
+        return quote! {
+            use ronky::Serializable;
+            let mut schema = ronky::TypeSchema::new(ronky::Types::#schema_type);
+            schema.set_metadata(#metadata);
+            let mut discriminants = ronky::Discriminants::new();
+            #(discriminants.add_variant(#names, #values);)*
+            schema.set_discriminants(discriminants);
+
+            schema
+        }
+        .into();
+    }
+
     // Extract attributes for the enum
     let attrs = match enum_variants::extract(&input.attrs) {
         Ok(attrs) => {
@@ -208,3 +292,530 @@ pub fn export_enum(input: &DeriveInput, variants: &Punctuated<Variant, Comma>) -
     }
     .into()
 }
+
+/// Retrieves the name of a variant, before the transformations of the enum are applied.
+fn variant_name(variant: &Variant, attrs: &[fields::FieldArguments]) -> String {
+    attrs
+        .iter()
+        .rev()
+        .find_map(|a| a.rename.as_ref())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| {
+            // Strip the r# prefix if present (for raw identifiers like r#type)
+            let name = variant.ident.to_string();
+            name.strip_prefix("r#").unwrap_or(&name).to_string()
+        })
+}
+
+/// Exports an enum with `#[arri(untagged)]` as an `UntaggedSchema`, which lists the schemas of
+/// the variants as its candidates.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the enum.
+/// * `variants` - A reference to a `Punctuated` collection of `Variant` objects representing the enum's variants.
+/// * `transforms` - The transformations of the names of the variants.
+/// * `metadata` - The metadata of the enum.
+/// * `docs` - The options for turning doc comments into descriptions.
+///
+/// # Returns
+///
+/// Returns a `TokenStream` that defines the schema for the enum.
+fn export_untagged(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+    transforms: &[EnumTransformation],
+    metadata: proc_macro2::TokenStream,
+    docs: &DocOptions,
+) -> TokenStream {
+    if let Some(discriminator) = enum_variants::extract(&input.attrs)
+        .ok()
+        .and_then(|attrs| attrs.into_iter().find_map(|attr| attr.discriminator))
+    {
+        return quote_spanned!(discriminator.span() =>
+            compile_error!("Discriminator can only be used with tagged enums.");
+        )
+        .into();
+    }
+
+    let mut candidates = Vec::new();
+    for variant in variants {
+        let attrs = match fields::extract(&variant.attrs) {
+            Ok(attrs) => attrs,
+            Err(e) => return e,
+        };
+
+        if let Some(alias) = attrs.iter().flat_map(|a| a.aliases.iter()).next() {
+            return quote_spanned!(
+                alias.span() =>
+                compile_error!("Aliases are not supported on untagged variants, as they are matched by their value.");
+            )
+            .into();
+        }
+
+        let field = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first().unwrap(),
+            _ => {
+                return quote_spanned!(
+                    variant.span() =>
+                    compile_error!("Untagged enums only support variants with exactly one unnamed field, such as `Text(String)`.");
+                )
+                .into();
+            }
+        };
+
+        let (field_stream, field_metadata) = match parse_field(field) {
+            Ok(ParsedField::Required(field, stream, args)) => {
+                let stream: proc_macro2::TokenStream = stream.into();
+                let field_metadata: Option<proc_macro2::TokenStream> =
+                    metadata::extract_from_field(field, &args, docs).map(|ts| {
+                        let ts: proc_macro2::TokenStream = ts.into();
+                        quote!(ty.set_metadata(#ts);)
+                    });
+                (stream, field_metadata)
+            }
+            Ok(ParsedField::Optional(..)) => {
+                return quote_spanned!(
+                    variant.span() =>
+                    compile_error!("Optional fields are not supported in unnamed enums.")
+                )
+                .into();
+            }
+            Err(e) => return e,
+        };
+
+        let variant_metadata: Option<proc_macro2::TokenStream> =
+            metadata::extract_attrs(&variant.attrs, &attrs, docs).map(|ts| {
+                let ts: proc_macro2::TokenStream = ts.into();
+                quote!(ty.set_metadata(#ts);)
+            });
+
+        let name = transforms
+            .iter()
+            .fold(variant_name(variant, &attrs), |acc, transform| {
+                transform.apply(&acc)
+            });
+
+        candidates.push(quote! {
+            candidates.add_variant(#name, Box::new({
+                let mut ty = #field_stream;
+                #field_metadata
+                #variant_metadata
+                ty
+            }));
+        });
+    }
+
+    quote! {
+        use ronky::Serializable;
+        let mut candidates = ronky::Candidates::new();
+        #(#candidates)*
+        let mut schema = ronky::UntaggedSchema::new(candidates);
+        schema.set_nullable(false);
+        schema.set_metadata(#metadata);
+
+        schema
+    }
+    .into()
+}
+
+/// Implements `ronky::untagged::Untagged` for enums with `#[arri(untagged)]`, decoding the first
+/// variant which accepts a value.
+///
+/// With the `validation` feature, a variant which parses but fails validation is rejected as
+/// well, so the next one is tried.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the enum.
+/// * `variants` - A reference to a `Punctuated` collection of `Variant` objects representing the enum's variants.
+/// * `attrs` - The parsed `#[arri(...)]` attributes of the enum.
+///
+/// # Returns
+///
+/// Returns the implementation, or an empty `TokenStream` if the enum isn't untagged.
+#[cfg(feature = "serialization")]
+pub fn export_untagged_impl(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+    attrs: &[EnumVariants],
+) -> proc_macro2::TokenStream {
+    if !attrs.iter().any(|attr| attr.untagged == Some(true)) {
+        return quote! {};
+    }
+    let transforms = attrs
+        .iter()
+        .flat_map(|attr| attr.transform.iter().cloned())
+        .collect::<Vec<_>>();
+
+    // Invalid variants are reported by the export itself
+    let mut idents = Vec::new();
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for variant in variants {
+        let Ok(attrs) = fields::extract(&variant.attrs) else {
+            return quote! {};
+        };
+        let field = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first().unwrap(),
+            _ => return quote! {},
+        };
+
+        idents.push(&variant.ident);
+        names.push(
+            transforms
+                .iter()
+                .fold(variant_name(variant, &attrs), |acc, transform| {
+                    transform.apply(&acc)
+                }),
+        );
+        types.push(&field.ty);
+    }
+
+    // A variant which fails validation is rejected like one which doesn't parse
+    let accept = if cfg!(feature = "validation") {
+        quote! {
+            match ronky::validation::Validate::validate(&candidate) {
+                Ok(()) => return Ok(candidate),
+                Err(errors) => errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }
+        }
+    } else {
+        quote!(return Ok(candidate))
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    for ty in &types {
+        where_clause.predicates.push(syn::parse_quote! {
+            #ty: ronky::__private::serde::Serialize + ronky::__private::serde::de::DeserializeOwned
+        });
+    }
+
+    quote! {
+        impl #impl_generics ronky::untagged::Untagged for #name #ty_generics #where_clause {
+            fn serialize_untagged<S: ronky::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match self {
+                    #(Self::#idents(value) => ronky::__private::serde::Serialize::serialize(value, serializer),)*
+                }
+            }
+
+            fn decode_untagged(
+                value: &ronky::BufferedValue,
+            ) -> Result<Self, ronky::untagged::UntaggedError> {
+                let mut failures = Vec::new();
+                #(
+                    let reason = match ronky::untagged::decode_variant(value) {
+                        Ok(value) => {
+                            let candidate = Self::#idents(value);
+                            #accept
+                        }
+                        Err(reason) => reason,
+                    };
+                    failures.push(ronky::untagged::Failure::new(#names, reason));
+                )*
+                Err(ronky::untagged::UntaggedError::new::<Self>(failures))
+            }
+        }
+    }
+}
+
+/// Implements `ronky::MapKey` for enums without fields, so they can be the keys of a map.
+///
+/// The keys are the values of the exported `EnumSchema`, so the derive applies the same renames
+/// and transformations. Enums with fields are tagged unions, which can't be keys.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the enum.
+/// * `variants` - A reference to a `Punctuated` collection of `Variant` objects representing the enum's variants.
+/// * `attrs` - The parsed `#[arri(...)]` attributes of the enum.
+///
+/// # Returns
+///
+/// Returns the implementation, or an empty `TokenStream` if the enum can't be a key.
+pub fn export_map_key(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+    attrs: &[EnumVariants],
+) -> proc_macro2::TokenStream {
+    if variants.is_empty() || variants.iter().any(|variant| !variant.fields.is_empty()) {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Integer-backed enums are keyed by their discriminants, like their schema
+    if let Some(repr) = attrs.iter().find_map(|attr| attr.repr.as_ref()) {
+        let Ok(int_repr) = int_repr(input, variants, repr) else {
+            return quote! {};
+        };
+        let ty = &int_repr.ty;
+        return quote! {
+            impl #impl_generics ronky::MapKey for #name #ty_generics #where_clause {
+                fn to_key(&self) -> String {
+                    ronky::IntEnum::to_repr(self).to_string()
+                }
+
+                fn from_key(key: &str) -> Result<Self, ronky::KeyError> {
+                    key.parse::<#ty>()
+                        .ok()
+                        .and_then(<Self as ronky::IntEnum>::from_repr)
+                        .ok_or_else(|| ronky::KeyError::new::<Self>(key))
+                }
+            }
+        };
+    }
+
+    let transforms = attrs
+        .iter()
+        .flat_map(|attr| attr.transform.iter().cloned())
+        .collect::<Vec<_>>();
+
+    // Invalid variants are reported by the export itself
+    let mut idents = Vec::new();
+    let mut keys = Vec::new();
+    for variant in variants {
+        let Ok(attrs) = fields::extract(&variant.attrs) else {
+            return quote! {};
+        };
+        idents.push(&variant.ident);
+        keys.push(
+            transforms
+                .iter()
+                .fold(variant_name(variant, &attrs), |acc, transform| {
+                    transform.apply(&acc)
+                }),
+        );
+    }
+
+    quote! {
+        impl #impl_generics ronky::MapKey for #name #ty_generics #where_clause {
+            fn to_key(&self) -> String {
+                match self {
+                    #(Self::#idents => #keys,)*
+                }
+                .to_string()
+            }
+
+            fn from_key(key: &str) -> Result<Self, ronky::KeyError> {
+                match key {
+                    #(#keys => Ok(Self::#idents),)*
+                    _ => Err(ronky::KeyError::new::<Self>(key)),
+                }
+            }
+        }
+    }
+}
+
+/// Implements `ronky::IntEnum` for enums with `#[arri(repr = "int")]`, encoding every variant as
+/// its discriminant.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the enum.
+/// * `variants` - A reference to a `Punctuated` collection of `Variant` objects representing the enum's variants.
+/// * `attrs` - The parsed `#[arri(...)]` attributes of the enum.
+///
+/// # Returns
+///
+/// Returns the implementation, or an empty `TokenStream` if the enum isn't integer-backed.
+pub fn export_int_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+    attrs: &[EnumVariants],
+) -> proc_macro2::TokenStream {
+    let Some(repr) = attrs.iter().find_map(|attr| attr.repr.as_ref()) else {
+        return quote! {};
+    };
+    // Invalid discriminants are reported by the export itself
+    let Ok(int_repr) = int_repr(input, variants, repr) else {
+        return quote! {};
+    };
+
+    let ty = &int_repr.ty;
+    let idents = int_repr.variants.iter().map(|(ident, _, _)| ident);
+    let exprs = int_repr.variants.iter().map(|(_, expr, _)| expr);
+    let patterns = exprs.clone();
+    let variants = idents.clone();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ronky::IntEnum for #name #ty_generics #where_clause {
+            type Repr = #ty;
+
+            fn to_repr(&self) -> #ty {
+                match self {
+                    #(Self::#idents => #exprs,)*
+                }
+            }
+
+            fn from_repr(value: #ty) -> Option<Self> {
+                match value {
+                    #(#patterns => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// The integer types an enum can be backed by, with their `ronky::Types` and range.
+const INT_REPRS: [(&str, &str, i128, i128); 8] = [
+    ("u8", "Uint8", 0, u8::MAX as i128),
+    ("i8", "Int8", i8::MIN as i128, i8::MAX as i128),
+    ("u16", "Uint16", 0, u16::MAX as i128),
+    ("i16", "Int16", i16::MIN as i128, i16::MAX as i128),
+    ("u32", "Uint32", 0, u32::MAX as i128),
+    ("i32", "Int32", i32::MIN as i128, i32::MAX as i128),
+    ("u64", "Uint64", 0, u64::MAX as i128),
+    ("i64", "Int64", i64::MIN as i128, i64::MAX as i128),
+];
+
+/// The integer representation of an enum with `#[arri(repr = "int")]`.
+struct IntRepr<'a> {
+    /// The integer type of the `#[repr(...)]`, e.g. `u8`.
+    ty: syn::Ident,
+    /// The matching variant of `ronky::Types`, e.g. `Uint8`.
+    schema_type: syn::Ident,
+    /// The variants, with their discriminant as written and its value.
+    variants: Vec<(&'a syn::Ident, &'a Expr, i128)>,
+}
+
+/// Reads the `#[repr(...)]` and the explicit discriminants of an integer-backed enum.
+///
+/// # Errors
+///
+/// Returns an error if the enum has fields, lacks an integer `#[repr(...)]`, or has a variant
+/// whose discriminant is implicit, not an integer literal, or out of range of the `#[repr(...)]`.
+fn int_repr<'a>(
+    input: &DeriveInput,
+    variants: &'a Punctuated<Variant, Comma>,
+    repr: &LitStr,
+) -> syn::Result<IntRepr<'a>> {
+    if variants.iter().any(|variant| !variant.fields.is_empty()) {
+        return Err(syn::Error::new(
+            repr.span(),
+            "`repr = \"int\"` is only supported on enums without fields.",
+        ));
+    }
+
+    let mut found = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if let Some(int) = INT_REPRS.iter().find(|(ty, ..)| meta.path.is_ident(ty)) {
+                found = Some((meta.path.get_ident().cloned().unwrap(), int));
+            } else if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of other representations, e.g. `align(4)`
+                let _args;
+                syn::parenthesized!(_args in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    let Some((ty, (_, schema_type, min, max))) = found else {
+        return Err(syn::Error::new(
+            repr.span(),
+            "`repr = \"int\"` requires an integer `#[repr(...)]` on the enum: one of u8, i8, u16, i16, u32, i32, u64 or i64.",
+        ));
+    };
+
+    let variants = variants
+        .iter()
+        .map(|variant| {
+            let Some((_, expr)) = &variant.discriminant else {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "`repr = \"int\"` requires every variant to have an explicit discriminant, such as `Low = 1`.",
+                ));
+            };
+            let value = discriminant_value(expr).ok_or_else(|| {
+                syn::Error::new(
+                    expr.span(),
+                    "`repr = \"int\"` requires the discriminants to be integer literals.",
+                )
+            })?;
+            if value < *min || value > *max {
+                return Err(syn::Error::new(
+                    expr.span(),
+                    format!("This discriminant is out of range for `{}`.", ty),
+                ));
+            }
+            Ok((&variant.ident, expr, value))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(IntRepr {
+        schema_type: syn::Ident::new(schema_type, ty.span()),
+        ty,
+        variants,
+    })
+}
+
+/// Evaluates a discriminant which is an integer literal, optionally negated.
+fn discriminant_value(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_parse::<i128>().ok().map(|value| -value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Finds an attribute of a variant which needs metadata of its own, together with the span to
+/// report it at, as only tagged union variants can carry metadata.
+fn unit_variant_metadata(
+    variant: &Variant,
+    attrs: &[fields::FieldArguments],
+) -> Option<(&'static str, proc_macro2::Span)> {
+    attrs.iter().find_map(|attr| {
+        if let Some(alias) = attr.aliases.first() {
+            Some(("alias", alias.span()))
+        } else if let Some((key, _)) = attr.meta.first() {
+            Some(("meta", key.span()))
+        } else if let Some(description) = &attr.description {
+            Some((
+                match description {
+                    Description::Inline(_) => "description",
+                    Description::File(_) => "description_file",
+                },
+                variant.ident.span(),
+            ))
+        } else if attr.deprecated.is_some() {
+            Some(("deprecated", variant.ident.span()))
+        } else if attr.docs.is_some() {
+            Some(("docs", variant.ident.span()))
+        } else if attr.example.is_some() {
+            Some(("example", variant.ident.span()))
+        } else {
+            None
+        }
+    })
+}
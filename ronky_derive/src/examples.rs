@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{Data, DeriveInput, spanned::Spanned};
+
+use crate::parsers::attributes::{enum_variants, fields, properties};
+
+/// Generates a test which checks the examples of a type against its exported schema.
+///
+/// The test is opt-in with `#[arri(test_examples)]`, as tests of items inside function bodies
+/// can't be run, and only compiled with `cfg(test)`. It checks the type-level
+/// `#[arri(example = ...)]` and the field-level `#[arri(example = "...")]` of non-generic types.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the struct or enum.
+///
+/// # Returns
+///
+/// Returns a `TokenStream` containing the test, or an empty stream if there is nothing to check.
+pub fn generate_example_test(input: &DeriveInput) -> TokenStream {
+    let (type_example, test_examples, fields) = match &input.data {
+        Data::Struct(data) => {
+            let args = properties::extract(&input.attrs).unwrap_or_default();
+            (
+                args.iter().rev().find_map(|a| a.example.clone()),
+                args.iter().rev().find_map(|a| a.test_examples),
+                data.fields.iter().collect::<Vec<_>>(),
+            )
+        }
+        Data::Enum(data) => {
+            let args = enum_variants::extract(&input.attrs).unwrap_or_default();
+            (
+                args.iter().rev().find_map(|a| a.example.clone()),
+                args.iter().rev().find_map(|a| a.test_examples),
+                data.variants
+                    .iter()
+                    .flat_map(|variant| &variant.fields)
+                    .collect(),
+            )
+        }
+        Data::Union(_) => return quote! {},
+    };
+
+    if test_examples != Some(true) {
+        return quote! {};
+    }
+    if !input.generics.params.is_empty() {
+        return quote_spanned! {input.generics.span() =>
+            compile_error!("The examples of a generic type can't be tested, as the test needs a concrete type");
+        };
+    }
+
+    let has_field_examples = fields.iter().any(|field| {
+        fields::extract(&field.attrs).is_ok_and(|args| args.iter().any(|arg| arg.example.is_some()))
+    });
+
+    if type_example.is_none() && !has_field_examples {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    let test_name = format_ident!("__ronky_examples_of_{}", name);
+
+    // The type-level example must encode, otherwise it silently disappears from the schema
+    let encodes = type_example.map(|example| {
+        let message = format!("the example of {} to serialize", name);
+        quote! {
+            let example: #name = #example;
+            ronky::ExportedSerialize::to_json(&example).expect(#message);
+        }
+    });
+
+    quote! {
+        #[cfg(test)]
+        #[test]
+        #[allow(non_snake_case)]
+        fn #test_name() {
+            #encodes
+            ronky::examples::assert_examples::<#name>();
+        }
+    }
+}
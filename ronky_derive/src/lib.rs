@@ -49,28 +49,38 @@
 //! struct ApiSchema {
 //!     field_name: String,        // becomes "fieldName" in JSON
 //!     is_deprecated: Option<bool>, // becomes "isDeprecated"
-//!     metadata: Option<MetadataSchema>, // enables set_metadata()
-//!     nullable: Option<bool>,     // enables set_nullable()
+//!     metadata: Option<MetadataSchema>, // enables try_set_metadata()
+//!     nullable: Option<bool>,     // enables try_set_nullable()
 //! }
 //! ```
 //!
 //! If you're looking for the crate that provides the core schema manipulation utilities, you are
 //! probably looking for the [ronky](https://docs.rs/ronky) crate.
 
+mod bounds;
 mod r#enum;
+#[cfg(feature = "serialization")]
+mod examples;
 mod metadata;
 mod named_struct;
 mod parsers;
+mod partial;
+mod polymorphic;
 #[cfg(feature = "serialization")]
 mod serialization;
 mod tuple_struct;
+#[cfg(feature = "validation")]
+mod validation;
 
-use r#enum::export_enum;
+use r#enum::{export_enum, export_int_enum, export_map_key};
 use heck::ToLowerCamelCase;
 use named_struct::export_named_struct;
+use parsers::attributes::{enum_variants, fields::has_directional_fields};
 use proc_macro::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::{Data, DataEnum, DataStruct, DeriveInput, Fields, parse_macro_input, spanned::Spanned};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Fields, LitStr, parse_macro_input, spanned::Spanned,
+};
 use tuple_struct::export_tuple_struct;
 
 /// A procedural macro to export a struct or enum.
@@ -118,35 +128,127 @@ pub fn exported_derive(input: TokenStream) -> TokenStream {
     let struct_name = input.ident.clone();
 
     let generics = &input.generics;
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
-    // Extract the type parameters from generics
-    let type_params = generics
-        .type_params()
-        .map(|param| param.ident.clone())
-        .collect::<Vec<_>>();
+    // The generic types the schema is built from are bound to be exportable, unless
+    // `#[arri(bound = "...")]` replaces the bounds
+    let bounds = bounds::exportable_bounds(&input);
+    let (type_names, type_ids) = bounds::type_param_names(&input, &bounds);
+    let where_clause = bounds::where_clause(&input, &bounds);
 
-    // Only generate the specialized get_type_name implementation if there are generic arguments
-    let get_type_name_impl = if !type_params.is_empty() {
-        quote! {
-            /// Returns the type name of the struct or enum, including generic parameters.
-            fn get_type_name() -> String {
-                format!(
-                    "::ronky::--virtual--::external::{}",
-                    vec![stringify!(#struct_name).to_string(), #(#type_params::get_type_name()),*].join("")
-                )
+    // Types with read_only or write_only fields, or with fields of such types, have a distinct
+    // input schema, which gets its own id
+    let fields = match input.data {
+        Data::Struct(DataStruct { ref fields, .. }) => fields.iter().collect::<Vec<_>>(),
+        Data::Enum(DataEnum { ref variants, .. }) => variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let is_directional = match has_directional_fields(fields.iter().copied()) {
+        true => quote!(true),
+        false => {
+            let mut seen = std::collections::HashSet::new();
+            let field_types = fields
+                .iter()
+                .map(|field| &field.ty)
+                .filter(|ty| seen.insert(ty.to_token_stream().to_string()))
+                .collect::<Vec<_>>();
+            quote! {
+                ronky::is_directional_with::<Self>(|| {
+                    false #(|| <#field_types as ronky::Exportable>::is_directional())*
+                })
             }
         }
-    } else {
-        // For non-generic types, use the default implementation
-        quote! {}
     };
 
+    // Generic types are named after their generic arguments
+    let type_name = match type_names.is_empty() {
+        true => quote!(ronky::type_utils::get_type_name::<Self>()),
+        false => quote! {
+            format!(
+                "::ronky::--virtual--::external::{}",
+                vec![stringify!(#struct_name).to_string(), #(#type_names),*].join("")
+            )
+        },
+    };
+
+    // The id is built at runtime, as it depends on the naming of the export
+    let name = struct_name.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    let id = match explicit_id(&input) {
+        Some(id) => quote!(#id.to_string()),
+        None => quote!(ronky::naming().type_id(module_path!(), #name)),
+    };
+    let id = match type_ids.is_empty() {
+        true => id,
+        false => quote! {
+            ronky::naming().generic_id(&#id, &[#(#type_ids),*])
+        },
+    };
+    // Types without generic parameters have a single schema, which is serialized once
+    let schema_json_impl = match generics.params.is_empty() {
+        true => quote! {
+            /// Returns the serialized schema of the struct or enum, which is built once.
+            fn schema_json() -> Option<&'static str> {
+                static SCHEMA_JSON: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+                SCHEMA_JSON
+                    .get_or_init(|| ronky::ExportContext::new().export::<Self>().serialize())
+                    .as_deref()
+            }
+        },
+        false => quote! {},
+    };
+
+    // Generate the partial companion for named structs annotated with #[arri(partial)]
+    let partial_impl = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref fields),
+            ..
+        }) => parsers::attributes::properties::extract(&input.attrs)
+            .map(|attrs| partial::export_partial(&input, &fields.named, &attrs))
+            .unwrap_or_default(),
+        _ => quote! {},
+    };
+
+    // The attributes of an enum are parsed once for its implementations below. Invalid
+    // attributes are reported by the export itself, so the implementations are left out
+    let enum_attrs = match input.data {
+        Data::Enum(DataEnum { ref variants, .. }) => enum_variants::extract(&input.attrs)
+            .ok()
+            .map(|attrs| (variants, attrs)),
+        _ => None,
+    };
+
+    // Enums without fields can be the keys of a map
+    let map_key_impl = enum_attrs
+        .as_ref()
+        .map(|(variants, attrs)| export_map_key(&input, variants, attrs))
+        .unwrap_or_default();
+
+    // Enums with `#[arri(repr = "int")]` are encoded as their discriminants
+    let int_enum_impl = enum_attrs
+        .as_ref()
+        .map(|(variants, attrs)| export_int_enum(&input, variants, attrs))
+        .unwrap_or_default();
+
     // Only generate serialization if the feature is enabled in the derive crate
     let serialization_impl = {
         #[cfg(feature = "serialization")]
         {
-            crate::serialization::generate_serialization(&input)
+            let serialization = crate::serialization::generate_serialization(&input);
+            let example_test = crate::examples::generate_example_test(&input);
+            // Untagged enums are decoded by trying their variants in order
+            let untagged = enum_attrs
+                .as_ref()
+                .map(|(variants, attrs)| r#enum::export_untagged_impl(&input, variants, attrs))
+                .unwrap_or_default();
+            quote! {
+                #serialization
+                #example_test
+                #untagged
+            }
         }
         #[cfg(not(feature = "serialization"))]
         {
@@ -154,35 +256,85 @@ pub fn exported_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Only generate validation if the feature is enabled in the derive crate
+    let validation_impl = {
+        #[cfg(feature = "validation")]
+        {
+            crate::validation::generate_validation(&input)
+        }
+        #[cfg(not(feature = "validation"))]
+        {
+            quote! {}
+        }
+    };
+
     quote! {
         impl #impl_generics ronky::Exportable for #struct_name #ty_generics #where_clause {
             /// Exports the struct or enum as a serializable representation.
             fn export_internal() -> impl ronky::Serializable {
                 #export
             }
-            #get_type_name_impl
+
+            /// Returns the type name of the struct or enum, including generic parameters.
+            fn get_type_name() -> String {
+                ronky::directional_name::<Self>(|| #type_name)
+            }
+
+            /// Returns the id of the schema of the struct or enum.
+            fn get_schema_id() -> String {
+                ronky::directional_name::<Self>(|| #id)
+            }
+
+            /// Returns whether the input and output schemas of the struct or enum differ.
+            fn is_directional() -> bool {
+                #is_directional
+            }
+            #schema_json_impl
         }
 
+        #partial_impl
+        #map_key_impl
+        #int_enum_impl
         #serialization_impl
+        #validation_impl
     }
     .into()
 }
 
+/// Retrieves the explicit `#[arri(id = "...")]` of a struct or enum, if any.
+///
+/// Invalid attributes are reported by the export itself, so they are ignored here.
+fn explicit_id(input: &DeriveInput) -> Option<LitStr> {
+    match input.data {
+        Data::Enum(_) => parsers::attributes::enum_variants::extract(&input.attrs)
+            .ok()?
+            .into_iter()
+            .rev()
+            .find_map(|attr| attr.id),
+        _ => parsers::attributes::properties::extract(&input.attrs)
+            .ok()?
+            .into_iter()
+            .rev()
+            .find_map(|attr| attr.id),
+    }
+}
+
 /// A procedural macro to derive the `Serializable` trait for structs.
 ///
 /// This macro generates an implementation of the `arri_repr::Serializable` trait for the
 /// annotated struct. It automatically generates the `serialize()` method based on struct fields,
-/// and optionally implements `set_metadata()` and `set_nullable()` if the corresponding fields exist.
+/// and optionally implements `try_set_metadata()` and `try_set_nullable()` if the corresponding fields exist.
 ///
 /// # Field name transformations
 /// - `is_deprecated` becomes `"isDeprecated"`
 /// - `deprecated_since` becomes `"deprecatedSince"`
 /// - `deprecated_message` becomes `"deprecatedNote"`
+/// - `extensions` becomes `"x-extensions"`
 /// - Other `snake_case` fields are converted to `camelCase`
 ///
 /// # Special field detection
-/// - If a field named `metadata` of type `Option<MetadataSchema>` exists, `set_metadata()` will be implemented
-/// - If a field named `nullable` or `is_nullable` of type `Option<bool>` exists, `set_nullable()` will be implemented
+/// - If a field named `metadata` of type `Option<MetadataSchema>` exists, `try_set_metadata()` will be implemented
+/// - If a field named `nullable` or `is_nullable` of type `Option<bool>` exists, `try_set_nullable()` will be implemented
 /// - Missing fields will generate warnings unless disabled with `#[arri_disable(metadata, nullable)]`
 ///
 /// Raw identifiers like `r#type`, `r#ref`, and `r#enum` are supported and serialized without the `r#` prefix.
@@ -194,8 +346,8 @@ pub fn exported_derive(input: TokenStream) -> TokenStream {
 ///     pub id: Option<String>,
 ///     pub description: Option<String>,
 ///     pub is_deprecated: Option<bool>,
-///     pub metadata: Option<MetadataSchema>,  // Enables set_metadata()
-///     pub nullable: Option<bool>,            // Enables set_nullable()
+///     pub metadata: Option<MetadataSchema>,  // Enables try_set_metadata()
+///     pub nullable: Option<bool>,            // Enables try_set_nullable()
 /// }
 /// ```
 #[proc_macro_derive(Serializable, attributes(arri_disable))]
@@ -306,26 +458,31 @@ fn generate_serializable_impl(
         });
     }
 
-    // Generate set_metadata implementation if metadata field exists
+    // Generate try_set_metadata implementation if metadata field exists
     let set_metadata_impl = if has_metadata {
         quote! {
-            fn set_metadata(&mut self, metadata: #crate_path::MetadataSchema) {
+            fn try_set_metadata(
+                &mut self,
+                metadata: #crate_path::MetadataSchema,
+            ) -> Result<(), #crate_path::SchemaError> {
                 self.metadata = Some(if let Some(current) = &self.metadata {
                     current.clone() | metadata
                 } else {
                     metadata
                 });
+                Ok(())
             }
         }
     } else {
         quote! {}
     };
 
-    // Generate set_nullable implementation if nullable field exists
+    // Generate try_set_nullable implementation if nullable field exists
     let set_nullable_impl = if let Some(field_name) = nullable_field {
         quote! {
-            fn set_nullable(&mut self, nullable: bool) {
+            fn try_set_nullable(&mut self, nullable: bool) -> Result<(), #crate_path::SchemaError> {
                 self.#field_name = Some(nullable);
+                Ok(())
             }
         }
     } else {
@@ -336,11 +493,10 @@ fn generate_serializable_impl(
         #(#warnings)*
 
         impl #impl_generics #crate_path::Serializable for #struct_name #ty_generics #where_clause {
-            fn serialize(&self) -> Option<String> {
-                #crate_path::Serializer::builder()
+            fn write_to(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+                #crate_path::Serializer::new(out)
                     #(#serialize_calls)*
-                    .build()
-                    .into()
+                    .finish()
             }
 
             #set_metadata_impl
@@ -362,6 +518,7 @@ fn transform_field_name(field_name: &str) -> String {
         "is_nullable" => "isNullable".to_string(),
         "is_strict" => "isStrict".to_string(),
         "optional_properties" => "optionalProperties".to_string(),
+        "extensions" => "x-extensions".to_string(),
         _ => {
             // Preserve all leading underscores; camelCase the remainder
             let underscores = field_name.chars().take_while(|&c| c == '_').count();
@@ -393,3 +550,44 @@ fn get_disabled_warnings(attrs: &[syn::Attribute]) -> Vec<String> {
 
     disabled
 }
+
+/// An attribute for traits whose trait objects are sent as a tagged union of their implementors.
+///
+/// `Box<dyn Trait>` exports as a `TaggedUnionSchema` over the types registered with
+/// [`macro@implementor`], keyed by their tag under the discriminator (`"type"` by default). The
+/// trait gets `Any` as a supertrait. With the `serialization` feature, `Box<dyn Trait>`
+/// implements serde's `Serialize` and `Deserialize` with the same tags.
+///
+/// # Example
+/// ```ignore
+/// #[ronky::polymorphic(discriminator = "kind")]
+/// trait Action {
+///     fn run(&self);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn polymorphic(args: TokenStream, item: TokenStream) -> TokenStream {
+    polymorphic::export_polymorphic(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// An attribute for `impl Trait for Type` blocks, registering the type as an implementor of a
+/// trait marked with [`macro@polymorphic`].
+///
+/// The type must implement `Exportable`, and with the `serialization` feature serde's
+/// `Serialize` and `Deserialize` as an object.
+///
+/// # Example
+/// ```ignore
+/// #[ronky::implementor(tag = "email")]
+/// impl Action for SendEmail {
+///     fn run(&self) {}
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn implementor(args: TokenStream, item: TokenStream) -> TokenStream {
+    polymorphic::export_implementor(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -1,46 +1,79 @@
 // TODO: Refactor move this (or applicable contents to the parsers(attributes) module)
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{Attribute, Expr, ExprLit, Field, Lit, LitStr, Meta, MetaNameValue};
+use syn::{Attribute, Expr, Field, Ident, LitStr};
+
+use crate::parsers::{
+    attributes::{Deprecation, Description, DocOptions, LiteralValue, fields::FieldArguments},
+    docs::{self, Piece},
+};
 
 /// Extracts documentation comments from the given attributes and converts them into a `TokenStream`.
 ///
 /// # Arguments
 ///
 /// * `attrs` - A slice of `Attribute` objects to extract documentation from.
+/// * `description` - The `#[arri(description...)]` argument, which takes precedence over the docs.
+/// * `options` - The `#[arri(docs(...))]` options used to process the docs.
 ///
 /// # Returns
 ///
 /// Returns an `Option<TokenStream>` containing the extracted documentation, or `None` if no documentation is found.
-fn extract_docs(attrs: &[Attribute]) -> Option<TokenStream> {
-    let docs = attrs
-        .iter()
-        .filter(|attr| attr.path().is_ident("doc"))
-        .map(|attr| match &attr.meta {
-            Meta::NameValue(MetaNameValue {
-                value:
-                    Expr::Lit(ExprLit {
-                        lit: Lit::Str(line),
-                        ..
-                    }),
-                ..
-            }) => line.value().trim().to_string(),
-            _ => unreachable!(),
-        })
-        .collect::<Vec<String>>()
-        .join("\n");
+fn extract_docs(
+    attrs: &[Attribute],
+    description: Option<&Description>,
+    options: &DocOptions,
+) -> Option<TokenStream> {
+    match description {
+        Some(Description::Inline(description)) => {
+            return Some(
+                quote! {
+                    metadata.set_description(#description);
+                }
+                .into(),
+            );
+        }
+        Some(Description::File(path)) => {
+            return Some(
+                quote! {
+                    metadata.set_description(
+                        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #path)).trim()
+                    );
+                }
+                .into(),
+            );
+        }
+        None => {}
+    }
 
-    match docs.is_empty() {
-        true => None,
-        false => Some(
+    let pieces = docs::process(attrs, options)?;
+    if let [Piece::Text(docs)] = pieces.as_slice() {
+        return Some(
             quote! {
                 metadata.set_description(#docs);
             }
             .into(),
-        ),
+        );
     }
+
+    // Links to types are resolved to their schema ids when exporting
+    let pieces = pieces.iter().map(|piece| match piece {
+        Piece::Text(text) => quote! { #text },
+        Piece::Type { ty, name } => quote! {
+            (&ronky::DocLink::<#ty>::new(#name)).schema_id().as_str()
+        },
+    });
+    Some(
+        quote! {
+            metadata.set_description({
+                use ronky::{ExportableDocLink as _, NamedDocLink as _};
+                [#(#pieces),*].concat()
+            });
+        }
+        .into(),
+    )
 }
 
 /// Extracts the `deprecated` attribute from the given attributes and converts it into a `TokenStream`.
@@ -48,11 +81,29 @@ fn extract_docs(attrs: &[Attribute]) -> Option<TokenStream> {
 /// # Arguments
 ///
 /// * `attrs` - A slice of `Attribute` objects to extract the `deprecated` attribute from.
+/// * `deprecation` - The `#[arri(deprecated(...))]` argument, which takes precedence over the
+///   `deprecated` attribute.
 ///
 /// # Returns
 ///
 /// Returns an `Option<TokenStream>` containing the extracted `deprecated` attribute, or `None` if not found.
-fn extract_deprecated(attrs: &[Attribute]) -> Option<TokenStream> {
+fn extract_deprecated(
+    attrs: &[Attribute],
+    deprecation: Option<&Deprecation>,
+) -> Option<TokenStream> {
+    if let Some(Deprecation { since, note }) = deprecation {
+        let since = since.iter();
+        let note = note.iter();
+        return Some(
+            quote! {
+                metadata.set_deprecated(true);
+                #(metadata.set_deprecated_since(#since);)*
+                #(metadata.set_deprecated_message(#note);)*
+            }
+            .into(),
+        );
+    }
+
     attrs
         .iter()
         .find(|attr| attr.path().is_ident("deprecated"))
@@ -90,20 +141,208 @@ fn extract_deprecated(attrs: &[Attribute]) -> Option<TokenStream> {
         })
 }
 
-/// Combines the extracted documentation and `deprecated` attributes into a single `TokenStream`.
+/// Converts the aliases of the `#[arri(alias = "...")]` attributes into a `TokenStream`.
+///
+/// # Arguments
+///
+/// * `args` - The parsed `#[arri(...)]` arguments of the field or variant.
+///
+/// # Returns
+///
+/// Returns an `Option<TokenStream>` registering the aliases, or `None` if there are no aliases.
+fn extract_aliases(args: &[FieldArguments]) -> Option<TokenStream> {
+    let aliases = args
+        .iter()
+        .flat_map(|arg| arg.aliases.iter())
+        .collect::<Vec<&LitStr>>();
+
+    match aliases.is_empty() {
+        true => None,
+        false => Some(
+            quote! {
+                #(metadata.add_alias(#aliases);)*
+            }
+            .into(),
+        ),
+    }
+}
+
+/// Converts the validation constraints of the `#[arri(min = ..., ...)]` attributes into a
+/// `TokenStream`.
+///
+/// # Arguments
+///
+/// * `args` - The parsed `#[arri(...)]` arguments of the field.
+///
+/// # Returns
+///
+/// Returns an `Option<TokenStream>` setting the constraints, or `None` if there are no constraints.
+fn extract_constraints(args: &[FieldArguments]) -> Option<TokenStream> {
+    let mut setters = Vec::new();
+
+    for arg in args {
+        if let Some(min) = arg.min {
+            let min = Literal::f64_suffixed(min);
+            setters.push(quote!(.set_min(#min)));
+        }
+        if let Some(max) = arg.max {
+            let max = Literal::f64_suffixed(max);
+            setters.push(quote!(.set_max(#max)));
+        }
+        if let Some(min_length) = arg.min_length {
+            setters.push(quote!(.set_min_length(#min_length)));
+        }
+        if let Some(max_length) = arg.max_length {
+            setters.push(quote!(.set_max_length(#max_length)));
+        }
+        if let Some(pattern) = &arg.pattern {
+            setters.push(quote!(.set_pattern(#pattern)));
+        }
+        if let Some(format) = &arg.format {
+            setters.push(quote!(.set_format(#format)));
+        }
+    }
+
+    match setters.is_empty() {
+        true => None,
+        false => Some(
+            quote! {
+                metadata.set_constraints(ronky::ConstraintsSchema::new()#(#setters)*.to_owned());
+            }
+            .into(),
+        ),
+    }
+}
+
+/// Converts the `#[arri(example = ...)]` literal of a field into a `TokenStream`.
+///
+/// # Arguments
+///
+/// * `args` - The parsed `#[arri(...)]` arguments of the field.
+///
+/// # Returns
+///
+/// Returns an `Option<TokenStream>` setting the example, or `None` if there is no example.
+fn extract_example(args: &[FieldArguments]) -> Option<TokenStream> {
+    let example = args.iter().rev().find_map(|arg| arg.example.as_ref())?;
+
+    let json = match example.to_json() {
+        Some(json) => quote!(#json),
+        // Strings are escaped by the serializer of the schema, so they're encoded the same way
+        None => quote!(<&str as ronky::Serializable>::serialize(&#example).unwrap_or_default()),
+    };
+
+    Some(
+        quote! {
+            metadata.set_example(#json);
+        }
+        .into(),
+    )
+}
+
+/// Converts the `#[arri(example = expr)]` expression of a type into a `TokenStream`.
+///
+/// The expression is a value of the type, which is encoded with serde, so it is only available
+/// with the `serialization` feature. An example which can't be encoded is reported as a
+/// `SchemaError::InvalidExample`.
+#[cfg(feature = "serialization")]
+fn extract_type_example(example: Option<&Expr>) -> Option<TokenStream2> {
+    example.map(|example| {
+        quote! {
+            let example: Self = #example;
+            match ronky::ExportedSerialize::to_json(&example) {
+                Ok(json) => {
+                    metadata.set_example(json);
+                }
+                Err(error) => ronky::report(ronky::SchemaError::InvalidExample {
+                    ty: std::any::type_name::<Self>(),
+                    error: error.to_string(),
+                }),
+            }
+        }
+    })
+}
+
+#[cfg(not(feature = "serialization"))]
+fn extract_type_example(example: Option<&Expr>) -> Option<TokenStream2> {
+    example.map(|example| {
+        quote::quote_spanned! {syn::spanned::Spanned::span(example) =>
+            compile_error!(
+                "An example of a type is encoded with serde, enable the `serialization` feature of ronky"
+            );
+        }
+    })
+}
+
+/// Converts the custom extensions of the `#[arri(meta(...))]` attributes into a `TokenStream`.
+///
+/// # Arguments
+///
+/// * `meta` - The `key = value` entries of the `meta(...)` arguments.
+///
+/// # Returns
+///
+/// Returns an `Option<TokenStream>` setting the extensions, or `None` if there are no extensions.
+fn extract_meta<'a>(
+    meta: impl IntoIterator<Item = &'a (Ident, LiteralValue)>,
+) -> Option<TokenStream> {
+    let (keys, values): (Vec<String>, Vec<&LiteralValue>) = meta
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .unzip();
+
+    match keys.is_empty() {
+        true => None,
+        false => Some(
+            quote! {
+                #(metadata.set_extension(#keys, #values);)*
+            }
+            .into(),
+        ),
+    }
+}
+
+/// Combines the extracted documentation, `deprecated` attributes, aliases, constraints and
+/// extensions into a single `TokenStream`.
 ///
 /// # Arguments
 ///
 /// * `attrs` - A slice of `Attribute` objects to extract attributes from.
+/// * `args` - The parsed `#[arri(...)]` arguments, used for the wire-level documentation, aliases,
+///   constraints and extensions.
+/// * `docs` - The `#[arri(docs(...))]` options inherited from the parent type.
 ///
 /// # Returns
 ///
 /// Returns an `Option<TokenStream>` containing the combined attributes, or `None` if no attributes are found.
-pub fn extract_attrs(attrs: &[Attribute]) -> Option<TokenStream> {
-    let docs: Option<TokenStream2> = extract_docs(attrs).map(Into::into);
-    let deprecated: Option<TokenStream2> = extract_deprecated(attrs).map(Into::into);
+pub fn extract_attrs(
+    attrs: &[Attribute],
+    args: &[FieldArguments],
+    docs: &DocOptions,
+) -> Option<TokenStream> {
+    let description = args.iter().rev().find_map(|arg| arg.description.as_ref());
+    let deprecation = args.iter().rev().find_map(|arg| arg.deprecated.as_ref());
+    let options = args
+        .iter()
+        .rev()
+        .find_map(|arg| arg.docs.as_ref())
+        .map_or_else(|| docs.clone(), |options| options.inherit(docs));
 
-    if docs.is_none() && deprecated.is_none() {
+    let docs: Option<TokenStream2> = extract_docs(attrs, description, &options).map(Into::into);
+    let deprecated: Option<TokenStream2> = extract_deprecated(attrs, deprecation).map(Into::into);
+    let aliases: Option<TokenStream2> = extract_aliases(args).map(Into::into);
+    let constraints: Option<TokenStream2> = extract_constraints(args).map(Into::into);
+    let example: Option<TokenStream2> = extract_example(args).map(Into::into);
+    let extensions: Option<TokenStream2> =
+        extract_meta(args.iter().flat_map(|arg| arg.meta.iter())).map(Into::into);
+
+    if docs.is_none()
+        && deprecated.is_none()
+        && aliases.is_none()
+        && constraints.is_none()
+        && example.is_none()
+        && extensions.is_none()
+    {
         return None;
     }
 
@@ -113,6 +352,10 @@ pub fn extract_attrs(attrs: &[Attribute]) -> Option<TokenStream> {
                 let mut metadata = ronky::MetadataSchema::new();
                 #deprecated;
                 #docs
+                #aliases
+                #constraints
+                #example
+                #extensions
                 metadata
             }
         }
@@ -120,23 +363,48 @@ pub fn extract_attrs(attrs: &[Attribute]) -> Option<TokenStream> {
     )
 }
 
+/// The type-level `#[arri(...)]` arguments which end up in the metadata of the type.
+#[derive(Default)]
+pub struct TypeMetadata {
+    /// The `key = value` entries of the `meta(...)` arguments.
+    pub meta: Vec<(Ident, LiteralValue)>,
+    /// The expression of the `example = ...` argument.
+    pub example: Option<Expr>,
+    /// The wire-level description.
+    pub description: Option<Description>,
+    /// The wire-level deprecation.
+    pub deprecated: Option<Deprecation>,
+    /// The options used to process the doc comments.
+    pub docs: Option<DocOptions>,
+}
+
 /// Extracts metadata from the given attributes and constructs a `MetadataSchema` object.
 ///
 /// # Arguments
 ///
 /// * `attrs` - A slice of `Attribute` objects to extract metadata from.
+/// * `args` - The type-level `#[arri(...)]` arguments which end up in the metadata.
 ///
 /// # Returns
 ///
 /// Returns a `TokenStream` representing the constructed `MetadataSchema` object.
-pub fn extract(attrs: &[Attribute]) -> TokenStream {
-    let base: proc_macro2::TokenStream =
-        extract_attrs(attrs).map_or(quote!(ronky::MetadataSchema::new()), Into::into);
+pub fn extract(attrs: &[Attribute], args: TypeMetadata) -> TokenStream {
+    let example = extract_type_example(args.example.as_ref());
+    let args = FieldArguments {
+        meta: args.meta,
+        description: args.description,
+        deprecated: args.deprecated,
+        docs: args.docs,
+        ..Default::default()
+    };
+    let base: proc_macro2::TokenStream = extract_attrs(attrs, &[args], &DocOptions::default())
+        .map_or(quote!(ronky::MetadataSchema::new()), Into::into);
 
     quote! {
         {
             let mut metadata = #base;
-            metadata.set_id(ronky::type_utils::get_type_name_from(Self::get_type_name()));
+            #example
+            metadata.set_id(ronky::define_id::<Self>());
             metadata
         }
     }
@@ -148,14 +416,20 @@ pub fn extract(attrs: &[Attribute]) -> TokenStream {
 /// # Arguments
 ///
 /// * `field` - A reference to a `Field` object to extract metadata from.
+/// * `args` - The parsed `#[arri(...)]` arguments of the field.
+/// * `docs` - The `#[arri(docs(...))]` options inherited from the parent type.
 ///
 /// # Returns
 ///
 /// Returns an `Option<TokenStream>` containing the extracted metadata, or `None` if no attributes are present.
-pub fn extract_from_field(field: &Field) -> Option<TokenStream> {
+pub fn extract_from_field(
+    field: &Field,
+    args: &[FieldArguments],
+    docs: &DocOptions,
+) -> Option<TokenStream> {
     if field.attrs.is_empty() {
         return None;
     }
 
-    extract_attrs(&field.attrs)
+    extract_attrs(&field.attrs, args, docs)
 }
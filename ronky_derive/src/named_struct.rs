@@ -1,12 +1,16 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{DeriveInput, Field, punctuated::Punctuated, token::Comma};
+use quote::{quote, quote_spanned};
+use syn::{DeriveInput, Field, punctuated::Punctuated, spanned::Spanned, token::Comma};
 
 use crate::{
-    metadata,
+    metadata::{self, TypeMetadata},
     parsers::{
         ParsedField,
-        attributes::properties::{self, CaseTransform},
+        attributes::{
+            DocOptions,
+            fields::{self as field_attributes, FieldArguments},
+            properties::{self, CaseTransform},
+        },
         parse_field,
     },
 };
@@ -16,40 +20,21 @@ use crate::{
 /// # Parameters
 /// - `$properties`: The collection to which the generated code will be pushed.
 /// - `$field`: The field being processed.
+/// - `$field_name`: The name of the field in the schema.
 /// - `$stream`: The type information or other data associated with the field.
 /// - `$args`: Additional arguments or metadata for the field.
+/// - `$docs`: The doc options inherited from the type.
 /// - `$set_property`: The method or function to set the property in the schema.
-/// - `$rename_all`: Optional case transformation to apply to field names.
 macro_rules! process_field {
-    ($properties:ident => $field:expr, $stream:expr, $args:expr, $set_property:ident, $rename_all:expr) => {{
-        // Extract the default field name from the field's identifier.
-        let default_field_name = $field.ident.as_ref().unwrap().to_string();
-
-        // Strip the r# prefix if present (for raw identifiers like r#type)
-        let default_field_name = default_field_name
-            .strip_prefix("r#")
-            .unwrap_or(&default_field_name)
-            .to_string();
-
-        // Determine the field name, allowing for renaming via arguments.
-        let field_name = $args
-            .into_iter()
-            .find(|a| a.rename.is_some())
-            .and_then(|a| a.rename)
-            .unwrap_or_else(|| {
-                // Apply rename_all transformation if no explicit rename is provided
-                match $rename_all {
-                    Some(transform) => transform.transform(&default_field_name),
-                    None => default_field_name,
-                }
-            });
+    ($properties:ident => $field:expr, $field_name:expr, $stream:expr, $args:expr, $docs:expr, $set_property:ident) => {{
+        let field_name = $field_name;
 
         // Convert the stream into a TokenStream for further processing.
         let stream: proc_macro2::TokenStream = $stream.into();
 
         // Extract metadata from the field, if available, and generate code to set it.
-        let field_metadata: Option<proc_macro2::TokenStream> = metadata::extract_from_field($field)
-            .map(|ts| {
+        let field_metadata: Option<proc_macro2::TokenStream> =
+            metadata::extract_from_field($field, &$args, $docs).map(|ts| {
                 let ts: proc_macro2::TokenStream = ts.into();
                 quote! {
                     use ronky::Serializable;
@@ -68,12 +53,52 @@ macro_rules! process_field {
     }};
 }
 
+/// Determines the name of a field in the schema.
+///
+/// An explicit `#[arri(rename = "...")]` takes precedence over the `rename_all` transformation.
+///
+/// # Arguments
+///
+/// * `field` - The field to determine the name for.
+/// * `args` - The parsed `#[arri(...)]` arguments of the field.
+/// * `rename_all` - Optional case transformation to apply to the field name.
+///
+/// # Returns
+///
+/// Returns the name of the field as it should appear in the schema.
+pub(crate) fn field_name(
+    field: &Field,
+    args: &[FieldArguments],
+    rename_all: &Option<CaseTransform>,
+) -> String {
+    // Extract the default field name from the field's identifier.
+    let default_field_name = field.ident.as_ref().unwrap().to_string();
+
+    // Strip the r# prefix if present (for raw identifiers like r#type)
+    let default_field_name = default_field_name
+        .strip_prefix("r#")
+        .unwrap_or(&default_field_name)
+        .to_string();
+
+    // Determine the field name, allowing for renaming via arguments.
+    args.iter()
+        .find_map(|a| a.rename.clone())
+        .unwrap_or_else(|| {
+            // Apply rename_all transformation if no explicit rename is provided
+            match rename_all {
+                Some(transform) => transform.transform(&default_field_name),
+                None => default_field_name,
+            }
+        })
+}
+
 /// Exports the fields of a struct as a `TokenStream` for use in schema generation.
 ///
 /// # Arguments
 ///
 /// * `fields` - A reference to a `Punctuated` collection of `Field` objects representing the struct's fields.
 /// * `rename_all` - Optional case transformation to apply to all field names.
+/// * `docs` - The doc options of the type, which the fields inherit.
 ///
 /// # Returns
 ///
@@ -81,18 +106,76 @@ macro_rules! process_field {
 pub fn export_struct_fields(
     fields: &Punctuated<Field, Comma>,
     rename_all: &Option<CaseTransform>,
+    docs: &DocOptions,
 ) -> TokenStream {
-    let mut properties = Vec::new();
+    let mut parsed_fields = Vec::new();
     for field in fields.iter() {
         match parse_field(field) {
-            Ok(ParsedField::Required(field, stream, args)) => {
-                process_field!(properties => field, stream, args, set_property, rename_all);
+            Ok(parsed) => parsed_fields.push(parsed),
+            Err(stream) => return stream,
+        }
+    }
+
+    // Resolve the schema names first, so aliases can be checked against all of them.
+    let names = parsed_fields
+        .iter()
+        .map(|parsed| {
+            let (ParsedField::Required(field, _, args) | ParsedField::Optional(field, _, args)) =
+                parsed;
+            let aliases = args.iter().flat_map(|a| a.aliases.clone()).collect();
+            (field_name(field, args, rename_all), aliases)
+        })
+        .collect::<Vec<_>>();
+
+    if let Err(err) = field_attributes::validate_aliases(&names) {
+        return err.into_compile_error().into();
+    }
+
+    let mut properties = Vec::new();
+    for (parsed, (field_name, _)) in parsed_fields.into_iter().zip(names) {
+        let (ParsedField::Required(field, _, args) | ParsedField::Optional(field, _, args)) =
+            &parsed;
+
+        // Fields that are only part of one direction are skipped for the other direction.
+        let read_only = args.iter().any(|a| a.read_only == Some(true));
+        let write_only = args.iter().any(|a| a.write_only == Some(true));
+        let direction = match (read_only, write_only) {
+            (true, true) => {
+                return quote_spanned!(field.ident.span() =>
+                    compile_error!("A field cannot be both read_only and write_only.")
+                )
+                .into();
             }
-            Ok(ParsedField::Optional(field, stream, args)) => {
-                process_field!(properties => field, stream, args, set_optional_property, rename_all);
+            (true, false) => Some(quote!(ronky::ExportDirection::Output)),
+            (false, true) => Some(quote!(ronky::ExportDirection::Input)),
+            (false, false) => None,
+        };
+
+        // Fields without an explicit name follow the casing of the export context.
+        let explicit = rename_all.is_some() || args.iter().any(|a| a.rename.is_some());
+        let field_name = match explicit {
+            true => quote!(#field_name),
+            false => quote!(&ronky::property_name(#field_name)),
+        };
+
+        let mut property = Vec::new();
+        match parsed {
+            ParsedField::Required(field, stream, args) => {
+                process_field!(property => field, field_name, stream, args, docs, set_property);
+            }
+            ParsedField::Optional(field, stream, args) => {
+                process_field!(property => field, field_name, stream, args, docs, set_optional_property);
             }
-            Err(stream) => return stream,
         }
+
+        properties.push(match direction {
+            Some(direction) => quote! {
+                if ronky::export_direction() == #direction {
+                    #(#property)*
+                }
+            },
+            None => quote!(#(#property)*),
+        });
     }
 
     quote! {
@@ -114,14 +197,24 @@ pub fn export_struct_fields(
 ///
 /// Returns a `TokenStream` that defines the schema for the named struct.
 pub fn export_named_struct(input: &DeriveInput, fields: &Punctuated<Field, Comma>) -> TokenStream {
-    let metadata: proc_macro2::TokenStream = metadata::extract(&input.attrs).into();
-    let (attrs, rename_all) = match properties::extract(&input.attrs) {
+    let (attrs, rename_all, type_metadata) = match properties::extract(&input.attrs) {
         Ok(attrs) => {
             if attrs.is_empty() {
-                (None, None)
+                (None, None, TypeMetadata::default())
             } else {
                 let strict = attrs.iter().find_map(|a| a.strict);
                 let rename_all = attrs.iter().find_map(|a| a.rename_all.clone());
+                let type_metadata =
+                    attrs
+                        .into_iter()
+                        .fold(TypeMetadata::default(), |mut metadata, a| {
+                            metadata.meta.extend(a.meta);
+                            metadata.example = a.example.or(metadata.example);
+                            metadata.description = a.description.or(metadata.description);
+                            metadata.deprecated = a.deprecated.or(metadata.deprecated);
+                            metadata.docs = a.docs.or(metadata.docs);
+                            metadata
+                        });
 
                 let attrs_tokens = strict.map(|strict_value| {
                     quote! {
@@ -129,13 +222,16 @@ pub fn export_named_struct(input: &DeriveInput, fields: &Punctuated<Field, Comma
                     }
                 });
 
-                (attrs_tokens, rename_all)
+                (attrs_tokens, rename_all, type_metadata)
             }
         }
-        Err(stream) => (Some(stream.into()), None),
+        Err(stream) => (Some(stream.into()), None, TypeMetadata::default()),
     };
+    let docs = type_metadata.docs.clone().unwrap_or_default();
+    let metadata: proc_macro2::TokenStream = metadata::extract(&input.attrs, type_metadata).into();
 
-    let base_export: proc_macro2::TokenStream = export_struct_fields(fields, &rename_all).into();
+    let base_export: proc_macro2::TokenStream =
+        export_struct_fields(fields, &rename_all, &docs).into();
 
     quote! {
         use ronky::Serializable;
@@ -1,7 +1,11 @@
 use arri_common::EnumTransformation;
 use quote::quote;
 
-use super::{parse_required_string, parse_string_or_list};
+use super::{
+    Bound, Deprecation, Description, DocOptions, LiteralValue, check_description, check_unique,
+    parse_bound, parse_deprecation, parse_description, parse_doc_options, parse_flag, parse_meta,
+    parse_required_expr, parse_required_string, parse_string_or_list,
+};
 
 /// Macro to match an `EnumTransformation` variant and generate corresponding tokens.
 macro_rules! enum_transformation_match {
@@ -29,9 +33,62 @@ define_arri_attrs! {
         pub(crate) transform: Vec<EnumTransformation>,
         /// Optional discriminator value for the enum variants.
         pub(crate) discriminator: Option<String>,
+        /// Custom metadata extensions from `#[arri(meta(...))]`.
+        pub(crate) meta: Vec<(syn::Ident, LiteralValue)>,
+        /// An example value of the type.
+        pub(crate) example: Option<syn::Expr>,
+        /// Generates a test which checks the examples against the schema.
+        pub(crate) test_examples: Option<bool>,
+        /// The wire-level description, overriding the doc comments.
+        pub(crate) description: Option<Description>,
+        /// The wire-level deprecation, overriding `#[deprecated]`.
+        pub(crate) deprecated: Option<Deprecation>,
+        /// Options for turning the doc comments into a description.
+        pub(crate) docs: Option<DocOptions>,
+        /// An explicit id of the schema, replacing the name of the type.
+        pub(crate) id: Option<syn::LitStr>,
+        /// The wire representation of the enum, `"int"` for its integer discriminants.
+        pub(crate) repr: Option<syn::LitStr>,
+        /// Whether the enum is encoded as the value of its variant, without a tag.
+        pub(crate) untagged: Option<bool>,
+        /// The bounds of the generated implementations, replacing the inferred bounds.
+        pub(crate) bound: Option<Bound>,
     }
 
     parse(args, input) {
+        "bound" => {
+            args.bound = Some(parse_bound(input)?);
+        }
+        "id" => {
+            let value = parse_required_string(input, "id")?;
+            super::fields::validate_name(&value, "An id")?;
+            args.id = Some(value);
+        }
+        "docs" => {
+            args.docs = Some(parse_doc_options(input)?);
+        }
+        "description" => {
+            args.description = Some(parse_description(input, "description", &args.description)?);
+        }
+        "description_file" => {
+            args.description = Some(parse_description(
+                input,
+                "description_file",
+                &args.description,
+            )?);
+        }
+        "deprecated" => {
+            args.deprecated = Some(parse_deprecation(input)?);
+        }
+        "meta" => {
+            args.meta.extend(parse_meta(input)?);
+        }
+        "example" => {
+            args.example = Some(parse_required_expr(input, "example")?);
+        }
+        "test_examples" => {
+            args.test_examples = Some(parse_flag(input)?);
+        }
         "transform" => {
             let transforms = parse_string_or_list(input, "transform")?;
             let transforms = transforms
@@ -40,9 +97,32 @@ define_arri_attrs! {
                 .collect::<Result<Vec<_>, _>>()?;
             args.transform.extend(transforms);
         }
+        "repr" => {
+            let value = parse_required_string(input, "repr")?;
+            if value.value() != "int" {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "The only supported representation is `repr = \"int\"`.",
+                ));
+            }
+            args.repr = Some(value);
+        }
+        "untagged" => {
+            args.untagged = Some(parse_flag(input)?);
+        }
         "discriminator" => {
             let value = parse_required_string(input, "discriminator")?;
             args.discriminator = Some(value.value());
         }
     }
+
+    check = check_merged;
+}
+
+/// Checks the arguments of all `#[arri(...)]` attributes of an enum together, as a
+/// description, id or bound in one attribute may conflict with one in another.
+fn check_merged(attrs: &[&syn::Attribute], args: &[EnumVariants]) -> syn::Result<()> {
+    check_description(attrs, args, |arg| arg.description.as_ref())?;
+    check_unique(attrs, args, "id", |arg| arg.id.as_ref())?;
+    check_unique(attrs, args, "bound", |arg| arg.bound.as_ref())
 }
@@ -1,21 +1,34 @@
-use syn::LitStr;
+use std::collections::HashSet;
 
-use super::{parse_flag, parse_required_string};
+use arri_common::FORMATS;
+use syn::{Attribute, LitStr};
 
-fn validate_rename(value: &LitStr) -> syn::Result<()> {
+use super::{
+    Deprecation, Description, DocOptions, LiteralValue, check_description, last_with_attr,
+    parse_deprecation, parse_description, parse_doc_options, parse_flag, parse_meta,
+    parse_required_expr, parse_required_length, parse_required_literal, parse_required_number,
+    parse_required_string,
+};
+
+/// Validates a name used on the wire, `kind` is used as the subject of the error message
+/// (e.g. "A rename").
+pub(crate) fn validate_name(value: &LitStr, kind: &str) -> syn::Result<()> {
     let new_name = value.value();
 
     if new_name.is_empty() {
-        Err(syn::Error::new(value.span(), "A rename cannot be empty"))
+        Err(syn::Error::new(
+            value.span(),
+            format!("{} cannot be empty", kind),
+        ))
     } else if new_name.contains(' ') {
         Err(syn::Error::new(
             value.span(),
-            "A rename cannot contain spaces",
+            format!("{} cannot contain spaces", kind),
         ))
     } else if new_name.starts_with(|c: char| c.is_numeric()) {
         Err(syn::Error::new(
             value.span(),
-            "A rename cannot start with a number",
+            format!("{} cannot start with a number", kind),
         ))
     } else if new_name
         .chars()
@@ -23,13 +36,29 @@ fn validate_rename(value: &LitStr) -> syn::Result<()> {
     {
         Err(syn::Error::new(
             value.span(),
-            "A rename can only contain a-z, A-Z and 0-9 or _",
+            format!("{} can only contain a-z, A-Z and 0-9 or _", kind),
         ))
     } else {
         Ok(())
     }
 }
 
+/// Ensures that a `#[arri(pattern = "...")]` is a valid regular expression.
+///
+/// The expression can only be checked when the `validation` feature is enabled, as that is the
+/// only case in which it is compiled at runtime.
+#[cfg(feature = "validation")]
+fn validate_pattern(value: &LitStr) -> syn::Result<()> {
+    regex::Regex::new(&value.value())
+        .map(|_| ())
+        .map_err(|err| syn::Error::new(value.span(), format!("Invalid pattern: {}", err)))
+}
+
+#[cfg(not(feature = "validation"))]
+fn validate_pattern(_value: &LitStr) -> syn::Result<()> {
+    Ok(())
+}
+
 define_arri_attrs! {
     /// Represents parsed attributes for struct fields.
     pub(crate) struct FieldArguments {
@@ -37,16 +66,208 @@ define_arri_attrs! {
         pub(crate) rename: Option<String>,
         /// Indicates whether the type is nullable.
         pub(crate) is_nullable: Option<bool>,
+        /// Alternative names that are accepted when decoding.
+        pub(crate) aliases: Vec<LitStr>,
+        /// Indicates whether the field is only part of the output schema.
+        pub(crate) read_only: Option<bool>,
+        /// Indicates whether the field is only part of the input schema.
+        pub(crate) write_only: Option<bool>,
+        /// The minimum (inclusive) numeric value.
+        pub(crate) min: Option<f64>,
+        /// The maximum (inclusive) numeric value.
+        pub(crate) max: Option<f64>,
+        /// The minimum length of a string or collection.
+        pub(crate) min_length: Option<u64>,
+        /// The maximum length of a string or collection.
+        pub(crate) max_length: Option<u64>,
+        /// A regular expression a string must match.
+        pub(crate) pattern: Option<LitStr>,
+        /// A well-known format a string must follow.
+        pub(crate) format: Option<LitStr>,
+        /// Indicates whether the constraints of the nested value are validated as well.
+        pub(crate) validate: Option<bool>,
+        /// An example value of the field.
+        pub(crate) example: Option<LiteralValue>,
+        /// Custom metadata extensions from `#[arri(meta(...))]`.
+        pub(crate) meta: Vec<(syn::Ident, LiteralValue)>,
+        /// The wire-level description, overriding the doc comments.
+        pub(crate) description: Option<Description>,
+        /// The wire-level deprecation, overriding `#[deprecated]`.
+        pub(crate) deprecated: Option<Deprecation>,
+        /// Options for turning the doc comments into a description.
+        pub(crate) docs: Option<DocOptions>,
+        /// The `ronky::ResultShape` the `Result`s in the field are exported with.
+        pub(crate) result: Option<syn::Expr>,
     }
 
     parse(args, input) {
+        "docs" => {
+            args.docs = Some(parse_doc_options(input)?);
+        }
+        "description" => {
+            args.description = Some(parse_description(input, "description", &args.description)?);
+        }
+        "description_file" => {
+            args.description = Some(parse_description(
+                input,
+                "description_file",
+                &args.description,
+            )?);
+        }
+        "deprecated" => {
+            args.deprecated = Some(parse_deprecation(input)?);
+        }
+        "meta" => {
+            args.meta.extend(parse_meta(input)?);
+        }
         "rename" => {
             let value = parse_required_string(input, "rename")?;
-            validate_rename(&value)?;
+            validate_name(&value, "A rename")?;
             args.rename = Some(value.value());
         }
         "nullable" => {
             args.is_nullable = Some(parse_flag(input)?);
         }
+        "alias" => {
+            let value = parse_required_string(input, "alias")?;
+            validate_name(&value, "An alias")?;
+            args.aliases.push(value);
+        }
+        "read_only" => {
+            args.read_only = Some(parse_flag(input)?);
+        }
+        "write_only" => {
+            args.write_only = Some(parse_flag(input)?);
+        }
+        "min" => {
+            args.min = Some(parse_required_number(input, "min")?);
+        }
+        "max" => {
+            args.max = Some(parse_required_number(input, "max")?);
+        }
+        "min_length" => {
+            args.min_length = Some(parse_required_length(input, "min_length")?);
+        }
+        "max_length" => {
+            args.max_length = Some(parse_required_length(input, "max_length")?);
+        }
+        "pattern" => {
+            let value = parse_required_string(input, "pattern")?;
+            validate_pattern(&value)?;
+            args.pattern = Some(value);
+        }
+        "format" => {
+            let value = parse_required_string(input, "format")?;
+            if !FORMATS.contains(&value.value().as_str()) {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format!("Unknown format, expected one of: {}", FORMATS.join(", ")),
+                ));
+            }
+            args.format = Some(value);
+        }
+        "validate" => {
+            args.validate = Some(parse_flag(input)?);
+        }
+        "example" => {
+            args.example = Some(parse_required_literal(input, "example")?);
+        }
+        "result" => {
+            args.result = Some(parse_required_expr(input, "result")?);
+        }
     }
+
+    check = check_merged;
+}
+
+/// Ensures that no alias collides with a canonical name or with another alias.
+///
+/// # Arguments
+///
+/// * `names` - The final name of every field or variant, together with its aliases.
+pub(crate) fn validate_aliases(names: &[(String, Vec<LitStr>)]) -> syn::Result<()> {
+    let mut seen = names
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<HashSet<String>>();
+
+    for (name, aliases) in names {
+        for alias in aliases {
+            let value = alias.value();
+
+            if value == *name {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    "An alias cannot be the same as the name it aliases",
+                ));
+            }
+
+            if !seen.insert(value.clone()) {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    format!("The alias '{}' collides with another name or alias", value),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the arguments of all `#[arri(...)]` attributes of a field together, as a bound or
+/// description in one attribute may conflict with one in another.
+fn check_merged(attrs: &[&Attribute], args: &[FieldArguments]) -> syn::Result<()> {
+    check_description(attrs, args, |arg| arg.description.as_ref())?;
+    check_range(attrs, args, |arg| arg.min, |arg| arg.max, ("min", "max"))?;
+    check_range(
+        attrs,
+        args,
+        |arg| arg.min_length,
+        |arg| arg.max_length,
+        ("min_length", "max_length"),
+    )
+}
+
+/// Ensures that the lower bound of a range isn't greater than its upper bound, reporting the
+/// conflict on the attribute which gave the later bound.
+fn check_range<T: PartialOrd>(
+    attrs: &[&Attribute],
+    args: &[FieldArguments],
+    lower: impl Fn(&FieldArguments) -> Option<T>,
+    upper: impl Fn(&FieldArguments) -> Option<T>,
+    (lower_key, upper_key): (&str, &str),
+) -> syn::Result<()> {
+    let (Some((lower, lower_idx, lower_attr)), Some((upper, upper_idx, upper_attr))) = (
+        last_with_attr(attrs, args, lower),
+        last_with_attr(attrs, args, upper),
+    ) else {
+        return Ok(());
+    };
+
+    match lower > upper {
+        true if lower_idx > upper_idx => Err(syn::Error::new_spanned(
+            lower_attr,
+            format!("{} cannot be greater than {}", lower_key, upper_key),
+        )),
+        true => Err(syn::Error::new_spanned(
+            upper_attr,
+            format!("{} cannot be less than {}", upper_key, lower_key),
+        )),
+        false => Ok(()),
+    }
+}
+
+/// Checks whether any of the fields is marked with `#[arri(read_only)]` or `#[arri(write_only)]`,
+/// in which case the input and output schemas of the type differ.
+///
+/// # Arguments
+///
+/// * `fields` - The fields to check.
+pub(crate) fn has_directional_fields<'a>(fields: impl IntoIterator<Item = &'a syn::Field>) -> bool {
+    fields.into_iter().any(|field| {
+        extract(&field.attrs).is_ok_and(|args| {
+            args.iter()
+                .any(|a| a.read_only == Some(true) || a.write_only == Some(true))
+        })
+    })
 }
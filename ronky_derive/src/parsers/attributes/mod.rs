@@ -1,7 +1,8 @@
 use proc_macro::TokenStream;
-use quote::quote_spanned;
+use proc_macro2::Literal;
+use quote::{ToTokens, quote_spanned};
 use syn::{
-    Attribute, LitBool, LitStr, Meta, Token, bracketed,
+    Attribute, Expr, Ident, Lit, LitBool, LitInt, LitStr, Meta, Token, bracketed, parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
@@ -16,6 +17,291 @@ pub(crate) fn parse_required_string(input: ParseStream, key_name: &str) -> syn::
     input.parse::<LitStr>()
 }
 
+/// Parses a required `= <number>`, which may be negative or fractional.
+pub(crate) fn parse_required_number(input: ParseStream, key_name: &str) -> syn::Result<f64> {
+    if !input.peek(syn::token::Eq) {
+        return Err(input.error(format!("Expected '=' after '{}'", key_name)));
+    }
+    input.parse::<syn::token::Eq>()?;
+
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+
+    let value = match input.parse::<Lit>()? {
+        Lit::Int(lit) => lit.base10_parse::<f64>()?,
+        Lit::Float(lit) => lit.base10_parse::<f64>()?,
+        lit => {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("Expected a number after '{}'", key_name),
+            ));
+        }
+    };
+
+    Ok(if negative { -value } else { value })
+}
+
+/// Parses a required `= <integer>`, used for lengths.
+pub(crate) fn parse_required_length(input: ParseStream, key_name: &str) -> syn::Result<u64> {
+    if !input.peek(syn::token::Eq) {
+        return Err(input.error(format!("Expected '=' after '{}'", key_name)));
+    }
+    input.parse::<syn::token::Eq>()?;
+    input.parse::<LitInt>()?.base10_parse::<u64>()
+}
+
+/// A literal value of an attribute, such as a `#[arri(meta(...))]` entry or a field example.
+#[derive(Debug, Clone)]
+pub(crate) enum LiteralValue {
+    Str(LitStr),
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+}
+
+impl LiteralValue {
+    /// Returns the value encoded as JSON, or `None` for strings, which are escaped at runtime.
+    pub(crate) fn to_json(&self) -> Option<String> {
+        match self {
+            Self::Str(_) => None,
+            Self::Bool(value) => Some(value.to_string()),
+            Self::Int(value) => Some(value.to_string()),
+            Self::Float(value) => Some(value.to_string()),
+        }
+    }
+}
+
+impl ToTokens for LiteralValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Str(value) => value.to_tokens(tokens),
+            Self::Bool(value) => value.to_tokens(tokens),
+            Self::Int(value) => Literal::i64_suffixed(*value).to_tokens(tokens),
+            Self::Float(value) => Literal::f64_suffixed(*value).to_tokens(tokens),
+        }
+    }
+}
+
+/// Parses a string, boolean or (optionally negative) number literal.
+pub(crate) fn parse_literal_value(input: ParseStream) -> syn::Result<LiteralValue> {
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+
+    let lit = input
+        .parse::<Lit>()
+        .map_err(|err| syn::Error::new(err.span(), "Expected a string, boolean or number"))?;
+
+    match lit {
+        Lit::Str(lit) if !negative => Ok(LiteralValue::Str(lit)),
+        Lit::Bool(lit) if !negative => Ok(LiteralValue::Bool(lit.value)),
+        Lit::Int(lit) => {
+            let value = lit.base10_parse::<i64>()?;
+            Ok(LiteralValue::Int(if negative { -value } else { value }))
+        }
+        Lit::Float(lit) => {
+            let value = lit.base10_parse::<f64>()?;
+            Ok(LiteralValue::Float(if negative { -value } else { value }))
+        }
+        lit => Err(syn::Error::new(
+            lit.span(),
+            "Expected a string, boolean or number",
+        )),
+    }
+}
+
+/// Parses the `(key = value, ...)` list of `#[arri(meta(...))]`.
+///
+/// Values can be strings, booleans or (optionally negative) numbers, and every key can only be
+/// used once.
+pub(crate) fn parse_meta(input: ParseStream) -> syn::Result<Vec<(Ident, LiteralValue)>> {
+    if !input.peek(syn::token::Paren) {
+        return Err(input.error("Expected a list of key = value pairs after 'meta'"));
+    }
+
+    let content;
+    parenthesized!(content in input);
+
+    let mut entries: Vec<(Ident, LiteralValue)> = Vec::new();
+    while !content.is_empty() {
+        let key: Ident = content.parse()?;
+        if entries.iter().any(|(existing, _)| *existing == key) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("Duplicate meta key: {}", key),
+            ));
+        }
+        content.parse::<Token![=]>()?;
+
+        entries.push((key, parse_literal_value(&content)?));
+        goto_next(&content)?;
+    }
+
+    Ok(entries)
+}
+
+/// The wire-level description of `#[arri(description = "...")]` or
+/// `#[arri(description_file = "...")]`, which takes precedence over doc comments.
+#[derive(Debug, Clone)]
+pub(crate) enum Description {
+    /// The description itself.
+    Inline(LitStr),
+    /// A path, relative to the manifest of the crate, of a file containing the description.
+    File(LitStr),
+}
+
+/// The wire-level deprecation of `#[arri(deprecated(...))]`, which takes precedence over
+/// Rust's `#[deprecated]`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Deprecation {
+    /// The version since which the item is deprecated.
+    pub(crate) since: Option<LitStr>,
+    /// A note explaining the deprecation.
+    pub(crate) note: Option<LitStr>,
+}
+
+/// Parses a `description` or `description_file` key, which cannot be combined.
+pub(crate) fn parse_description(
+    input: ParseStream,
+    key_name: &str,
+    existing: &Option<Description>,
+) -> syn::Result<Description> {
+    let span = input.span();
+    let value = parse_required_string(input, key_name)?;
+
+    if existing.is_some() {
+        return Err(syn::Error::new(
+            span,
+            "Only one of description and description_file can be used",
+        ));
+    }
+
+    match key_name {
+        "description_file" => Ok(Description::File(value)),
+        _ => Ok(Description::Inline(value)),
+    }
+}
+
+/// Parses `deprecated` with an optional `(since = "...", note = "...")` list.
+pub(crate) fn parse_deprecation(input: ParseStream) -> syn::Result<Deprecation> {
+    let mut deprecation = Deprecation::default();
+    if !input.peek(syn::token::Paren) {
+        return Ok(deprecation);
+    }
+
+    let content;
+    parenthesized!(content in input);
+
+    while !content.is_empty() {
+        let key: Ident = content.parse()?;
+        match key.to_string().as_str() {
+            "since" => deprecation.since = Some(parse_required_string(&content, "since")?),
+            "note" => deprecation.note = Some(parse_required_string(&content, "note")?),
+            key_str => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Unknown deprecation property: {}", key_str),
+                ));
+            }
+        }
+        goto_next(&content)?;
+    }
+
+    Ok(deprecation)
+}
+
+/// The options of `#[arri(docs(...))]`, which control how doc comments become descriptions.
+///
+/// Options on a type are inherited by its fields and variants.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DocOptions {
+    /// Only keep the first paragraph of the docs.
+    pub(crate) summary: Option<bool>,
+    /// The titles of the sections (e.g. `# Examples`) which are stripped from the docs.
+    pub(crate) strip: Option<Vec<String>>,
+}
+
+impl DocOptions {
+    /// Returns these options, falling back on the options of the parent for unset options.
+    pub(crate) fn inherit(&self, parent: &Self) -> Self {
+        Self {
+            summary: self.summary.or(parent.summary),
+            strip: self.strip.clone().or_else(|| parent.strip.clone()),
+        }
+    }
+}
+
+/// Parses the `(summary, strip = [...])` list of `#[arri(docs(...))]`.
+///
+/// A bare `strip` strips the sections which only make sense in rustdoc.
+pub(crate) fn parse_doc_options(input: ParseStream) -> syn::Result<DocOptions> {
+    if !input.peek(syn::token::Paren) {
+        return Err(input.error("Expected a list of options after 'docs'"));
+    }
+
+    let content;
+    parenthesized!(content in input);
+
+    let mut options = DocOptions::default();
+    while !content.is_empty() {
+        let key: Ident = content.parse()?;
+        match key.to_string().as_str() {
+            "summary" => options.summary = Some(parse_flag(&content)?),
+            "strip" => {
+                options.strip = Some(match content.peek(syn::token::Eq) {
+                    true => parse_string_or_list(&content, "strip")?,
+                    false => crate::parsers::docs::RUSTDOC_SECTIONS
+                        .iter()
+                        .map(|section| section.to_string())
+                        .collect(),
+                });
+            }
+            key_str => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Unknown docs property: {}", key_str),
+                ));
+            }
+        }
+        goto_next(&content)?;
+    }
+
+    Ok(options)
+}
+
+/// Parses a required `= <literal>`, see [`parse_literal_value`].
+pub(crate) fn parse_required_literal(
+    input: ParseStream,
+    key_name: &str,
+) -> syn::Result<LiteralValue> {
+    if !input.peek(syn::token::Eq) {
+        return Err(input.error(format!("Expected '=' after '{}'", key_name)));
+    }
+    input.parse::<syn::token::Eq>()?;
+    parse_literal_value(input)
+}
+
+/// Parses a required `= <expression>`.
+pub(crate) fn parse_required_expr(input: ParseStream, key_name: &str) -> syn::Result<Expr> {
+    if !input.peek(syn::token::Eq) {
+        return Err(input.error(format!("Expected '=' after '{}'", key_name)));
+    }
+    input.parse::<syn::token::Eq>()?;
+    input.parse::<Expr>()
+}
+
+/// The where predicates of `#[arri(bound = "...")]`, which replace the inferred bounds.
+pub(crate) type Bound = Punctuated<syn::WherePredicate, Token![,]>;
+
+/// Parses a required `= "T: Trait, ..."` into where predicates, which may be empty.
+pub(crate) fn parse_bound(input: ParseStream) -> syn::Result<Bound> {
+    let value = parse_required_string(input, "bound")?;
+    value.parse_with(Bound::parse_terminated)
+}
+
 /// Parses an optional `= true/false` flag, defaulting to `true` when bare.
 pub(crate) fn parse_flag(input: ParseStream) -> syn::Result<bool> {
     if input.peek(syn::token::Eq) {
@@ -53,6 +339,9 @@ pub(crate) fn parse_string_or_list(input: ParseStream, key_name: &str) -> syn::R
 /// The `parse(args, input)` block names the variables available in handler blocks:
 /// - `args`: the struct being populated
 /// - `input`: the `ParseStream`
+///
+/// An optional `check = path;` names a function which receives the `#[arri(...)]` attributes
+/// and their parsed arguments, to check arguments which conflict across attributes.
 macro_rules! define_arri_attrs {
     (
         $(#[$meta:meta])*
@@ -66,6 +355,8 @@ macro_rules! define_arri_attrs {
         parse($args:ident, $input:ident) {
             $( $key:literal => $handler:block )*
         }
+
+        $(check = $check:path;)?
     ) => {
         $(#[$meta])*
         #[derive(Debug, Default)]
@@ -99,7 +390,12 @@ macro_rules! define_arri_attrs {
         pub(crate) fn extract(
             attrs: &[::syn::Attribute],
         ) -> Result<Vec<$name>, ::proc_macro::TokenStream> {
-            super::parse_arri_attrs(attrs)
+            let parsed = super::parse_arri_attrs(attrs)?;
+            $(
+                $check(&super::arri_attrs(attrs), &parsed)
+                    .map_err(|err| ::proc_macro::TokenStream::from(err.into_compile_error()))?;
+            )?
+            Ok(parsed)
         }
     };
 }
@@ -108,12 +404,87 @@ pub(crate) mod enum_variants;
 pub(crate) mod fields;
 pub(crate) mod properties;
 
-/// Parses attributes with the `#[arri(...)]` format and extracts their arguments.
-pub(crate) fn parse_arri_attrs<T: Parse>(attrs: &[Attribute]) -> Result<Vec<T>, TokenStream> {
-    let attrs: Vec<_> = attrs
+/// Retrieves the `#[arri(...)]` attributes, in the order of their parsed arguments.
+pub(crate) fn arri_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
         .iter()
         .filter(|attr| attr.path().is_ident("arri"))
-        .collect();
+        .collect()
+}
+
+/// Retrieves the last of the parsed arguments which has a value, with the attribute it was
+/// given in. Later attributes override earlier ones.
+pub(crate) fn last_with_attr<'a, A, T>(
+    attrs: &[&'a Attribute],
+    args: &'a [A],
+    get: impl Fn(&'a A) -> Option<T>,
+) -> Option<(T, usize, &'a Attribute)> {
+    args.iter()
+        .enumerate()
+        .rev()
+        .find_map(|(idx, arg)| get(arg).map(|value| (value, idx, attrs[idx])))
+}
+
+/// Ensures that `description` and `description_file` aren't both given, also not in separate
+/// attributes, reporting the conflict on the later of the two.
+pub(crate) fn check_description<A>(
+    attrs: &[&Attribute],
+    args: &[A],
+    get: impl Fn(&A) -> Option<&Description>,
+) -> syn::Result<()> {
+    let inline = last_with_attr(attrs, args, |arg| match get(arg) {
+        Some(Description::Inline(value)) => Some(value),
+        _ => None,
+    });
+    let file = last_with_attr(attrs, args, |arg| match get(arg) {
+        Some(Description::File(value)) => Some(value),
+        _ => None,
+    });
+
+    match (inline, file) {
+        (Some((inline, inline_idx, _)), Some((file, file_idx, _))) => Err(syn::Error::new(
+            match inline_idx > file_idx {
+                true => inline.span(),
+                false => file.span(),
+            },
+            "Only one of description and description_file can be used",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Ensures that an argument isn't given different values in separate attributes, reporting the
+/// conflict on the later value.
+pub(crate) fn check_unique<A, T: ToTokens>(
+    attrs: &[&Attribute],
+    args: &[A],
+    name: &str,
+    get: impl Fn(&A) -> Option<&T>,
+) -> syn::Result<()> {
+    let mut values = args
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, arg)| get(arg).map(|value| (value, idx)));
+    let Some((first, _)) = values.next() else {
+        return Ok(());
+    };
+
+    let first = first.to_token_stream().to_string();
+    match values.find(|(value, _)| value.to_token_stream().to_string() != first) {
+        Some((value, idx)) => {
+            let message = format!("Only one {} can be used", name);
+            Err(match value.to_token_stream().is_empty() {
+                true => syn::Error::new_spanned(attrs[idx], message),
+                false => syn::Error::new_spanned(value, message),
+            })
+        }
+        None => Ok(()),
+    }
+}
+
+/// Parses attributes with the `#[arri(...)]` format and extracts their arguments.
+pub(crate) fn parse_arri_attrs<T: Parse>(attrs: &[Attribute]) -> Result<Vec<T>, TokenStream> {
+    let attrs = arri_attrs(attrs);
 
     let mut parsed_attributes = Vec::new();
 
@@ -2,7 +2,11 @@ use heck::{
     ToKebabCase, ToLowerCamelCase, ToPascalCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
 };
 
-use super::{parse_flag, parse_required_string};
+use super::{
+    Bound, Deprecation, Description, DocOptions, LiteralValue, check_description, check_unique,
+    parse_bound, parse_deprecation, parse_description, parse_doc_options, parse_flag, parse_meta,
+    parse_required_expr, parse_required_string,
+};
 
 /// Supported casing transformations for rename_all.
 #[derive(Debug, Clone, PartialEq)]
@@ -30,6 +34,18 @@ impl CaseTransform {
         }
     }
 
+    /// Returns the attribute value this transformation was parsed from.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::CamelCase => "camelCase",
+            Self::PascalCase => "PascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+            Self::KebabCase => "kebab-case",
+            Self::ScreamingKebabCase => "SCREAMING-KEBAB-CASE",
+        }
+    }
+
     /// Applies the case transformation to a field name.
     pub(crate) fn transform(&self, name: &str) -> String {
         match self {
@@ -43,6 +59,47 @@ impl CaseTransform {
     }
 }
 
+/// The options of `#[arri(partial)]`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PartialOptions {
+    /// A custom name of the patch, instead of `{Struct}Patch`.
+    pub(crate) name: Option<syn::Ident>,
+    /// Whether the patch derives serde's `Serialize` and `Deserialize`.
+    pub(crate) serde: bool,
+}
+
+/// Parses `partial`, `partial = "Name"` or `partial(name = "Name", serde)`.
+fn parse_partial(input: syn::parse::ParseStream) -> syn::Result<PartialOptions> {
+    let mut options = PartialOptions::default();
+    if input.peek(syn::token::Eq) {
+        options.name = Some(parse_required_string(input, "partial")?.parse()?);
+        return Ok(options);
+    }
+    if !input.peek(syn::token::Paren) {
+        return Ok(options);
+    }
+
+    let content;
+    syn::parenthesized!(content in input);
+
+    while !content.is_empty() {
+        let key: syn::Ident = content.parse()?;
+        match key.to_string().as_str() {
+            "name" => options.name = Some(parse_required_string(&content, "name")?.parse()?),
+            "serde" => options.serde = parse_flag(&content)?,
+            key_str => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Unknown partial option: {}", key_str),
+                ));
+            }
+        }
+        super::goto_next(&content)?;
+    }
+
+    Ok(options)
+}
+
 define_arri_attrs! {
     /// Represents parsed properties arguments.
     pub(crate) struct PropertiesArguments {
@@ -50,9 +107,63 @@ define_arri_attrs! {
         pub(crate) strict: Option<bool>,
         /// Optional rename_all transformation for all fields.
         pub(crate) rename_all: Option<CaseTransform>,
+        /// Generates a partial (patch) companion of the struct.
+        pub(crate) partial: Option<PartialOptions>,
+        /// Custom metadata extensions from `#[arri(meta(...))]`.
+        pub(crate) meta: Vec<(syn::Ident, LiteralValue)>,
+        /// An example value of the type.
+        pub(crate) example: Option<syn::Expr>,
+        /// Generates a test which checks the examples against the schema.
+        pub(crate) test_examples: Option<bool>,
+        /// The wire-level description, overriding the doc comments.
+        pub(crate) description: Option<Description>,
+        /// The wire-level deprecation, overriding `#[deprecated]`.
+        pub(crate) deprecated: Option<Deprecation>,
+        /// Options for turning the doc comments into a description.
+        pub(crate) docs: Option<DocOptions>,
+        /// An explicit id of the schema, replacing the name of the type.
+        pub(crate) id: Option<syn::LitStr>,
+        /// The bounds of the generated implementations, replacing the inferred bounds.
+        pub(crate) bound: Option<Bound>,
     }
 
     parse(args, input) {
+        "bound" => {
+            args.bound = Some(parse_bound(input)?);
+        }
+        "id" => {
+            let value = parse_required_string(input, "id")?;
+            super::fields::validate_name(&value, "An id")?;
+            args.id = Some(value);
+        }
+        "docs" => {
+            args.docs = Some(parse_doc_options(input)?);
+        }
+        "description" => {
+            args.description = Some(parse_description(input, "description", &args.description)?);
+        }
+        "description_file" => {
+            args.description = Some(parse_description(
+                input,
+                "description_file",
+                &args.description,
+            )?);
+        }
+        "deprecated" => {
+            args.deprecated = Some(parse_deprecation(input)?);
+        }
+        "meta" => {
+            args.meta.extend(parse_meta(input)?);
+        }
+        "example" => {
+            args.example = Some(parse_required_expr(input, "example")?);
+        }
+        "test_examples" => {
+            args.test_examples = Some(parse_flag(input)?);
+        }
+        "partial" => {
+            args.partial = Some(parse_partial(input)?);
+        }
         "strict" => {
             args.strict = Some(parse_flag(input)?);
         }
@@ -73,4 +184,14 @@ define_arri_attrs! {
             }
         }
     }
+
+    check = check_merged;
+}
+
+/// Checks the arguments of all `#[arri(...)]` attributes of a struct together, as a
+/// description, id or bound in one attribute may conflict with one in another.
+fn check_merged(attrs: &[&syn::Attribute], args: &[PropertiesArguments]) -> syn::Result<()> {
+    check_description(attrs, args, |arg| arg.description.as_ref())?;
+    check_unique(attrs, args, "id", |arg| arg.id.as_ref())?;
+    check_unique(attrs, args, "bound", |arg| arg.bound.as_ref())
 }
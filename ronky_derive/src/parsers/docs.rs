@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, MetaNameValue, PathArguments, TypePath};
+
+use super::attributes::DocOptions;
+
+/// The sections which are stripped by a bare `#[arri(docs(strip))]`, as they only make sense in
+/// the Rust documentation.
+pub(crate) const RUSTDOC_SECTIONS: &[&str] = &["Examples", "Panics", "Errors", "Safety"];
+
+/// Prefixes which disambiguate the kind of item of an intra-doc link, e.g. `struct@Pet`.
+const DISAMBIGUATORS: &[&str] = &[
+    "struct@",
+    "enum@",
+    "union@",
+    "trait@",
+    "type@",
+    "mod@",
+    "module@",
+    "fn@",
+    "function@",
+    "method@",
+    "field@",
+    "variant@",
+    "const@",
+    "constant@",
+    "static@",
+    "value@",
+    "macro@",
+    "prim@",
+    "primitive@",
+    "derive@",
+    "tymethod@",
+];
+
+/// Disambiguators of intra-doc links which may point to a type.
+const TYPE_DISAMBIGUATORS: &[&str] = &["struct@", "enum@", "union@", "type@"];
+
+/// Stands in for a type link in the description until it's split into [`Piece`]s.
+const LINK_MARKER: char = '\u{0}';
+
+/// A part of a description.
+pub(crate) enum Piece {
+    /// Text which is kept as-is.
+    Text(String),
+    /// A link to a type, which is resolved to its schema id when exporting.
+    Type {
+        /// The linked type.
+        ty: TypePath,
+        /// The name of the linked type, used if it isn't exported.
+        name: String,
+    },
+}
+
+/// Converts the doc comments of an item into a description.
+///
+/// Unlike rustdoc's raw attributes, the description keeps the paragraph and code block
+/// structure of the docs, with the common indentation removed. Intra-doc links to types become
+/// [`Piece::Type`]s, other intra-doc links are replaced by the name of the linked item.
+///
+/// # Arguments
+///
+/// * `attrs` - The attributes of the item, of which only `#[doc = "..."]` is used.
+/// * `options` - The `#[arri(docs(...))]` options of the item.
+///
+/// # Returns
+///
+/// Returns the pieces of the description, or `None` if the item has no (remaining)
+/// documentation.
+pub(crate) fn process(attrs: &[Attribute], options: &DocOptions) -> Option<Vec<Piece>> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .flat_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) => line
+                .value()
+                .split('\n')
+                .map(|line| line.trim_end().to_string())
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        })
+        .collect::<Vec<String>>();
+
+    let mut links = Vec::new();
+    let lines = structure(&dedent(lines), options, &mut links);
+    let lines = match options.summary == Some(true) {
+        true => summary(&lines),
+        false => lines,
+    };
+
+    let description = lines.join("\n").trim_matches('\n').to_string();
+    if description.is_empty() {
+        return None;
+    }
+
+    // The markers are kept in order, a summary only drops those of the later paragraphs
+    let mut links = links.into_iter();
+    let mut pieces = Vec::new();
+    for (index, text) in description.split(LINK_MARKER).enumerate() {
+        if index > 0 {
+            pieces.extend(links.next());
+        }
+        if !text.is_empty() {
+            pieces.push(Piece::Text(text.to_string()));
+        }
+    }
+
+    Some(pieces)
+}
+
+/// Removes the indentation which all non-empty lines have in common.
+fn dedent(lines: Vec<String>) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+
+    lines
+        .into_iter()
+        .map(|line| line.get(indent..).unwrap_or_default().to_string())
+        .collect()
+}
+
+/// Walks the lines of the docs, stripping sections, hidden code lines and link definitions,
+/// and resolving the intra-doc links outside of code blocks. Links to types are collected into
+/// `links`, in the order of their markers.
+fn structure(lines: &[String], options: &DocOptions, links: &mut Vec<Piece>) -> Vec<String> {
+    let definitions = lines
+        .iter()
+        .filter_map(|line| link_definition(line.trim_start()))
+        .map(|(label, target)| (label.to_lowercase(), target.to_string()))
+        .collect::<HashMap<_, _>>();
+    let mut output: Vec<String> = Vec::new();
+    // The fence and whether the code block contains Rust code
+    let mut code_block: Option<(String, bool)> = None;
+    // The level of the heading of the section which is being stripped
+    let mut stripping: Option<usize> = None;
+
+    for line in lines {
+        let trimmed = line.trim_start();
+
+        if let Some((fence, is_rust)) = &code_block {
+            let is_rust = *is_rust;
+            if trimmed.starts_with(fence.as_str()) {
+                code_block = None;
+            } else if is_rust && is_hidden(trimmed) {
+                continue;
+            }
+
+            if stripping.is_none() {
+                output.push(match is_rust && trimmed.starts_with("##") {
+                    // `##` escapes a line which starts with `#` in rustdoc
+                    true => line.replacen("##", "#", 1),
+                    false => line.clone(),
+                });
+            }
+            continue;
+        }
+
+        if let Some(fence) = ["```", "~~~"]
+            .into_iter()
+            .find(|fence| trimmed.starts_with(fence))
+        {
+            let info = trimmed.trim_start_matches(fence.chars().next().unwrap_or('`'));
+            code_block = Some((fence.to_string(), is_rust_block(info)));
+        } else if let Some((level, title)) = heading(trimmed) {
+            match stripping {
+                Some(stripped) if level > stripped => {}
+                _ => stripping = is_stripped(title, options).then_some(level),
+            }
+        } else if is_link_definition(trimmed) {
+            continue;
+        }
+
+        if stripping.is_none() {
+            output.push(match code_block {
+                Some(_) => line.clone(),
+                None => resolve_links(line, &definitions, links),
+            });
+        }
+    }
+
+    // Stripped sections and link definitions may leave consecutive blank lines behind
+    output.dedup_by(|a, b| a.is_empty() && b.is_empty());
+    output
+}
+
+/// Keeps the first paragraph of the docs, joined into a single line.
+fn summary(lines: &[String]) -> Vec<String> {
+    let paragraph = lines
+        .iter()
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.trim())
+        .collect::<Vec<_>>();
+
+    vec![paragraph.join(" ")]
+}
+
+/// Returns whether a section with the given title should be stripped.
+fn is_stripped(title: &str, options: &DocOptions) -> bool {
+    options
+        .strip
+        .iter()
+        .flatten()
+        .any(|section| section.eq_ignore_ascii_case(title))
+}
+
+/// Parses a markdown heading into its level and title.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let title = line.get(level..)?;
+
+    match (1..=6).contains(&level) && (title.is_empty() || title.starts_with(' ')) {
+        true => Some((level, title.trim())),
+        false => None,
+    }
+}
+
+/// Returns whether the info string of a code fence denotes Rust code, which is the default in
+/// rustdoc.
+fn is_rust_block(info: &str) -> bool {
+    info.split([',', ' '])
+        .map(str::trim)
+        .filter(|attribute| !attribute.is_empty())
+        .all(|attribute| {
+            attribute == "rust"
+                || attribute.starts_with("edition")
+                || [
+                    "ignore",
+                    "should_panic",
+                    "no_run",
+                    "compile_fail",
+                    "test_harness",
+                    "standalone_crate",
+                ]
+                .contains(&attribute)
+        })
+}
+
+/// Returns whether a line of a Rust code block is hidden in rustdoc.
+fn is_hidden(line: &str) -> bool {
+    line == "#" || line.starts_with("# ")
+}
+
+/// Returns whether a line defines the target of a reference-style intra-doc link, e.g.
+/// `[Pet]: crate::models::Pet`.
+fn is_link_definition(line: &str) -> bool {
+    link_definition(line).is_some_and(|(_, target)| item_name(target).is_some())
+}
+
+/// Parses the label and target of a reference-style link definition, which may also point to a
+/// URL.
+fn link_definition(line: &str) -> Option<(&str, &str)> {
+    line.strip_prefix('[')
+        .and_then(|line| line.split_once("]:"))
+        .map(|(label, target)| (label, target.trim()))
+}
+
+/// Resolves the name of the item an intra-doc link points to, or `None` if the target isn't a
+/// Rust path (e.g. a URL).
+fn item_name(target: &str) -> Option<&str> {
+    let target = DISAMBIGUATORS
+        .iter()
+        .find_map(|prefix| target.strip_prefix(prefix))
+        .unwrap_or(target);
+    let target = target
+        .strip_suffix("()")
+        .or_else(|| target.strip_suffix('!'))
+        .unwrap_or(target);
+    // Generic arguments aren't part of the schema id
+    let target = target.split('<').next().unwrap_or(target);
+
+    let is_path = !target.is_empty()
+        && target.split("::").all(|segment| {
+            segment.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+
+    match is_path {
+        true => target.rsplit("::").next(),
+        false => None,
+    }
+}
+
+/// Parses the target of an intra-doc link which points to a type, e.g. `crate::models::Pet` or
+/// `Page<Pet>`, and not to a function, macro, trait, module, variant or member.
+fn type_path(target: &str) -> Option<TypePath> {
+    let target = match DISAMBIGUATORS
+        .iter()
+        .find_map(|prefix| Some((*prefix, target.strip_prefix(prefix)?)))
+    {
+        Some((prefix, target)) => TYPE_DISAMBIGUATORS.contains(&prefix).then_some(target)?,
+        None => target,
+    };
+
+    let path = syn::parse_str::<TypePath>(target).ok()?;
+    let is_type = |segment: &&syn::PathSegment| {
+        segment
+            .ident
+            .to_string()
+            .starts_with(|c: char| c.is_uppercase())
+    };
+    let segments = path.path.segments.iter().collect::<Vec<_>>();
+    let (last, modules) = segments.split_last()?;
+
+    let is_type_path = path.qself.is_none()
+        && is_type(last)
+        && modules
+            .iter()
+            .all(|segment| !is_type(segment) && matches!(segment.arguments, PathArguments::None));
+    is_type_path.then_some(path)
+}
+
+/// Replaces the intra-doc links of a line by the names of the linked items, leaving inline code
+/// and regular links untouched. Bare links to types are replaced by a marker instead, and
+/// collected into `links`.
+fn resolve_links(
+    line: &str,
+    definitions: &HashMap<String, String>,
+    links: &mut Vec<Piece>,
+) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['[', '`']) {
+        let previous = rest[..start].chars().next_back();
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Inline code is copied as-is
+        if rest.starts_with('`') {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            let fence = &rest[..ticks];
+            match rest[ticks..].find(fence) {
+                Some(end) => {
+                    output.push_str(&rest[..ticks + end + ticks]);
+                    rest = &rest[ticks + end + ticks..];
+                }
+                None => {
+                    output.push_str(rest);
+                    rest = "";
+                }
+            }
+            continue;
+        }
+
+        let Some(end) = rest.find(']') else {
+            break;
+        };
+        let text = &rest[1..end];
+        let after = &rest[end + 1..];
+
+        // `[text](target)` and `[text][target]`
+        let explicit = [('(', ')'), ('[', ']')]
+            .into_iter()
+            .find_map(|(open, close)| {
+                let target = after.strip_prefix(open)?;
+                let close = target.find(close)?;
+                Some((&target[..close], 1 + close + 1))
+            });
+
+        let (replacement, consumed) = match explicit {
+            Some((target, length)) => {
+                let target = match target.is_empty() {
+                    true => text.trim_matches('`'),
+                    false => target.trim_matches('`'),
+                };
+                match item_name(target) {
+                    Some(_) => (Some(text.to_string()), end + 1 + length),
+                    None => (None, end + 1 + length),
+                }
+            }
+            // `[Pet]` isn't confused with an index such as `values[i]`
+            None if previous.is_some_and(|c| c.is_alphanumeric() || c == '_') => (None, end + 1),
+            None => {
+                let code = text.strip_prefix('`').and_then(|t| t.strip_suffix('`'));
+                let target = definitions
+                    .get(&text.to_lowercase())
+                    .map_or(code.unwrap_or(text), String::as_str);
+
+                let name = item_name(target).map(|name| match type_path(target) {
+                    Some(ty) => {
+                        links.push(Piece::Type {
+                            ty,
+                            name: name.to_string(),
+                        });
+                        LINK_MARKER.to_string()
+                    }
+                    None => name.to_string(),
+                });
+                match code {
+                    Some(_) => (name.map(|name| format!("`{}`", name)), end + 1),
+                    None => (name, end + 1),
+                }
+            }
+        };
+
+        match replacement {
+            Some(replacement) => output.push_str(&replacement),
+            None => output.push_str(&rest[..consumed]),
+        }
+        rest = &rest[consumed..];
+    }
+
+    output.push_str(rest);
+    output
+}
@@ -2,7 +2,10 @@ use proc_macro::TokenStream;
 use quote::{ToTokens, quote, quote_spanned};
 use syn::{Field, spanned::Spanned};
 
-use crate::parsers::{attributes::fields, types::is_option_type};
+use crate::parsers::{
+    attributes::fields,
+    types::{is_double_option_type, is_option_type, option_inner},
+};
 
 use super::{FieldParser, ParsedField};
 
@@ -26,10 +29,9 @@ impl FieldParser for BaseParser {
     /// * `Err(TokenStream)` - If there is an error during parsing, such as
     ///   invalid attributes or type mismatches.
     fn parse(field: &Field) -> Result<ParsedField<'_>, TokenStream> {
-        let ty = &field.ty;
-
-        // Generate the exportable type representation.
-        let export = quote!(<#ty as ronky::Exportable>::export());
+        // An optional field is an optional property rather than a nullable one, so the type
+        // inside the `Option` is exported.
+        let ty = option_inner(&field.ty).unwrap_or(&field.ty);
 
         // Check if the field type is an `Option`.
         let is_optional = is_option_type(&field.ty);
@@ -37,9 +39,24 @@ impl FieldParser for BaseParser {
         // Extract field attributes (rename + nullable).
         let field_attrs = fields::extract(&field.attrs)?;
 
+        // Generate the exportable type representation, with the shape of the `Result`s in it.
+        let export = match field_attrs
+            .iter()
+            .rev()
+            .find_map(|attr| attr.result.as_ref())
+        {
+            Some(shape) => quote! {
+                ronky::with_result_shape(#shape, <#ty as ronky::Exportable>::export)
+            },
+            None => quote!(<#ty as ronky::Exportable>::export()),
+        };
+
         // Process nullable from field attributes.
         let nullable_code = {
-            let mut actual_nullable: Option<bool> = None;
+            // A nested option distinguishes an absent value from an explicit null, so it is
+            // nullable unless stated otherwise.
+            let mut actual_nullable: Option<bool> =
+                is_double_option_type(&field.ty).then_some(true);
             for attr in &field_attrs {
                 if let Some(is_nullable) = attr.is_nullable {
                     // Ensure only optional types can be nullable.
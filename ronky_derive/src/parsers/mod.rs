@@ -1,7 +1,8 @@
 pub(crate) mod attributes;
+pub(crate) mod docs;
 
 mod fields;
-mod types;
+pub(crate) mod types;
 use fields::{BaseParser, FieldParser};
 use proc_macro::TokenStream;
 use syn::Field;
@@ -1,4 +1,64 @@
-use syn::Type;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Counts how many `Option`s are nested in the given type.
+///
+/// # Arguments
+///
+/// * `ty` - A reference to a `Type` object to check.
+///
+/// # Returns
+///
+/// Returns `0` for a non-optional type, `1` for `Option<T>`, `2` for `Option<Option<T>>`, etc.
+pub(crate) fn option_depth(ty: &Type) -> usize {
+    let Type::Path(type_path) = ty else {
+        return 0;
+    };
+
+    match type_path.path.segments.last() {
+        Some(segment) if segment.ident == "Option" => {
+            let inner_depth = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .find_map(|arg| match arg {
+                        GenericArgument::Type(inner) => Some(option_depth(inner)),
+                        _ => None,
+                    })
+                    .unwrap_or_default(),
+                _ => 0,
+            };
+
+            1 + inner_depth
+        }
+        _ => 0,
+    }
+}
+
+/// Retrieves the type wrapped by an `Option`.
+///
+/// # Arguments
+///
+/// * `ty` - A reference to a `Type` object to check.
+///
+/// # Returns
+///
+/// Returns `T` for `Option<T>`, or `None` if the type isn't an `Option`.
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    match type_path.path.segments.last() {
+        Some(segment) if segment.ident == "Option" => match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
 /// Checks if the given type is an `Option`.
 ///
@@ -10,12 +70,21 @@ use syn::Type;
 ///
 /// Returns `true` if the type is an `Option`, otherwise `false`.
 pub(crate) fn is_option_type(ty: &Type) -> bool {
-    match ty {
-        Type::Path(type_path) => type_path
-            .path
-            .segments
-            .last()
-            .is_some_and(|segment| segment.ident == "Option"),
-        _ => false,
-    }
+    option_depth(ty) > 0
+}
+
+/// Checks if the given type is a nested `Option`, such as `Option<Option<T>>`.
+///
+/// A nested option can distinguish between a value that was not sent (`None`) and a value that
+/// was explicitly sent as `null` (`Some(None)`).
+///
+/// # Arguments
+///
+/// * `ty` - A reference to a `Type` object to check.
+///
+/// # Returns
+///
+/// Returns `true` if the type is an `Option` of an `Option`, otherwise `false`.
+pub(crate) fn is_double_option_type(ty: &Type) -> bool {
+    option_depth(ty) > 1
 }
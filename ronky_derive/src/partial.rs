@@ -0,0 +1,258 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Field, punctuated::Punctuated, token::Comma};
+
+use crate::parsers::attributes::properties::{CaseTransform, PropertiesArguments};
+
+/// The serde attributes of a field which describe the original struct rather than its wire
+/// format, as the patch generates its own.
+#[cfg(feature = "serialization")]
+const GENERATED_FIELD_ATTRS: &[&str] = &[
+    "default",
+    "skip_serializing_if",
+    "with",
+    "serialize_with",
+    "deserialize_with",
+];
+
+/// The serde attributes of a struct which only apply to the original struct.
+#[cfg(feature = "serialization")]
+const ORIGINAL_STRUCT_ATTRS: &[&str] = &[
+    "crate",
+    "default",
+    "from",
+    "try_from",
+    "into",
+    "remote",
+    "transparent",
+];
+
+/// Copies the `#[serde(...)]` attributes of the original struct or field, without `skipped`.
+///
+/// Returns the attributes, and whether one of them sets `key`. Attributes serde can't parse are
+/// left out, as serde reports them on the original struct.
+#[cfg(feature = "serialization")]
+fn copy_serde_attrs(attrs: &[syn::Attribute], skipped: &[&str], key: &str) -> (TokenStream, bool) {
+    use syn::{Meta, punctuated::Punctuated};
+
+    let mut has_key = false;
+    let copied = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .ok()
+        })
+        .map(|metas| {
+            let metas = metas
+                .into_iter()
+                .filter(|meta| !skipped.iter().any(|skipped| meta.path().is_ident(skipped)))
+                .inspect(|meta| has_key |= meta.path().is_ident(key))
+                .collect::<Vec<_>>();
+            quote!(#[serde(#(#metas),*)])
+        })
+        .collect();
+
+    (copied, has_key)
+}
+
+/// Generates the serde attributes for a field of the partial struct.
+///
+/// The serde attributes of the field are kept, so the patch has the wire format of the struct.
+/// Without a serde rename, the wire name follows the exported schema. Absent fields are
+/// skipped, and optional fields use `ronky::double_option` so an explicit `null` clears the
+/// value, as the nullable patch property they are exported as allows.
+#[cfg(feature = "serialization")]
+fn serde_field_attrs(
+    field: &Field,
+    rename_all: &Option<CaseTransform>,
+    serde_rename_all: bool,
+) -> TokenStream {
+    use crate::{
+        named_struct::field_name,
+        parsers::{attributes::fields, types::is_option_type},
+    };
+
+    let (copied, serde_rename) = copy_serde_attrs(&field.attrs, GENERATED_FIELD_ATTRS, "rename");
+    let args = fields::extract(&field.attrs).unwrap_or_default();
+
+    let naming = (!serde_rename && !serde_rename_all).then(|| {
+        let name = field_name(field, &args, rename_all);
+        let aliases = args.iter().flat_map(|a| a.aliases.clone());
+        quote!(rename = #name, #(alias = #aliases,)*)
+    });
+
+    // The patch field of an optional field is a nested option, which is exported as nullable
+    let is_nullable = is_option_type(&field.ty)
+        && args
+            .iter()
+            .rev()
+            .find_map(|a| a.is_nullable)
+            .unwrap_or(true);
+    let with = is_nullable.then(|| quote!(, with = "ronky::double_option"));
+
+    quote! {
+        #copied
+        #[serde(
+            #naming
+            default,
+            skip_serializing_if = "Option::is_none"
+            #with
+        )]
+    }
+}
+
+/// Generates the serde derives for the partial struct, with the serde attributes of the struct.
+#[cfg(feature = "serialization")]
+fn serde_struct_attrs(input: &DeriveInput) -> (TokenStream, bool) {
+    let (copied, serde_rename_all) =
+        copy_serde_attrs(&input.attrs, ORIGINAL_STRUCT_ATTRS, "rename_all");
+    let attrs = quote! {
+        #[derive(ronky::__private::serde::Serialize, ronky::__private::serde::Deserialize)]
+        #[serde(crate = "ronky::__private::serde")]
+        #copied
+    };
+    (attrs, serde_rename_all)
+}
+
+#[cfg(not(feature = "serialization"))]
+fn serde_field_attrs(
+    _field: &Field,
+    _rename_all: &Option<CaseTransform>,
+    _serde_rename_all: bool,
+) -> TokenStream {
+    quote! {}
+}
+
+#[cfg(not(feature = "serialization"))]
+fn serde_struct_attrs(_input: &DeriveInput) -> (TokenStream, bool) {
+    let error = quote! {
+        compile_error!("`#[arri(partial(serde))]` requires the `serialization` feature of ronky.");
+    };
+    (error, false)
+}
+
+/// Generates the partial (patch) companion of a named struct annotated with `#[arri(partial)]`.
+///
+/// The companion is named `{Struct}Patch` (or the name given with `#[arri(partial = "...")]`),
+/// wraps every field in an `Option` and exports all properties as optional properties. Fields
+/// that are already optional become `Option<Option<T>>`. With `#[arri(partial(serde))]`, the
+/// companion derives serde's traits, and optional fields can be cleared with `null`.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the struct.
+/// * `fields` - A reference to a `Punctuated` collection of `Field` objects representing the struct's fields.
+/// * `attrs` - The parsed `#[arri(...)]` arguments of the struct.
+///
+/// # Returns
+///
+/// Returns a `TokenStream` containing the partial struct and its `apply` method, or an empty
+/// stream if the struct is not annotated with `#[arri(partial)]`.
+pub fn export_partial(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+    attrs: &[PropertiesArguments],
+) -> TokenStream {
+    let Some(options) = attrs.iter().find_map(|a| a.partial.clone()) else {
+        return quote! {};
+    };
+
+    let struct_name = &input.ident;
+    let patch_name = options
+        .name
+        .unwrap_or_else(|| format_ident!("{}Patch", struct_name));
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let rename_all = attrs.iter().find_map(|a| a.rename_all.clone());
+    let rename_all_attr = rename_all.as_ref().map(|transform| {
+        let transform = transform.as_str();
+        quote!(#[arri(rename_all = #transform)])
+    });
+    let strict_attr = attrs
+        .iter()
+        .find_map(|a| a.strict)
+        .map(|strict| quote!(#[arri(strict = #strict)]));
+    let bound_attr = crate::bounds::bound_attr(input);
+
+    // Bare links are resolved to the schema id, which needs the generic arguments of the type
+    let link = match input.generics.params.is_empty() {
+        true => format!("[`{}`]", struct_name),
+        false => format!("[`{0}`](struct@{0})", struct_name),
+    };
+    let doc = format!(
+        "A partial update of {}, in which every property is optional.",
+        link
+    );
+
+    let (serde_attrs, serde_rename_all) = match options.serde {
+        true => serde_struct_attrs(input),
+        false => (quote! {}, false),
+    };
+
+    let mut patch_fields = Vec::new();
+    let mut apply_fields = Vec::new();
+
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let field_vis = &field.vis;
+
+        // Keep the docs, deprecations and arri attributes (renames, aliases, ...) of the field.
+        let kept_attrs = field.attrs.iter().filter(|attr| {
+            attr.path().is_ident("doc")
+                || attr.path().is_ident("deprecated")
+                || attr.path().is_ident("arri")
+        });
+
+        let serde_attrs = match options.serde {
+            true => serde_field_attrs(field, &rename_all, serde_rename_all),
+            false => quote! {},
+        };
+
+        patch_fields.push(quote! {
+            #(#kept_attrs)*
+            #serde_attrs
+            #field_vis #ident: Option<#ty>,
+        });
+
+        apply_fields.push(quote! {
+            if let Some(value) = self.#ident {
+                target.#ident = value;
+            }
+        });
+    }
+
+    let field_idents = fields.iter().map(|field| field.ident.as_ref().unwrap());
+
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        #[derive(ronky::Exported)]
+        #serde_attrs
+        #rename_all_attr
+        #strict_attr
+        #bound_attr
+        #vis struct #patch_name #generics #where_clause {
+            #(#patch_fields)*
+        }
+
+        impl #impl_generics #patch_name #ty_generics #where_clause {
+            /// Applies every property that is set in this patch to `target`.
+            #[allow(deprecated)]
+            #vis fn apply(self, target: &mut #struct_name #ty_generics) {
+                #(#apply_fields)*
+            }
+        }
+
+        impl #impl_generics Default for #patch_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#field_idents: None,)*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, ItemTrait, LitStr, meta::ParseNestedMeta, spanned::Spanned};
+
+use crate::parsers::attributes::fields::validate_name;
+
+/// Parses a `key = "value"` argument of an attribute macro, which may only be given once.
+fn parse_once(meta: &ParseNestedMeta, slot: &mut Option<LitStr>, key: &str) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!("`{}` can only be given once", key)));
+    }
+    let value: LitStr = meta.value()?.parse()?;
+    validate_name(&value, "A tag or discriminator")?;
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Exports `Box<dyn Trait>` as a tagged union over the registered implementors of a trait.
+///
+/// The trait gets `Any` as a supertrait, so a trait object can be matched with its
+/// implementor. With the `serialization` feature, `dyn Trait` implements `Serialize` and
+/// `Box<dyn Trait>` implements `Deserialize`, dispatching to the implementor by its tag.
+///
+/// # Arguments
+///
+/// * `args` - The arguments of the attribute, an optional `discriminator = "..."`.
+/// * `item` - The trait.
+///
+/// # Returns
+///
+/// Returns the trait with the implementations, or a compile error.
+pub fn export_polymorphic(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let mut discriminator = None;
+    let parser = syn::meta::parser(|meta| match meta.path.get_ident() {
+        Some(key) if key == "discriminator" => {
+            parse_once(&meta, &mut discriminator, "discriminator")
+        }
+        _ => Err(meta.error("Unknown argument, expected `discriminator = \"...\"`")),
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    let mut item: ItemTrait = syn::parse2(item)?;
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "Polymorphic traits can't have generic parameters, as they are exported as a single tagged union.",
+        ));
+    }
+    item.supertraits.push(syn::parse_quote!(::core::any::Any));
+
+    let ident = &item.ident;
+    let discriminator = match discriminator {
+        Some(discriminator) => quote!(#discriminator),
+        None => quote!(ronky::DEFAULT_DISCRIMINATOR),
+    };
+
+    let serialization = if cfg!(feature = "serialization") {
+        quote! {
+            impl ronky::__private::serde::Serialize for dyn #ident {
+                fn serialize<S: ronky::__private::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let value: &dyn ::core::any::Any = self;
+                    ronky::trait_object::serialize::<dyn #ident, S>(value, #discriminator, serializer)
+                }
+            }
+
+            impl<'de> ronky::__private::serde::Deserialize<'de> for Box<dyn #ident> {
+                fn deserialize<D: ronky::__private::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    ronky::trait_object::deserialize::<dyn #ident, D>(#discriminator, deserializer)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #item
+
+        impl ronky::Exportable for Box<dyn #ident> {
+            fn get_type_name() -> String {
+                ronky::type_utils::get_type_name::<dyn #ident>()
+            }
+
+            fn export_internal() -> impl ronky::Serializable {
+                ronky::export_implementors::<dyn #ident>(#discriminator)
+            }
+
+            fn is_directional() -> bool {
+                ronky::is_directional_with::<Self>(|| {
+                    ronky::implementors_are_directional::<dyn #ident>()
+                })
+            }
+        }
+
+        #serialization
+    })
+}
+
+/// Registers the type of an `impl Trait for Type` block as an implementor of `dyn Trait`.
+///
+/// Implementors are submitted through `inventory`, and registered when the implementors of a
+/// trait object are first looked up.
+///
+/// # Arguments
+///
+/// * `args` - The arguments of the attribute, a required `tag = "..."`.
+/// * `item` - The implementation of the trait.
+///
+/// # Returns
+///
+/// Returns the implementation with the registration, or a compile error.
+pub fn export_implementor(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let span = args.span();
+    let mut tag = None;
+    let parser = syn::meta::parser(|meta| match meta.path.get_ident() {
+        Some(key) if key == "tag" => parse_once(&meta, &mut tag, "tag"),
+        _ => Err(meta.error("Unknown argument, expected `tag = \"...\"`")),
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    let item: ItemImpl = syn::parse2(item)?;
+    let Some(tag) = tag else {
+        return Err(syn::Error::new(
+            span,
+            "An implementor needs a tag, such as `#[ronky::implementor(tag = \"email\")]`.",
+        ));
+    };
+    let Some((_, trait_path, _)) = &item.trait_ else {
+        return Err(syn::Error::new(
+            item.self_ty.span(),
+            "Only implementations of a trait can be registered, such as `impl Action for SendEmail`.",
+        ));
+    };
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "Generic implementations can't be registered, as every type needs its own tag.",
+        ));
+    }
+
+    let ty = &item.self_ty;
+    let serialization = if cfg!(feature = "serialization") {
+        quote!(ronky::trait_object::register::<dyn #trait_path, #ty>();)
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #item
+
+        impl ronky::Implementor<dyn #trait_path> for #ty {
+            const TAG: &'static str = #tag;
+
+            fn into_object(self: Box<Self>) -> Box<dyn #trait_path> {
+                self
+            }
+        }
+
+        ronky::inventory::submit! {
+            ronky::SubmittedImplementor({
+                fn register() {
+                    ronky::register_implementor::<dyn #trait_path, #ty>();
+                    #serialization
+                }
+                register
+            })
+        }
+    })
+}
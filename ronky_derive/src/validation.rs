@@ -0,0 +1,226 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Fields, Index, punctuated::Punctuated, token::Comma};
+
+use crate::{
+    bounds,
+    named_struct::field_name,
+    parsers::attributes::{
+        enum_variants,
+        fields::{self, FieldArguments},
+        properties::{self, CaseTransform},
+    },
+};
+
+/// Generates the checks for the constraints of a single field.
+///
+/// # Arguments
+///
+/// * `value` - An expression referencing the value of the field.
+/// * `path` - An expression with the path of the field.
+/// * `args` - The parsed `#[arri(...)]` arguments of the field.
+///
+/// # Returns
+///
+/// Returns `None` if the field has no constraints and isn't validated recursively.
+fn field_checks(
+    value: TokenStream,
+    path: TokenStream,
+    args: &[FieldArguments],
+) -> Option<TokenStream> {
+    // Later attributes override earlier ones, as in the exported constraints, so every
+    // constraint is checked once with its last value.
+    let mut checks = Vec::new();
+
+    if let Some(min) = args.iter().rev().find_map(|arg| arg.min) {
+        checks.push(quote!(ronky::validation::check_min(#value, #min, &path, errors);));
+    }
+    if let Some(max) = args.iter().rev().find_map(|arg| arg.max) {
+        checks.push(quote!(ronky::validation::check_max(#value, #max, &path, errors);));
+    }
+    if let Some(min_length) = args.iter().rev().find_map(|arg| arg.min_length) {
+        let min_length = min_length as usize;
+        checks
+            .push(quote!(ronky::validation::check_min_length(#value, #min_length, &path, errors);));
+    }
+    if let Some(max_length) = args.iter().rev().find_map(|arg| arg.max_length) {
+        let max_length = max_length as usize;
+        checks
+            .push(quote!(ronky::validation::check_max_length(#value, #max_length, &path, errors);));
+    }
+    if let Some(pattern) = args.iter().rev().find_map(|arg| arg.pattern.as_ref()) {
+        checks.push(quote! {
+            static PATTERN: ronky::validation::Pattern = ronky::validation::Pattern::new(#pattern);
+            ronky::validation::check_pattern(#value, &PATTERN, &path, errors);
+        });
+    }
+    if let Some(format) = args.iter().rev().find_map(|arg| arg.format.as_ref()) {
+        checks.push(quote!(ronky::validation::check_format(#value, #format, &path, errors);));
+    }
+
+    if args.iter().any(|arg| arg.validate == Some(true)) {
+        checks.push(quote!(ronky::validation::Validate::validate_at(#value, &path, errors);));
+    }
+
+    (!checks.is_empty()).then(|| {
+        quote! {
+            {
+                let path = #path;
+                #(#checks)*
+            }
+        }
+    })
+}
+
+/// Generates the checks for the named fields of a struct or tagged union variant.
+///
+/// The fields are referenced through the bindings returned alongside the checks, and the paths
+/// use the property names of the exported schema.
+fn named_fields_checks(
+    named: &Punctuated<Field, Comma>,
+    rename_all: &Option<CaseTransform>,
+) -> (Vec<syn::Ident>, Vec<TokenStream>) {
+    let mut bindings = Vec::new();
+    let mut checks = Vec::new();
+
+    for field in named.iter() {
+        let args = fields::extract(&field.attrs).unwrap_or_default();
+        let ident = field.ident.clone().unwrap();
+        let name = field_name(field, &args, rename_all);
+
+        if let Some(check) = field_checks(
+            quote!(#ident),
+            quote!(ronky::validation::join_path(path, #name)),
+            &args,
+        ) {
+            bindings.push(ident);
+            checks.push(check);
+        }
+    }
+
+    (bindings, checks)
+}
+
+/// Generates the implementation of `ronky::validation::Validate` for a struct or enum.
+///
+/// The implementation enforces the `min`, `max`, `min_length`, `max_length`, `pattern` and
+/// `format` constraints of every field, and recurses into fields marked with
+/// `#[arri(validate)]`, which requires the type parameters they use to be `Validate`.
+///
+/// # Arguments
+///
+/// * `input` - A reference to the `DeriveInput` representing the struct or enum.
+///
+/// # Returns
+///
+/// Returns a `TokenStream` containing the implementation, or an empty stream for unsupported
+/// inputs (which already report an error while exporting).
+pub fn generate_validation(input: &DeriveInput) -> TokenStream {
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => {
+                let rename_all = properties::extract(&input.attrs)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find_map(|a| a.rename_all);
+                let (bindings, checks) = named_fields_checks(&named.named, &rename_all);
+
+                quote! {
+                    let Self { #(#bindings,)* .. } = self;
+                    #(#checks)*
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                // Tuple structs are transparent, so the inner value shares the path.
+                let checks = unnamed
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, field)| {
+                        let idx = Index::from(idx);
+                        let args = fields::extract(&field.attrs).unwrap_or_default();
+                        field_checks(quote!(&self.#idx), quote!(path.to_string()), &args)
+                    });
+
+                quote!(#(#checks)*)
+            }
+            Fields::Unit => return quote! {},
+        },
+        Data::Enum(data) => {
+            let untagged = enum_variants::extract(&input.attrs)
+                .unwrap_or_default()
+                .iter()
+                .any(|attr| attr.untagged == Some(true));
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Named(named) => {
+                        let (bindings, checks) = named_fields_checks(&named.named, &None);
+                        quote! {
+                            Self::#ident { #(#bindings,)* .. } => {
+                                #(#checks)*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(unnamed) => {
+                        // Unnamed variants are exported with their value under "value", unless
+                        // the enum is untagged and the value is the variant itself.
+                        let value = format_ident!("value");
+                        let value_path = match untagged {
+                            true => quote!(path.to_string()),
+                            false => quote!(ronky::validation::join_path(path, "value")),
+                        };
+                        let check = unnamed.unnamed.first().and_then(|field| {
+                            let args = fields::extract(&field.attrs).unwrap_or_default();
+                            field_checks(quote!(#value), value_path.clone(), &args)
+                        });
+
+                        match check {
+                            Some(check) => quote!(Self::#ident(#value, ..) => #check),
+                            None => quote!(Self::#ident(..) => {}),
+                        }
+                    }
+                    Fields::Unit => quote!(Self::#ident => {}),
+                }
+            });
+
+            if data.variants.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => return quote! {},
+    };
+
+    let validated = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect::<Vec<_>>(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    let validated = validated.into_iter().filter(|field| {
+        fields::extract(&field.attrs)
+            .is_ok_and(|args| args.iter().any(|arg| arg.validate == Some(true)))
+    });
+    let where_clause = bounds::where_clause(input, &bounds::validate_bounds(input, validated));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ronky::validation::Validate for #name #ty_generics #where_clause {
+            #[allow(deprecated, unused_variables)]
+            fn validate_at(&self, path: &str, errors: &mut Vec<ronky::validation::ValidationError>) {
+                #body
+            }
+        }
+    }
+}
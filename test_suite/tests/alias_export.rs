@@ -0,0 +1,108 @@
+use ronky::{
+    Exportable, Exported, ExportedDeserialize, MetadataSchema, PropertiesSchema, Serializable,
+    TaggedUnionSchema, TypeSchema, Types,
+};
+use serde::Deserialize;
+
+#[test]
+fn test_field_alias_export() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(rename_all = "camelCase")]
+    struct User {
+        #[arri(alias = "name", alias = "fullName")]
+        display_name: String,
+        age: Option<u8>,
+    }
+
+    let export = User::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("User").to_owned());
+    expected.set_property(
+        "displayName",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .add_alias("name")
+                    .add_alias("fullName")
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_optional_property("age", Box::new(TypeSchema::new(Types::Uint8)));
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_field_alias_keeps_docs() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct User {
+        /// The name shown in the UI
+        #[arri(rename = "displayName", alias = "name")]
+        name: String,
+    }
+
+    let export = User::export();
+    let serialized: serde_json::Value = serde_json::from_str(&export.serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["properties"]["displayName"],
+        serde_json::json!({
+            "type": "string",
+            "metadata": {
+                "description": "The name shown in the UI",
+                "aliases": ["name"]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_variant_alias_export() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(transform = "uppercase")]
+    enum Shape {
+        #[arri(alias = "round")]
+        Circle {
+            radius: f64,
+        },
+        Square {
+            side: f64,
+        },
+    }
+
+    let export = Shape::export();
+    assert!(export.is::<TaggedUnionSchema>());
+    let export = export.downcast_ref::<TaggedUnionSchema>().unwrap();
+
+    let serialized: serde_json::Value = serde_json::from_str(&export.serialize().unwrap()).unwrap();
+    assert_eq!(
+        serialized["mapping"]["CIRCLE"]["metadata"],
+        serde_json::json!({ "aliases": ["round"] })
+    );
+    assert!(serialized["mapping"]["SQUARE"].get("metadata").is_none());
+}
+
+#[test]
+fn test_alias_decoding() {
+    #[derive(Exported, Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[arri(rename_all = "camelCase")]
+    struct User {
+        #[serde(alias = "name")]
+        #[arri(alias = "name")]
+        display_name: String,
+    }
+
+    let canonical = User::from_json(r#"{"displayName":"Ronky"}"#).unwrap();
+    let aliased = User::from_json(r#"{"name":"Ronky"}"#).unwrap();
+
+    assert_eq!(canonical, aliased);
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ronky::{
+    ExportContext, ExportDirection, Exportable, Exported, PropertiesSchema, Serializable,
+    TypeSchema, Types,
+};
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct User {
+    #[arri(read_only)]
+    id: u64,
+    name: String,
+}
+
+static EXPORTS: AtomicUsize = AtomicUsize::new(0);
+
+// Implemented by hand, to count how often the type is exported.
+struct Counted;
+
+impl Exportable for Counted {
+    fn export_internal() -> impl Serializable {
+        EXPORTS.fetch_add(1, Ordering::SeqCst);
+        let mut schema = PropertiesSchema::new();
+        schema.set_property("value", Box::new(TypeSchema::new(Types::Uint8)));
+        schema
+    }
+}
+
+#[test]
+fn test_cached_exports_are_shared() {
+    let context = ExportContext::new().set_cached(true);
+    let first = context.export_shared::<User>();
+    let second = context.clone().export_shared::<User>();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.serialize(), User::export().serialize());
+    assert!(first.is::<PropertiesSchema>());
+
+    // Another thread exports its own schema, which is shared by its exports
+    let threaded = std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let schema = context.export_shared::<User>();
+                assert!(Arc::ptr_eq(&schema, &context.export_shared::<User>()));
+                schema.serialize()
+            })
+            .join()
+            .unwrap()
+    });
+    assert_eq!(threaded, first.serialize());
+
+    // Without a cache every export is new
+    let uncached = ExportContext::new();
+    assert!(!Arc::ptr_eq(
+        &uncached.export_shared::<User>(),
+        &uncached.export_shared::<User>()
+    ));
+}
+
+#[test]
+fn test_cached_serialization() {
+    let context = ExportContext::new().set_cached(true);
+    let before = EXPORTS.load(Ordering::SeqCst);
+
+    let serialized = context.serialize::<Counted>().unwrap();
+    let threaded = std::thread::scope(|scope| {
+        scope
+            .spawn(|| context.serialize::<Counted>().unwrap())
+            .join()
+            .unwrap()
+    });
+    context.export_shared::<Counted>();
+
+    assert!(Arc::ptr_eq(&serialized, &threaded));
+    assert_eq!(Some(serialized.to_string()), Counted::export().serialize());
+    // Once for the cache, and once for the uncached comparison
+    assert_eq!(EXPORTS.load(Ordering::SeqCst) - before, 2);
+}
+
+#[test]
+fn test_cache_is_keyed_by_configuration() {
+    let output = ExportContext::new().set_cached(true);
+    let input = output.clone().set_direction(ExportDirection::Input);
+
+    let output_schema = output.serialize::<User>().unwrap();
+    let input_schema = input.serialize::<User>().unwrap();
+
+    assert_ne!(output_schema, input_schema);
+    assert!(input_schema.contains("UserInput"));
+    // The contexts share the cache, which holds an entry per direction
+    assert_eq!(output.cache().unwrap().len(), 2);
+
+    output.cache().unwrap().clear();
+    assert!(input.cache().unwrap().is_empty());
+    assert!(output.clone().set_cached(false).cache().is_none());
+}
@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(alias = "other")]
+    example: u8,
+    other: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(alias = "old")]
+    example: u8,
+    #[arri(alias = "old")]
+    other: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+enum Test {
+    #[arri(alias = "old")]
+    Example,
+    Other,
+}
+
+fn main() {}
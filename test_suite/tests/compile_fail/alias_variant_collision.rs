@@ -0,0 +1,11 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(transform = "uppercase")]
+enum Test {
+    #[arri(alias = "OTHER")]
+    Example { value: u8 },
+    Other { value: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(pattern = "[a-z")]
+    example: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(min_length = 5)]
+    #[arri(max_length = 2)]
+    example: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(min = 10, max = 1)]
+    example: u8,
+}
+
+fn main() {}
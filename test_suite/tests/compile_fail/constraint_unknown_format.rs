@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(format = "phone")]
+    example: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct User {
+    #[arri(deprecated(reason = "Use display_name instead"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(description = "A user", description_file = "docs/user.md")]
+struct User {
+    name: String,
+}
+
+fn main() {}
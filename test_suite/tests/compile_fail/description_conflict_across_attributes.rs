@@ -0,0 +1,12 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(description = "A user")]
+#[arri(description_file = "docs/user.md")]
+struct User {
+    #[arri(description_file = "docs/name.md")]
+    #[arri(description = "The name")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+enum Test {
+    #[arri(description = "An example")]
+    Example,
+    Other,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(docs(compact))]
+struct User {
+    name: String,
+}
+
+fn main() {}
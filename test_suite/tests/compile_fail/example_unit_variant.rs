@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+enum Test {
+    #[arri(example = "Example")]
+    Example,
+    Other,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(bound = "T Exportable")]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(id = "Account")]
+#[arri(id = "Customer")]
+struct User {
+    name: String,
+}
+
+#[derive(Exported)]
+#[arri(bound = "T: ronky::Exportable")]
+#[arri(bound = "")]
+enum Change<T> {
+    Set { value: T },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(id = "User Account")]
+struct User {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use ronky::Exported;
+
+#[ronky::polymorphic]
+trait Action {}
+
+#[derive(Exported)]
+struct SendEmail {
+    to: String,
+}
+
+#[ronky::implementor]
+impl Action for SendEmail {}
+
+fn main() {}
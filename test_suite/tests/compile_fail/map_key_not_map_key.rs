@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Coordinates {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Exported)]
+struct Grid {
+    cells: HashMap<Coordinates, bool>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(meta(owner = "billing", owner = "payments"))]
+    example: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(meta(owner = billing))]
+struct Test {
+    example: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+enum Test {
+    #[arri(meta(owner = "billing"))]
+    Example,
+    Other,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+struct Test {
+    #[arri(read_only, write_only)]
+    example: u8,
+}
+
+fn main() {}
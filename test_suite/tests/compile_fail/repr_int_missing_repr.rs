@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(repr = "int")]
+enum Priority {
+    Low = 1,
+    High = 5,
+}
+
+fn main() {}
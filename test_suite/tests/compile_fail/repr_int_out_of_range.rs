@@ -0,0 +1,11 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(repr = "int")]
+#[repr(u8)]
+enum Priority {
+    Low = 1,
+    High = 300,
+}
+
+fn main() {}
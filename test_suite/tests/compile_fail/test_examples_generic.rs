@@ -0,0 +1,11 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(test_examples)]
+struct Page<T> {
+    #[arri(example = 1)]
+    page: u32,
+    items: Vec<T>,
+}
+
+fn main() {}
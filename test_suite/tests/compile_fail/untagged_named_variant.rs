@@ -0,0 +1,10 @@
+use ronky::Exported;
+
+#[derive(Exported)]
+#[arri(untagged)]
+enum Either {
+    Text(String),
+    Pair { left: u8, right: u8 },
+}
+
+fn main() {}
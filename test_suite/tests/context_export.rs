@@ -0,0 +1,159 @@
+use ronky::{
+    EnumTransformation, ExportContext, ExportDirection, Exportable, Exported, GenericIds,
+    MetadataSchema, Naming, PropertiesSchema, RefSchema, Serializable, TypeSchema, Types,
+};
+
+mod outer {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    pub struct Node {
+        pub label: String,
+        pub inner: super::inner::Node,
+    }
+}
+
+mod inner {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "InnerNode")]
+    pub struct Node {
+        pub value: u8,
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Account {
+    display_name: String,
+    #[arri(rename = "e_mail")]
+    email_address: String,
+    #[arri(write_only)]
+    secret_key: String,
+    parent: Option<Box<Self>>,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+#[arri(rename_all = "PascalCase")]
+struct Renamed {
+    first_name: String,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Page<T: Exportable + 'static> {
+    items: Vec<T>,
+}
+
+// Implemented by hand, to panic halfway through an export.
+struct Faulty;
+
+impl Exportable for Faulty {
+    fn export_internal() -> impl Serializable {
+        let mut schema = PropertiesSchema::new();
+        schema.set_property("account", Account::export());
+        if !schema.properties.is_empty() {
+            panic!("export failed");
+        }
+        schema
+    }
+}
+
+fn properties(export: &dyn Serializable) -> &PropertiesSchema {
+    export.downcast_ref::<PropertiesSchema>().unwrap()
+}
+
+#[test]
+fn test_same_named_types_are_not_recursive() {
+    let export = outer::Node::export();
+
+    let mut inner = PropertiesSchema::new();
+    inner.set_metadata(MetadataSchema::new().set_id("InnerNode").to_owned());
+    inner.set_property("value", Box::new(TypeSchema::new(Types::Uint8)));
+
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("Node").to_owned());
+    expected.set_property("label", Box::new(TypeSchema::new(Types::String)));
+    expected.set_property("inner", Box::new(inner));
+
+    assert_eq!(*properties(export.as_ref()), expected);
+}
+
+#[test]
+fn test_context_configures_export() {
+    let context = ExportContext::new()
+        .set_direction(ExportDirection::Input)
+        .set_casing(EnumTransformation::Camelcase);
+    let export = context.export::<Account>();
+    let export = properties(export.as_ref());
+
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("AccountInput").to_owned());
+    expected.set_property("displayName", Box::new(TypeSchema::new(Types::String)));
+    // Explicit names are kept as-is
+    expected.set_property("e_mail", Box::new(TypeSchema::new(Types::String)));
+    expected.set_property("secretKey", Box::new(TypeSchema::new(Types::String)));
+    expected.set_optional_property("parent", Box::new(RefSchema::new("AccountInput")));
+
+    assert_eq!(*export, expected);
+
+    // A rename_all takes precedence over the casing of the context
+    let export = context.export::<Renamed>();
+    assert!(
+        properties(export.as_ref())
+            .properties
+            .contains_key("FirstName")
+    );
+}
+
+#[test]
+fn test_context_export_is_reproducible() {
+    let context = ExportContext::new().set_naming(Naming::new().set_generics(GenericIds::Of));
+
+    let first = context.export::<Page<Account>>().serialize();
+    // Neither an export in progress nor another thread changes the result
+    let nested = ExportContext::new()
+        .set_direction(ExportDirection::Input)
+        .export::<Account>();
+    let threaded = std::thread::scope(|scope| {
+        scope
+            .spawn(|| context.export::<Page<Account>>().serialize())
+            .join()
+            .unwrap()
+    });
+
+    assert!(nested.serialize().unwrap().contains("AccountInput"));
+    assert_eq!(first, context.export::<Page<Account>>().serialize());
+    assert_eq!(first, threaded);
+    assert!(first.unwrap().contains("PageOfAccount"));
+
+    // The default context is unaffected
+    assert_eq!(Page::<Account>::get_schema_id(), "PageAccount");
+    assert_eq!(
+        ExportContext::current().direction(),
+        ExportDirection::Output
+    );
+}
+
+#[test]
+fn test_context_is_restored_after_panic() {
+    let context = ExportContext::new().set_direction(ExportDirection::Input);
+    let result = std::panic::catch_unwind(|| context.export::<Faulty>());
+    assert!(result.is_err());
+
+    assert_eq!(
+        ExportContext::current().direction(),
+        ExportDirection::Output
+    );
+    let export = Account::export();
+    let export = properties(export.as_ref());
+    assert_eq!(
+        export.metadata.as_ref().and_then(|m| m.id.as_deref()),
+        Some("Account")
+    );
+    assert!(!export.properties.contains_key("secret_key"));
+}
@@ -0,0 +1,186 @@
+use ronky::{
+    Exportable, Exported, MetadataSchema, PropertiesSchema, Serializable, TaggedUnionSchema,
+    TypeSchema, Types,
+};
+
+#[test]
+fn test_description_overrides_docs() {
+    /// Rust-level documentation
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(description = "A user of the API")]
+    struct User {
+        /// The internal identifier, which is a UUID
+        #[arri(description = "The identifier of the user")]
+        id: String,
+        /// The name of the user
+        name: String,
+    }
+
+    let export = User::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(
+        MetadataSchema::new()
+            .set_description("A user of the API")
+            .set_id("User")
+            .to_owned(),
+    );
+    expected.set_property(
+        "id",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_description("The identifier of the user")
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_property(
+        "name",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_description("The name of the user")
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_description_file() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(description_file = "tests/docs/invoice.md")]
+    struct Invoice {
+        total: u32,
+    }
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&Invoice::export().serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["metadata"]["description"],
+        "# Invoice\n\nAn invoice sent to a customer."
+    );
+}
+
+#[test]
+fn test_deprecated_without_rust_deprecation() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(deprecated(since = "2.0.0", note = "Use Account instead"))]
+    struct User {
+        #[arri(deprecated(note = "Use display_name instead"))]
+        name: String,
+        #[arri(deprecated)]
+        nickname: String,
+    }
+
+    // Reading the fields doesn't emit deprecation warnings, as they aren't deprecated in Rust
+    let user = User {
+        name: "Ronky".to_string(),
+        nickname: "Ron".to_string(),
+    };
+    assert_eq!(user.name, "Ronky");
+
+    let export = User::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(
+        MetadataSchema::new()
+            .set_deprecated(true)
+            .set_deprecated_since("2.0.0")
+            .set_deprecated_message("Use Account instead")
+            .set_id("User")
+            .to_owned(),
+    );
+    expected.set_property(
+        "name",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_deprecated(true)
+                    .set_deprecated_message("Use display_name instead")
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_property(
+        "nickname",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(MetadataSchema::new().set_deprecated(true).to_owned());
+            ty
+        }),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_overrides_rust_deprecation() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Config {
+        #[deprecated(since = "0.1.0", note = "Rust-level note")]
+        #[arri(deprecated(since = "1.0.0", note = "Wire-level note"))]
+        retries: u8,
+    }
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&Config::export().serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["properties"]["retries"]["metadata"],
+        serde_json::json!({
+            "isDeprecated": true,
+            "deprecatedSince": "1.0.0",
+            "deprecatedNote": "Wire-level note"
+        })
+    );
+}
+
+#[test]
+fn test_tagged_union_variant_description() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    enum Shape {
+        #[arri(description = "A round shape", deprecated)]
+        Circle { radius: f64 },
+    }
+
+    let export = Shape::export();
+    let mut expected = TaggedUnionSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("Shape").to_owned());
+    expected.add_mapping(
+        "Circle",
+        Box::new({
+            let mut export = PropertiesSchema::new();
+            export.set_property("radius", Box::new(TypeSchema::new(Types::Float64)));
+            export.set_metadata(
+                MetadataSchema::new()
+                    .set_deprecated(true)
+                    .set_description("A round shape")
+                    .to_owned(),
+            );
+            export
+        }),
+    );
+
+    assert!(export.is::<TaggedUnionSchema>());
+    let export = export.downcast_ref::<TaggedUnionSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
@@ -0,0 +1,107 @@
+use ronky::{
+    ElementsSchema, Exportable, Exported, MetadataSchema, PropertiesSchema, Registry, Serializable,
+    TypeSchema, Types,
+};
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct User {
+    #[arri(read_only)]
+    id: u64,
+    name: String,
+    #[arri(write_only)]
+    password: String,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Team {
+    name: String,
+    members: Vec<User>,
+}
+
+fn user_schema(id: &str, directional_field: (&str, Types)) -> PropertiesSchema {
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id(id).to_owned());
+    if directional_field.0 == "id" {
+        expected.set_property("id", Box::new(TypeSchema::new(directional_field.1)));
+        expected.set_property("name", Box::new(TypeSchema::new(Types::String)));
+    } else {
+        expected.set_property("name", Box::new(TypeSchema::new(Types::String)));
+        expected.set_property(
+            directional_field.0,
+            Box::new(TypeSchema::new(directional_field.1)),
+        );
+    }
+    expected
+}
+
+#[test]
+fn test_output_export() {
+    let expected = user_schema("User", ("id", Types::Uint64));
+
+    for export in [User::export(), User::export_output()] {
+        assert!(export.is::<PropertiesSchema>());
+        let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+        assert_eq!(*export, expected);
+    }
+}
+
+#[test]
+fn test_input_export() {
+    let expected = user_schema("UserInput", ("password", Types::String));
+
+    let export = User::export_input();
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+
+    // The direction is restored after the export
+    let export = User::export();
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, user_schema("User", ("id", Types::Uint64)));
+}
+
+#[test]
+fn test_nested_input_export() {
+    let export = Team::export_input();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("TeamInput").to_owned());
+    expected.set_property("name", Box::new(TypeSchema::new(Types::String)));
+    expected.set_property(
+        "members",
+        Box::new(ElementsSchema::new(Box::new(user_schema(
+            "UserInput",
+            ("password", Types::String),
+        )))),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_registry_registers_both_directions() {
+    let mut registry = Registry::new();
+    registry.register::<Team>().unwrap();
+
+    assert_eq!(registry.ids().collect::<Vec<_>>(), ["Team", "TeamInput"]);
+    assert_eq!(registry.path_of("User"), registry.path_of("UserInput"));
+    assert!(Team::is_directional());
+    assert!(!String::is_directional());
+}
+
+#[test]
+fn test_input_export_without_directional_fields() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Plain {
+        name: String,
+    }
+
+    assert_eq!(
+        Plain::export_input().serialize(),
+        Plain::export_output().serialize()
+    );
+}
@@ -0,0 +1,214 @@
+use ronky::{Exportable, Exported, Serializable};
+
+fn description(serialized: &serde_json::Value) -> &str {
+    serialized["metadata"]["description"].as_str().unwrap()
+}
+
+fn export<T: Exportable>() -> serde_json::Value {
+    serde_json::from_str(&T::export().serialize().unwrap()).unwrap()
+}
+
+mod models {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "PetOwner")]
+    pub struct Owner {
+        pub name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    pub struct Page<T> {
+        pub items: Vec<T>,
+    }
+}
+
+mod clinic {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Veterinarian")]
+    pub struct Vet {
+        pub name: String,
+    }
+
+    /// Isn't exported, so links to it keep its name.
+    pub struct Clinic;
+}
+
+use clinic::Clinic;
+use models::{Owner, Page};
+
+#[test]
+fn test_docs_keep_structure() {
+    /// A pet of an [`Owner`].
+    ///
+    /// The name is shown as-is:
+    ///
+    /// ```
+    /// # use my_crate::Pet;
+    /// let pet = Pet {
+    ///     name: "Ronky".to_string(),
+    /// };
+    /// ```
+    ///
+    /// - Cats
+    ///   - Ronky
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Pet {
+        name: String,
+    }
+
+    assert_eq!(
+        description(&export::<Pet>()),
+        "A pet of an `PetOwner`.\n\nThe name is shown as-is:\n\n```\nlet pet = Pet {\n    name: \"Ronky\".to_string(),\n};\n```\n\n- Cats\n  - Ronky"
+    );
+}
+
+#[test]
+fn test_docs_resolve_intra_doc_links() {
+    /// Owned by [`crate::models::Owner`], see [Pet] and [the vet](struct@Vet).
+    /// Treated by the [Vet] at the [`Clinic`], listed on a [`Page<Pet>`].
+    /// Also [`Self::name`], [`Status::Active`] and [`register()`].
+    ///
+    /// External links stay: [Arri](https://github.com/modiimedia/arri), as does `[Pet]` and
+    /// values[i].
+    ///
+    /// [Vet]: crate::clinic::Vet
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Adoption {
+        name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Pet {
+        name: String,
+    }
+
+    assert_eq!(
+        description(&export::<Adoption>()),
+        "Owned by `PetOwner`, see Pet and the vet.\nTreated by the Veterinarian at the `Clinic`, listed on a `PagePet`.\nAlso `name`, `Active` and `register`.\n\nExternal links stay: [Arri](https://github.com/modiimedia/arri), as does `[Pet]` and\nvalues[i]."
+    );
+}
+
+#[test]
+fn test_docs_strip_sections() {
+    /// Registers a pet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # This isn't a heading
+    /// register();
+    /// ```
+    ///
+    /// ## Nested
+    ///
+    /// Part of the examples.
+    ///
+    /// # Notes
+    ///
+    /// Names are unique.
+    ///
+    /// # Panics
+    ///
+    /// Never.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(docs(strip))]
+    struct Registration {
+        name: String,
+    }
+
+    /// Registers a pet.
+    ///
+    /// # Notes
+    ///
+    /// Names are unique.
+    ///
+    /// # Panics
+    ///
+    /// Never.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(docs(strip = ["Notes"]))]
+    struct CustomRegistration {
+        name: String,
+    }
+
+    assert_eq!(
+        description(&export::<Registration>()),
+        "Registers a pet.\n\n# Notes\n\nNames are unique."
+    );
+    assert_eq!(
+        description(&export::<CustomRegistration>()),
+        "Registers a pet.\n\n# Panics\n\nNever."
+    );
+}
+
+#[test]
+fn test_docs_summary() {
+    /// A pet, which is
+    /// usually a cat.
+    ///
+    /// More details.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(docs(summary))]
+    struct Pet {
+        /// The name of the pet.
+        ///
+        /// Names don't have to be unique.
+        name: String,
+        /// The age of the pet.
+        ///
+        /// Measured in years.
+        #[arri(docs(summary = false))]
+        age: u8,
+    }
+
+    let serialized = export::<Pet>();
+    assert_eq!(description(&serialized), "A pet, which is usually a cat.");
+    assert_eq!(
+        description(&serialized["properties"]["name"]),
+        "The name of the pet."
+    );
+    assert_eq!(
+        description(&serialized["properties"]["age"]),
+        "The age of the pet.\n\nMeasured in years."
+    );
+}
+
+#[test]
+fn test_docs_options_on_variants() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(docs(summary))]
+    enum Event {
+        /// A pet was adopted.
+        ///
+        /// Includes the new owner.
+        Adopted {
+            /// The new owner.
+            ///
+            /// Always set.
+            owner: String,
+        },
+    }
+
+    let serialized = export::<Event>();
+    assert_eq!(
+        description(&serialized["mapping"]["Adopted"]),
+        "A pet was adopted."
+    );
+    assert_eq!(
+        description(&serialized["mapping"]["Adopted"]["properties"]["owner"]),
+        "The new owner."
+    );
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use ronky::{
+    ExportContext, Exportable, Exported, Modifier, PropertiesSchema, Registry, SchemaError,
+    Serializable, TypeSchema, Types,
+};
+
+/// A custom schema which supports neither metadata nor nullability.
+#[derive(Debug)]
+struct OpaqueSchema;
+
+impl Serializable for OpaqueSchema {
+    fn write_to(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(r#"{"type":"string"}"#)
+    }
+}
+
+struct Money;
+
+impl Exportable for Money {
+    fn export_internal() -> impl Serializable {
+        OpaqueSchema
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Invoice {
+    /// The amount due.
+    amount: Money,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Receipt {
+    total: Money,
+}
+
+// Implemented by hand, to export a failing type in a nested scope.
+struct InvoiceForm;
+
+impl Exportable for InvoiceForm {
+    fn export_internal() -> impl Serializable {
+        let mut schema = PropertiesSchema::new();
+        schema.set_property("invoice", Invoice::export_input());
+        schema
+    }
+}
+
+fn unsupported_metadata() -> SchemaError {
+    SchemaError::Unsupported {
+        modifier: Modifier::Metadata,
+        schema: std::any::type_name::<OpaqueSchema>(),
+        serialized: Some(r#"{"type":"string"}"#.to_string()),
+    }
+}
+
+#[test]
+fn test_try_export_reports_unsupported_modifiers() {
+    let error = ExportContext::new().try_export::<Invoice>().unwrap_err();
+    assert_eq!(error, unsupported_metadata());
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "`metadata` can't be applied to a `{}` schema ({{\"type\":\"string\"}})",
+            std::any::type_name::<OpaqueSchema>()
+        )
+    );
+
+    assert_eq!(Invoice::try_export().unwrap_err(), unsupported_metadata());
+}
+
+#[test]
+fn test_try_export_succeeds_without_modifiers() {
+    let export = Receipt::try_export().unwrap();
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(
+        export.properties["total"].serialize().as_deref(),
+        TypeSchema::new(Types::String).serialize().as_deref()
+    );
+}
+
+#[test]
+fn test_export_panics_on_unsupported_modifiers() {
+    let result = std::panic::catch_unwind(Invoice::export);
+    assert!(result.is_err());
+
+    // The panic doesn't affect later exports
+    assert!(Receipt::try_export().is_ok());
+}
+
+#[test]
+fn test_errors_of_nested_scopes_are_reported() {
+    assert_eq!(
+        ExportContext::new()
+            .try_export::<InvoiceForm>()
+            .unwrap_err(),
+        unsupported_metadata()
+    );
+    assert_eq!(
+        Registry::new().register::<InvoiceForm>().unwrap_err(),
+        unsupported_metadata()
+    );
+}
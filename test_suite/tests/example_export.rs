@@ -0,0 +1,167 @@
+use ronky::{
+    Exportable, Exported, MetadataSchema, PropertiesSchema, SchemaError, Serializable, TypeSchema,
+    Types,
+    examples::{check_examples, validate_document},
+};
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Exported, Serialize, Deserialize)]
+#[arri(example = Address { city: "Ghent".to_string(), postal_code: None }, test_examples)]
+struct Address {
+    #[arri(example = "Ghent")]
+    city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arri(example = 9000)]
+    postal_code: Option<u16>,
+}
+
+#[derive(Exported, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[arri(example = Shape::Circle { radius: 1.5 }, test_examples)]
+enum Shape {
+    Circle {
+        #[arri(example = 2.5)]
+        radius: f64,
+    },
+    Square {
+        #[arri(example = 4)]
+        side: u32,
+    },
+}
+
+// Implemented by hand, to build examples which don't match the schema.
+struct Mismatched;
+
+impl Exportable for Mismatched {
+    fn export_internal() -> impl Serializable {
+        let example = |json: &str| {
+            let mut ty = TypeSchema::new(Types::Uint8);
+            ty.set_metadata(MetadataSchema::new().set_example(json).to_owned());
+            ty
+        };
+
+        let mut schema = PropertiesSchema::new();
+        schema.set_property("age", Box::new(example("\"forty-two\"")));
+        schema.set_property("score", Box::new(example("300")));
+        schema
+    }
+}
+
+#[test]
+fn test_field_examples_export() {
+    let export = Address::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(
+        MetadataSchema::new()
+            .set_example(r#"{"city":"Ghent"}"#)
+            .set_id("Address")
+            .to_owned(),
+    );
+    expected.set_property(
+        "city",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(MetadataSchema::new().set_example("\"Ghent\"").to_owned());
+            ty
+        }),
+    );
+    expected.set_optional_property(
+        "postal_code",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::Uint16);
+            ty.set_metadata(MetadataSchema::new().set_example("9000").to_owned());
+            ty
+        }),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_examples_serialize_as_json() {
+    let serialized: serde_json::Value =
+        serde_json::from_str(&Shape::export().serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["metadata"]["example"],
+        serde_json::json!({ "type": "Circle", "radius": 1.5 })
+    );
+    assert_eq!(
+        serialized["mapping"]["Circle"]["properties"]["radius"]["metadata"]["example"],
+        serde_json::json!(2.5)
+    );
+    assert_eq!(
+        serialized["mapping"]["Square"]["properties"]["side"]["metadata"]["example"],
+        serde_json::json!(4)
+    );
+}
+
+#[test]
+fn test_valid_examples() {
+    assert_eq!(check_examples::<Address>(), Ok(()));
+    assert_eq!(check_examples::<Shape>(), Ok(()));
+}
+
+#[test]
+fn test_mismatched_examples() {
+    assert_eq!(
+        check_examples::<Mismatched>(),
+        Err(vec![
+            "example at #/properties/age: expected an integer within the range of the type"
+                .to_string(),
+            "example at #/properties/score: expected an integer within the range of the type"
+                .to_string(),
+        ])
+    );
+}
+
+#[test]
+fn test_validate_document() {
+    let schema: sonic_rs::Value =
+        sonic_rs::from_str(&Shape::export().serialize().unwrap()).unwrap();
+
+    let valid: sonic_rs::Value = sonic_rs::from_str(r#"{"type":"Square","side":2}"#).unwrap();
+    assert_eq!(validate_document(&schema, &valid), Ok(()));
+
+    let invalid: sonic_rs::Value = sonic_rs::from_str(r#"{"type":"Square","side":"two"}"#).unwrap();
+    assert_eq!(
+        validate_document(&schema, &invalid),
+        Err(vec![
+            "side: expected an integer within the range of the type".to_string()
+        ])
+    );
+
+    let unknown: sonic_rs::Value = sonic_rs::from_str(r#"{"type":"Triangle"}"#).unwrap();
+    assert_eq!(
+        validate_document(&schema, &unknown),
+        Err(vec![
+            "expected `type` to name one of the variants".to_string()
+        ])
+    );
+}
+
+#[test]
+fn test_unencodable_example_is_reported() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(example = Secret { value: 42 })]
+    struct Secret {
+        value: u8,
+    }
+
+    impl Serialize for Secret {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("secrets aren't shown"))
+        }
+    }
+
+    match Secret::try_export() {
+        Err(SchemaError::InvalidExample { ty, error }) => {
+            assert!(ty.ends_with("Secret"));
+            assert!(error.contains("secrets aren't shown"));
+        }
+        _ => panic!("expected the example to be reported"),
+    }
+}
@@ -0,0 +1,125 @@
+use ronky::{
+    Exportable, Exported, ExtensionValue, MetadataSchema, PropertiesSchema, Serializable,
+    TaggedUnionSchema, TypeSchema, Types,
+};
+
+#[test]
+fn test_struct_and_field_extensions() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(meta(owner = "billing"))]
+    struct Invoice {
+        #[arri(meta(pii = true, weight = 3, ratio = -0.5))]
+        email: String,
+        total: u32,
+    }
+
+    let export = Invoice::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(
+        MetadataSchema::new()
+            .set_extension("owner", "billing")
+            .set_id("Invoice")
+            .to_owned(),
+    );
+    expected.set_property(
+        "email",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_extension("pii", true)
+                    .set_extension("weight", 3)
+                    .set_extension("ratio", -0.5)
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_property("total", Box::new(TypeSchema::new(Types::Uint32)));
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_extensions_serialize_namespaced() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(strict, meta(owner = "billing", ui_hint = "table"))]
+    struct Invoice {
+        /// The invoiced customer
+        #[arri(meta(pii = true))]
+        customer: String,
+    }
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&Invoice::export().serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["metadata"],
+        serde_json::json!({
+            "id": "Invoice",
+            "x-extensions": { "owner": "billing", "ui_hint": "table" }
+        })
+    );
+    assert_eq!(
+        serialized["properties"]["customer"]["metadata"],
+        serde_json::json!({
+            "description": "The invoiced customer",
+            "x-extensions": { "pii": true }
+        })
+    );
+}
+
+#[test]
+fn test_enum_extensions() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(meta(owner = "payments"))]
+    enum Payment {
+        #[arri(meta(provider = "stripe"))]
+        Card {
+            last_digits: String,
+        },
+        Transfer {
+            iban: String,
+        },
+    }
+
+    let export = Payment::export();
+    assert!(export.is::<TaggedUnionSchema>());
+    let export = export.downcast_ref::<TaggedUnionSchema>().unwrap();
+
+    let metadata = export.metadata.as_ref().unwrap();
+    assert_eq!(
+        metadata.extension("owner"),
+        Some(&ExtensionValue::from("payments"))
+    );
+
+    let serialized: serde_json::Value = serde_json::from_str(&export.serialize().unwrap()).unwrap();
+    assert_eq!(
+        serialized["mapping"]["Card"]["metadata"]["x-extensions"],
+        serde_json::json!({ "provider": "stripe" })
+    );
+}
+
+#[test]
+fn test_extensions_merge_with_metadata() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(meta(owner = "billing", pii = false))]
+    struct Invoice {
+        total: u32,
+    }
+
+    let mut export = Invoice::export();
+    export.set_metadata(MetadataSchema::new().set_extension("pii", true).to_owned());
+
+    let serialized: serde_json::Value = serde_json::from_str(&export.serialize().unwrap()).unwrap();
+    assert_eq!(
+        serialized["metadata"]["x-extensions"],
+        serde_json::json!({ "owner": "billing", "pii": true })
+    );
+}
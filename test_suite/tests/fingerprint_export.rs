@@ -0,0 +1,109 @@
+use ronky::{
+    ElementsSchema, Exportable, MetadataSchema, Serializable, TypeSchema, Types,
+    fingerprint::{Fingerprint, eq_ignoring_docs},
+};
+
+mod v1 {
+    use ronky::Exported;
+
+    /// A pet.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Pet")]
+    pub struct Pet {
+        pub name: String,
+        pub age: u8,
+    }
+}
+
+mod v2 {
+    use ronky::Exported;
+
+    /// A pet which lives in the store.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Pet")]
+    pub struct Pet {
+        pub age: u8,
+        /// The name the pet responds to.
+        pub name: String,
+    }
+}
+
+mod v3 {
+    use ronky::Exported;
+
+    /// A pet.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Pet")]
+    pub struct Pet {
+        pub name: String,
+        pub age: u16,
+    }
+}
+
+mod reordered {
+    use ronky::Exported;
+
+    /// A pet.
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Pet")]
+    pub struct Pet {
+        pub age: u8,
+        pub name: String,
+    }
+}
+
+#[test]
+fn test_semantic_equality() {
+    let v1 = v1::Pet::export();
+
+    // The order of the properties doesn't matter, their documentation does
+    assert_eq!(v1.as_ref(), reordered::Pet::export().as_ref());
+    assert_ne!(v1.as_ref(), v2::Pet::export().as_ref());
+    assert_ne!(v1.serialize(), reordered::Pet::export().serialize());
+
+    assert!(eq_ignoring_docs(&v1, &v2::Pet::export()));
+    assert!(!eq_ignoring_docs(&v1, &v3::Pet::export()));
+}
+
+#[test]
+fn test_fingerprints() {
+    let v1 = Fingerprint::of(&v1::Pet::export());
+
+    assert_eq!(v1, Fingerprint::of(&v1::Pet::export()));
+    assert_eq!(v1, Fingerprint::of(&reordered::Pet::export()));
+    assert_ne!(v1, Fingerprint::of(&v2::Pet::export()));
+    assert_ne!(v1, Fingerprint::of(&v3::Pet::export()));
+
+    assert_eq!(
+        Fingerprint::of_structure(&v1::Pet::export()),
+        Fingerprint::of_structure(&v2::Pet::export())
+    );
+    assert_ne!(
+        Fingerprint::of_structure(&v1::Pet::export()),
+        Fingerprint::of_structure(&v3::Pet::export())
+    );
+
+    // A fingerprint can be exchanged as a string
+    assert_eq!(v1.to_string().len(), 16);
+    assert_eq!(v1.to_string().parse(), Ok(v1));
+}
+
+#[test]
+fn test_elements_equality_includes_metadata() {
+    let elements = || ElementsSchema::new(Box::new(TypeSchema::new(Types::String)));
+
+    let mut nullable = elements();
+    nullable.set_nullable(true);
+    assert_ne!(elements(), nullable);
+
+    let mut described = elements();
+    described.set_metadata(MetadataSchema::new().set_description("Tags").to_owned());
+    assert_ne!(elements(), described);
+    assert!(eq_ignoring_docs(&elements(), &described));
+
+    assert_eq!(elements(), elements());
+}
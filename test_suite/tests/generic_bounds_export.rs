@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use ronky::{Exportable, Exported, Serializable, TypeSchema, Types};
+
+/// A value which isn't exportable itself.
+struct Secret;
+
+trait Backend {
+    type Id;
+    type Timestamp;
+}
+
+struct Postgres;
+
+impl Backend for Postgres {
+    type Id = u64;
+    type Timestamp = i64;
+}
+
+/// A reference to another record, which is an id whatever the type of the record.
+struct Key<T>(PhantomData<T>);
+
+impl<T> Exportable for Key<T> {
+    fn export_internal() -> impl Serializable {
+        TypeSchema::new(Types::String)
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+#[arri(partial)]
+struct About<T> {
+    of: T,
+    related: Vec<T>,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+enum Change<T> {
+    Created { value: T },
+    Removed { previous: Option<T> },
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Record<B: Backend> {
+    id: B::Id,
+    deleted_at: Option<<B as Backend>::Timestamp>,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Named<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+#[arri(bound = "")]
+struct Link<T> {
+    target: Key<T>,
+}
+
+#[test]
+fn test_generic_bounds_are_inferred() {
+    assert_eq!(
+        About::<String>::export().serialize().unwrap(),
+        r#"{"properties":{"of":{"type":"string"},"related":{"elements":{"type":"string"}}},"optionalProperties":{},"metadata":{"id":"AboutString"}}"#
+    );
+    assert_eq!(AboutPatch::<String>::get_schema_id(), "AboutPatchString");
+    assert_eq!(Change::<bool>::get_schema_id(), "Change");
+    assert_eq!(Named::<'static, u8>::get_schema_id(), "Named");
+}
+
+#[test]
+fn test_borrowed_type_parameters() {
+    fn id_of<'a>(_: &Named<'a, &'a str>) -> String {
+        Named::<'a, &'a str>::get_schema_id()
+    }
+
+    let name = String::from("short-lived");
+    let named = Named {
+        name: &name,
+        value: name.as_str(),
+    };
+    assert_eq!(id_of(&named), "Named");
+    assert_eq!(
+        Named::<Vec<u8>>::export().serialize().unwrap(),
+        r#"{"properties":{"name":{"type":"string"},"value":{"elements":{"type":"uint8"}}},"optionalProperties":{},"metadata":{"id":"Named"}}"#
+    );
+}
+
+#[test]
+fn test_associated_types_are_bound() {
+    assert_eq!(
+        Record::<Postgres>::export().serialize().unwrap(),
+        r#"{"properties":{"id":{"type":"uint64"}},"optionalProperties":{"deleted_at":{"type":"int64"}},"metadata":{"id":"RecordPostgres"}}"#
+    );
+}
+
+#[test]
+fn test_explicit_bounds_replace_the_inferred_bounds() {
+    assert_eq!(
+        Link::<Secret>::export().serialize().unwrap(),
+        r#"{"properties":{"target":{"type":"string"}},"optionalProperties":{},"metadata":{"id":"LinkSecret"}}"#
+    );
+    // The id is built from the type parameters, whatever the bounds
+    assert_eq!(Link::<u8>::get_schema_id(), "Link");
+    assert_eq!(Link::<Postgres>::get_schema_id(), "LinkPostgres");
+}
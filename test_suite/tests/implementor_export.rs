@@ -0,0 +1,166 @@
+use ronky::{
+    Exportable, Exported, ExportedDeserialize, ExportedSerialize, Serializable, implementors,
+};
+use serde::{Deserialize, Serialize};
+
+#[ronky::polymorphic]
+trait Action {
+    fn describe(&self) -> String;
+}
+
+#[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+struct SendEmail {
+    to: String,
+}
+
+#[ronky::implementor(tag = "email")]
+impl Action for SendEmail {
+    fn describe(&self) -> String {
+        format!("email {}", self.to)
+    }
+}
+
+/// Runs the nested actions in order.
+#[derive(Exported, Serialize, Deserialize)]
+struct Sequence {
+    steps: Vec<Box<dyn Action>>,
+}
+
+#[ronky::implementor(tag = "sequence")]
+impl Action for Sequence {
+    fn describe(&self) -> String {
+        let steps = self.steps.iter().map(|step| step.describe());
+        format!("[{}]", steps.collect::<Vec<_>>().join(", "))
+    }
+}
+
+#[ronky::polymorphic(discriminator = "kind")]
+trait Shape {}
+
+#[derive(Exported, Serialize, Deserialize)]
+struct Circle {
+    radius: f64,
+}
+
+#[ronky::implementor(tag = "circle")]
+impl Shape for Circle {}
+
+#[derive(Exported, Serialize, Deserialize)]
+struct Workflow {
+    action: Box<dyn Action>,
+}
+
+#[test]
+fn test_trait_object_export() {
+    assert_eq!(
+        implementors::<dyn Action>(),
+        [
+            ("email", std::any::type_name::<SendEmail>()),
+            ("round", std::any::type_name::<Circle>()),
+            ("sequence", std::any::type_name::<Sequence>())
+        ]
+    );
+    assert_eq!(
+        Box::<dyn Action>::export().serialize().unwrap(),
+        r#"{"discriminator":"type","mapping":{"email":{"properties":{"to":{"type":"string"}},"optionalProperties":{},"metadata":{"id":"SendEmail"}},"round":{"properties":{"radius":{"type":"float64"}},"optionalProperties":{},"metadata":{"id":"Circle"}},"sequence":{"properties":{"steps":{"elements":{"ref":"Action"}}},"optionalProperties":{},"metadata":{"id":"Sequence","description":"Runs the nested actions in order."}}}}"#
+    );
+    assert_eq!(
+        Box::<dyn Shape>::export().serialize().unwrap(),
+        r#"{"discriminator":"kind","mapping":{"circle":{"properties":{"radius":{"type":"float64"}},"optionalProperties":{},"metadata":{"id":"Circle"}}}}"#
+    );
+}
+
+#[test]
+fn test_trait_object_in_a_field_export() {
+    assert_eq!(
+        Workflow::export().serialize(),
+        Some(format!(
+            r#"{{"properties":{{"action":{}}},"optionalProperties":{{}},"metadata":{{"id":"Workflow"}}}}"#,
+            Box::<dyn Action>::export().serialize().unwrap()
+        ))
+    );
+}
+
+#[test]
+fn test_trait_object_serialization() {
+    let workflow = Workflow {
+        action: Box::new(Sequence {
+            steps: vec![
+                Box::new(SendEmail {
+                    to: "ops@example.com".to_string(),
+                }),
+                Box::new(Sequence { steps: Vec::new() }),
+            ],
+        }),
+    };
+
+    let json = workflow.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"action":{"type":"sequence","steps":[{"type":"email","to":"ops@example.com"},{"type":"sequence","steps":[]}]}}"#
+    );
+
+    let decoded = Workflow::from_json(&json).unwrap();
+    assert_eq!(decoded.action.describe(), "[email ops@example.com, []]");
+    assert_eq!(decoded.to_json().unwrap(), json);
+}
+
+#[test]
+fn test_trait_object_serialization_with_serde_json() {
+    let json =
+        r#"{"action":{"steps":[{"to":"ops@example.com","type":"email"}],"type":"sequence"}}"#;
+
+    let decoded = serde_json::from_str::<Workflow>(json).unwrap();
+    assert_eq!(decoded.action.describe(), "[email ops@example.com]");
+    assert_eq!(
+        serde_json::to_string(&decoded).unwrap(),
+        r#"{"action":{"type":"sequence","steps":[{"type":"email","to":"ops@example.com"}]}}"#
+    );
+}
+
+#[test]
+fn test_unknown_tags_are_rejected() {
+    let error = Workflow::from_json(r#"{"action":{"type":"sms","to":"+32"}}"#)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("unknown tag `sms`"));
+    assert!(Workflow::from_json(r#"{"action":{"to":"ops@example.com"}}"#).is_err());
+}
+
+#[ronky::implementor(tag = "round")]
+impl Action for Circle {
+    fn describe(&self) -> String {
+        format!("circle {}", self.radius)
+    }
+}
+
+#[derive(Exported, Serialize, Deserialize)]
+struct Drawing {
+    shape: Box<dyn Shape>,
+}
+
+#[test]
+fn test_implementor_of_multiple_trait_objects() {
+    let drawing = Drawing {
+        shape: Box::new(Circle { radius: 1.5 }),
+    };
+    let workflow = Workflow {
+        action: Box::new(Circle { radius: 2.5 }),
+    };
+
+    assert_eq!(
+        drawing.to_json().unwrap(),
+        r#"{"shape":{"kind":"circle","radius":1.5}}"#
+    );
+    assert_eq!(
+        workflow.to_json().unwrap(),
+        r#"{"action":{"type":"round","radius":2.5}}"#
+    );
+    assert_eq!(
+        Workflow::from_json(r#"{"action":{"type":"round","radius":2.5}}"#)
+            .unwrap()
+            .action
+            .describe(),
+        "circle 2.5"
+    );
+}
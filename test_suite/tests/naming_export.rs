@@ -0,0 +1,256 @@
+use ronky::{
+    ExportContext, Exportable, Exported, GenericIds, IdCollision, MetadataSchema, Naming,
+    PropertiesSchema, RefSchema, Registry, SCHEMA_VERSION, SchemaError, Serializable, with_naming,
+};
+
+mod billing {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    pub struct Error {
+        pub code: u16,
+    }
+}
+
+mod auth {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    pub struct Error {
+        pub reason: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "AuthError")]
+    pub struct RenamedError {
+        pub reason: String,
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct User {
+    name: String,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Page<T: Exportable + 'static> {
+    items: Vec<T>,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Pair<A: Exportable, B: Exportable> {
+    first: A,
+    second: B,
+}
+
+fn id_of(schema: &dyn Serializable) -> Option<String> {
+    let serialized: serde_json::Value = serde_json::from_str(&schema.serialize()?).ok()?;
+    serialized["metadata"]["id"].as_str().map(str::to_string)
+}
+
+#[test]
+fn test_explicit_id() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Account")]
+    struct User {
+        friends: Option<Box<Self>>,
+    }
+
+    let export = User::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("Account").to_owned());
+    expected.set_optional_property("friends", Box::new(RefSchema::new("Account")));
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_explicit_id_on_enum_and_generic() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Status")]
+    enum State {
+        Active,
+        Inactive,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(id = "Paginated")]
+    struct List<T: Exportable + 'static> {
+        items: Vec<T>,
+    }
+
+    assert_eq!(id_of(State::export().as_ref()).as_deref(), Some("Status"));
+    assert_eq!(
+        id_of(List::<User>::export().as_ref()).as_deref(),
+        Some("PaginatedUser")
+    );
+}
+
+#[test]
+fn test_module_qualified_ids() {
+    let qualified = Naming::new().set_qualified(true);
+
+    assert_eq!(billing::Error::get_schema_id(), "Error");
+    with_naming(qualified, || {
+        assert_eq!(billing::Error::get_schema_id(), "BillingError");
+        assert_eq!(auth::Error::get_schema_id(), "AuthError");
+        // Explicit ids are used as-is
+        assert_eq!(auth::RenamedError::get_schema_id(), "AuthError");
+        assert_eq!(
+            id_of(billing::Error::export().as_ref()).as_deref(),
+            Some("BillingError")
+        );
+    });
+}
+
+#[test]
+fn test_generic_id_strategies() {
+    let ids = |generics: GenericIds| {
+        with_naming(Naming::new().set_generics(generics), || {
+            (
+                Page::<User>::get_schema_id(),
+                Pair::<User, Page<u8>>::get_schema_id(),
+            )
+        })
+    };
+
+    assert_eq!(
+        ids(GenericIds::Concatenated),
+        ("PageUser".to_string(), "PairUserPage".to_string())
+    );
+    assert_eq!(
+        ids(GenericIds::Underscored),
+        ("Page_User".to_string(), "Pair_User_Page".to_string())
+    );
+    assert_eq!(
+        ids(GenericIds::Of),
+        ("PageOfUser".to_string(), "PairOfUserAndPage".to_string())
+    );
+    assert_eq!(
+        ids(GenericIds::Custom {
+            name: "counted",
+            build: |id, arguments| format!("{}{}Generic", id, arguments.len()),
+        }),
+        ("Page1Generic".to_string(), "Pair2Generic".to_string())
+    );
+}
+
+#[test]
+fn test_primitive_generic_arguments() {
+    let primitive = Naming::new().set_primitive_arguments(true);
+
+    assert_eq!(Pair::<User, Page<u8>>::get_schema_id(), "PairUserPage");
+    with_naming(primitive, || {
+        assert_eq!(Pair::<User, Page<u8>>::get_schema_id(), "PairUserPageu8");
+    });
+    with_naming(primitive.set_capitalized_arguments(true), || {
+        assert_eq!(Pair::<User, Page<u8>>::get_schema_id(), "PairUserPageU8");
+        assert_eq!(Page::<bool>::get_schema_id(), "PageBool");
+    });
+}
+
+#[test]
+fn test_registry_detects_collisions() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Failure {
+        billing: billing::Error,
+        auth: auth::Error,
+    }
+
+    let mut registry = Registry::new();
+    assert_eq!(
+        registry.register::<Failure>().unwrap_err(),
+        SchemaError::IdCollision(IdCollision {
+            id: "Error".to_string(),
+            existing: "naming_export::billing::Error".to_string(),
+            colliding: "naming_export::auth::Error".to_string(),
+        })
+    );
+    assert_eq!(registry.ids().count(), 0);
+
+    registry.register::<billing::Error>().unwrap();
+    let collision = registry.register::<auth::Error>().unwrap_err();
+    assert_eq!(
+        collision.to_string(),
+        "The id `Error` is used by both `naming_export::billing::Error` and `naming_export::auth::Error`, give one of them another id with #[arri(id = \"...\")]"
+    );
+}
+
+#[test]
+fn test_registry_collects_schemas() {
+    let mut registry = Registry::new();
+    registry
+        .register::<billing::Error>()
+        .unwrap()
+        .register::<auth::RenamedError>()
+        .unwrap()
+        .register::<Page<User>>()
+        .unwrap()
+        // Registering a type again is not a collision
+        .register::<billing::Error>()
+        .unwrap();
+
+    assert_eq!(
+        registry.ids().collect::<Vec<_>>(),
+        vec!["Error", "AuthError", "PageUser"]
+    );
+    assert_eq!(registry.path_of("User"), Some("naming_export::User"));
+    assert_eq!(
+        id_of(registry.get("PageUser").unwrap()).as_deref(),
+        Some("PageUser")
+    );
+
+    // Qualified ids resolve the collision
+    let mut registry =
+        Registry::with_context(ExportContext::new().set_naming(Naming::new().set_qualified(true)));
+    registry
+        .register::<billing::Error>()
+        .unwrap()
+        .register::<auth::Error>()
+        .unwrap();
+    assert_eq!(
+        registry.ids().collect::<Vec<_>>(),
+        vec!["BillingError", "AuthError"]
+    );
+}
+
+#[test]
+fn test_registry_serializes_definitions() {
+    let mut registry = Registry::new();
+    registry.register::<billing::Error>().unwrap();
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&registry.serialize().unwrap()).unwrap();
+    assert_eq!(serialized["schemaVersion"], SCHEMA_VERSION);
+    assert_eq!(
+        serialized["definitions"]["Error"]["properties"]["code"]["type"],
+        "uint16"
+    );
+
+    let mut registry = Registry::with_context(ExportContext::new().set_schema_version("v0.0.9"));
+    registry.register::<User>().unwrap();
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&registry.serialize().unwrap()).unwrap();
+    assert_eq!(serialized["schemaVersion"], "v0.0.9");
+    assert_eq!(
+        serialized["definitions"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["User"]
+    );
+}
@@ -1,4 +1,9 @@
-use ronky::{Exportable, Exported, PropertiesSchema, TypeSchema, Types};
+use std::collections::HashMap;
+
+use ronky::{
+    ElementsSchema, Exportable, Exported, PropertiesSchema, Serializable, TypeSchema, Types,
+    ValuesSchema,
+};
 
 #[test]
 fn test_export() {
@@ -110,3 +115,118 @@ fn test_nullable_explicit_disable_export() {
     let export = export.downcast_ref::<PropertiesSchema>().unwrap();
     assert_eq!(export.optional_properties, expected.optional_properties);
 }
+
+#[test]
+fn test_double_option_export() {
+    #[derive(Exported)]
+    #[allow(dead_code)]
+    struct PatchStruct {
+        value: Option<Option<String>>,
+    }
+
+    let export = PatchStruct::export();
+    let expected = {
+        let mut prop = PropertiesSchema::new();
+        prop.set_optional_property(
+            "value",
+            Box::new({
+                use ronky::Serializable;
+                let mut ty = TypeSchema::new(Types::String);
+                ty.set_nullable(true);
+                ty
+            }),
+        );
+
+        prop
+    };
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert!(export.properties.is_empty());
+    assert_eq!(export.optional_properties, expected.optional_properties);
+}
+
+#[test]
+fn test_double_option_explicit_disable_export() {
+    #[derive(Exported)]
+    #[allow(dead_code)]
+    struct PatchStruct {
+        #[arri(nullable = false)]
+        value: Option<Option<String>>,
+    }
+
+    let export = PatchStruct::export();
+    let expected = {
+        let mut prop = PropertiesSchema::new();
+        prop.set_optional_property(
+            "value",
+            Box::new({
+                use ronky::Serializable;
+                let mut ty = TypeSchema::new(Types::String);
+                ty.set_nullable(false);
+                ty
+            }),
+        );
+
+        prop
+    };
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(export.optional_properties, expected.optional_properties);
+}
+
+#[test]
+fn test_nested_option_export() {
+    fn nullable_string() -> Option<String> {
+        let mut ty = TypeSchema::new(Types::String);
+        ty.set_nullable(true);
+        ty.serialize()
+    }
+
+    let elements = Vec::<Option<String>>::export();
+    let elements = elements.downcast_ref::<ElementsSchema>().unwrap();
+    assert_eq!(elements.elements.serialize(), nullable_string());
+
+    let values = HashMap::<String, Option<String>>::export();
+    let values = values.downcast_ref::<ValuesSchema>().unwrap();
+    assert_eq!(values.values.serialize(), nullable_string());
+
+    assert_eq!(
+        Result::<Option<String>, String>::export()
+            .serialize()
+            .unwrap(),
+        r#"{"discriminator":"type","mapping":{"Ok":{"properties":{"value":{"type":"string","isNullable":true}},"optionalProperties":{}},"Err":{"properties":{"value":{"type":"string"}},"optionalProperties":{}}}}"#
+    );
+}
+
+#[test]
+fn test_generic_option_export() {
+    #[derive(Exported)]
+    #[allow(dead_code)]
+    struct Page<T: Exportable + 'static> {
+        items: Vec<T>,
+        cursor: Option<String>,
+    }
+
+    #[derive(Exported)]
+    #[allow(dead_code)]
+    struct Wrapper(Option<u8>);
+
+    let export = Page::<Option<u8>>::export();
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(
+        export.properties["items"].serialize().unwrap(),
+        r#"{"elements":{"type":"uint8","isNullable":true}}"#
+    );
+    // Fields stay optional properties, rather than nullable ones
+    assert_eq!(
+        export.optional_properties["cursor"].serialize().unwrap(),
+        r#"{"type":"string"}"#
+    );
+
+    assert_eq!(
+        Wrapper::export().serialize().unwrap(),
+        r#"{"type":"uint8","isNullable":true}"#
+    );
+}
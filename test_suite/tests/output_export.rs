@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use ronky::{ExportContext, Exportable, Exported, OutputOptions, Serializable};
+
+/// A note attached to an order.
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Note {
+    /// The text of the note.
+    zeta: String,
+    #[arri(deprecated(since = "1.2.0", note = "Notes are no longer categorized"))]
+    category: Option<String>,
+    /// A field which happens to be called description.
+    description: Vec<u8>,
+}
+
+fn value(json: &str) -> serde_json::Value {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_pretty_output() {
+    let compact = Note::export().serialize().unwrap();
+    let pretty = OutputOptions::new()
+        .set_indent(4)
+        .serialize(&Note::export())
+        .unwrap();
+
+    assert_eq!(value(&pretty), value(&compact));
+    assert!(
+        pretty.starts_with("{\n    \"properties\": {\n        \"zeta\": {\n            \"type\"")
+    );
+    assert!(pretty.contains("\"elements\": {"));
+    assert!(pretty.ends_with("\n    }\n}"));
+}
+
+#[test]
+fn test_canonical_output() {
+    let default = Note::export().serialize().unwrap();
+    let canonical = OutputOptions::new()
+        .set_canonical(true)
+        .serialize(&Note::export())
+        .unwrap();
+
+    assert_eq!(value(&canonical), value(&default));
+    // Properties keep their order by default, and are sorted in canonical output
+    assert!(default.find("\"zeta\"") < default.find("\"description\""));
+    assert!(canonical.find("\"description\"") < canonical.find("\"zeta\""));
+    assert!(canonical.find("\"metadata\"") < canonical.find("\"properties\""));
+}
+
+#[test]
+fn test_hash_maps_are_deterministic() {
+    let keys = (0..32).map(|i| format!("key{}", i)).collect::<Vec<_>>();
+    let first = keys
+        .iter()
+        .map(|k| (k.clone(), 1))
+        .collect::<HashMap<_, _>>();
+    let second = keys
+        .iter()
+        .rev()
+        .map(|k| (k.clone(), 1))
+        .collect::<HashMap<_, _>>();
+
+    assert_eq!(first.serialize(), second.serialize());
+}
+
+#[test]
+fn test_stripped_output() {
+    let stripped = OutputOptions::new()
+        .set_stripped(true)
+        .serialize(&Note::export())
+        .unwrap();
+    let stripped = value(&stripped);
+
+    assert_eq!(stripped["metadata"], serde_json::json!({ "id": "Note" }));
+    assert_eq!(
+        stripped["properties"]["zeta"],
+        serde_json::json!({ "type": "string" })
+    );
+    // Deprecated properties stay marked as such
+    assert_eq!(
+        stripped["optionalProperties"]["category"]["metadata"],
+        serde_json::json!({ "isDeprecated": true })
+    );
+    // Properties named like documentation are kept
+    assert!(stripped["properties"]["description"].is_object());
+}
+
+#[test]
+fn test_context_output() {
+    let options = OutputOptions::new().set_indent(2).set_canonical(true);
+    let context = ExportContext::new().set_output(options).set_cached(true);
+
+    assert_eq!(
+        context.serialize::<Note>().as_deref(),
+        options.serialize(&Note::export()).as_deref()
+    );
+}
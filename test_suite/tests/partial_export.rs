@@ -0,0 +1,195 @@
+use ronky::{
+    Exportable, Exported, ExportedDeserialize, ExportedSerialize, MetadataSchema, PropertiesSchema,
+    Serializable, TypeSchema, Types,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Exported, Debug, PartialEq)]
+#[arri(partial(serde), rename_all = "camelCase")]
+struct User {
+    /// The name shown in the UI
+    display_name: String,
+    age: u8,
+    #[arri(nullable)]
+    nickname: Option<String>,
+}
+
+#[test]
+fn test_partial_export() {
+    let export = UserPatch::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(
+        MetadataSchema::new()
+            .set_id("UserPatch")
+            .set_description("A partial update of `User`, in which every property is optional.")
+            .to_owned(),
+    );
+    expected.set_optional_property(
+        "displayName",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_description("The name shown in the UI")
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_optional_property("age", Box::new(TypeSchema::new(Types::Uint8)));
+    expected.set_optional_property(
+        "nickname",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_nullable(true);
+            ty
+        }),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_partial_apply() {
+    let mut user = User {
+        display_name: "Ronky".to_string(),
+        age: 14,
+        nickname: Some("Ronk".to_string()),
+    };
+
+    UserPatch {
+        age: Some(15),
+        ..Default::default()
+    }
+    .apply(&mut user);
+
+    assert_eq!(
+        user,
+        User {
+            display_name: "Ronky".to_string(),
+            age: 15,
+            nickname: Some("Ronk".to_string()),
+        }
+    );
+
+    UserPatch {
+        display_name: Some("Ronky the cat".to_string()),
+        nickname: Some(None),
+        ..Default::default()
+    }
+    .apply(&mut user);
+
+    assert_eq!(
+        user,
+        User {
+            display_name: "Ronky the cat".to_string(),
+            age: 15,
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn test_partial_custom_name() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Cover {
+        color: String,
+    }
+
+    // Without `partial(serde)`, the fields don't need to implement serde's traits
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    #[arri(partial = "UpdateBook")]
+    struct Book {
+        title: String,
+        cover: Cover,
+    }
+
+    let export = UpdateBook::export();
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert!(export.properties.is_empty());
+    assert_eq!(
+        export.metadata.as_ref().and_then(|m| m.id.clone()),
+        Some("UpdateBook".to_string())
+    );
+}
+
+#[test]
+fn test_partial_serialization() {
+    let patch = UserPatch::from_json(r#"{"displayName":"Ronky","nickname":null}"#).unwrap();
+
+    assert_eq!(patch.display_name, Some("Ronky".to_string()));
+    assert_eq!(patch.age, None);
+    assert_eq!(patch.nickname, Some(None));
+
+    assert_eq!(
+        patch.to_json().unwrap(),
+        r#"{"displayName":"Ronky","nickname":null}"#
+    );
+}
+
+#[derive(Exported, Serialize, Deserialize)]
+#[arri(partial(name = "UpdateAccount", serde))]
+#[serde(rename_all = "kebab-case")]
+struct Account {
+    display_name: String,
+    #[serde(rename = "mail")]
+    email: String,
+    bio: Option<String>,
+}
+
+#[test]
+fn test_partial_keeps_the_serde_attributes() {
+    let patch = UpdateAccount {
+        display_name: Some("Ronky".to_string()),
+        email: Some("ronky@example.com".to_string()),
+        ..Default::default()
+    };
+    let json = patch.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"display-name":"Ronky","mail":"ronky@example.com"}"#
+    );
+    assert_eq!(UpdateAccount::from_json(&json).unwrap().email, patch.email);
+
+    let mut account = Account {
+        display_name: "Ronk".to_string(),
+        email: "ronk@example.com".to_string(),
+        bio: None,
+    };
+    patch.apply(&mut account);
+    assert_eq!(
+        account.to_json().unwrap(),
+        r#"{"display-name":"Ronky","mail":"ronky@example.com","bio":null}"#
+    );
+}
+
+#[test]
+fn test_partial_clears_optional_fields() {
+    let serialized: serde_json::Value =
+        serde_json::from_str(&UpdateAccount::export().serialize().unwrap()).unwrap();
+    assert_eq!(
+        serialized["optionalProperties"]["bio"]["isNullable"],
+        serde_json::json!(true)
+    );
+
+    let patch = UpdateAccount::from_json(r#"{"bio":null}"#).unwrap();
+    assert_eq!(patch.bio, Some(None));
+    assert_eq!(patch.to_json().unwrap(), r#"{"bio":null}"#);
+
+    let mut account = Account {
+        display_name: "Ronk".to_string(),
+        email: "ronk@example.com".to_string(),
+        bio: Some("Cat".to_string()),
+    };
+    patch.apply(&mut account);
+    assert_eq!(account.bio, None);
+
+    let patch = UpdateAccount::from_json(r#"{"bio":"Cat"}"#).unwrap();
+    assert_eq!(patch.bio, Some(Some("Cat".to_string())));
+    assert_eq!(UpdateAccount::from_json("{}").unwrap().bio, None);
+}
@@ -52,3 +52,44 @@ fn test_binary_tree_export() {
     let export = export.downcast_ref::<PropertiesSchema>().unwrap();
     assert_eq!(*export, expected);
 }
+
+#[test]
+fn test_nullable_recursive_field_export() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Comment {
+        /// The comment this is a reply to
+        #[arri(nullable)]
+        parent: Option<Box<Self>>,
+    }
+
+    let export = Comment::export();
+    let expected = {
+        let mut schema = PropertiesSchema::new();
+
+        schema.set_metadata(MetadataSchema::new().set_id("Comment").to_owned());
+        schema.set_optional_property(
+            "parent",
+            Box::new({
+                let mut ty = RefSchema::new("Comment");
+                ty.set_nullable(true);
+                ty.set_metadata(
+                    MetadataSchema::new()
+                        .set_description("The comment this is a reply to")
+                        .to_owned(),
+                );
+                ty
+            }),
+        );
+
+        schema
+    };
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+    assert_eq!(
+        export.serialize().unwrap(),
+        r#"{"properties":{},"optionalProperties":{"parent":{"ref":"Comment","metadata":{"description":"The comment this is a reply to"},"isNullable":true}},"metadata":{"id":"Comment"}}"#
+    );
+}
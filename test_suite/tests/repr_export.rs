@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use ronky::{
+    Discriminants, Exportable, Exported, ExportedDeserialize, ExportedSerialize, IntEnum, MapKey,
+    Serializable, TypeSchema,
+};
+use serde::{Deserialize, Serialize};
+
+/// How urgent a ticket is.
+#[derive(Exported, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[arri(repr = "int", transform = "snakecase")]
+#[repr(u8)]
+enum Priority {
+    Low = 1,
+    #[arri(rename = "Urgent")]
+    High = 5,
+}
+
+#[derive(Exported, Debug, PartialEq)]
+#[arri(repr = "int")]
+#[repr(i16)]
+enum Balance {
+    Debt = -300,
+    Even = 0,
+    Credit = 0x7f,
+}
+
+#[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+struct Ticket {
+    #[serde(with = "ronky::int_enum")]
+    priority: Priority,
+    #[serde(with = "ronky::map_keys")]
+    estimates: BTreeMap<Priority, u32>,
+}
+
+#[test]
+fn test_int_enum_export() {
+    let export = Priority::export();
+    assert_eq!(
+        export.serialize().unwrap(),
+        r#"{"type":"uint8","metadata":{"id":"Priority","description":"How urgent a ticket is.","x-extensions":{"discriminants":[{"name":"low","value":1},{"name":"urgent","value":5}]}}}"#
+    );
+
+    let mut discriminants = Discriminants::new();
+    discriminants.add_variant("low", 1).add_variant("urgent", 5);
+    assert_eq!(
+        export.downcast_ref::<TypeSchema>().unwrap().discriminants(),
+        Some(&discriminants)
+    );
+}
+
+#[test]
+fn test_signed_int_enum_export() {
+    assert_eq!(
+        Balance::export().serialize().unwrap(),
+        r#"{"type":"int16","metadata":{"id":"Balance","x-extensions":{"discriminants":[{"name":"Debt","value":-300},{"name":"Even","value":0},{"name":"Credit","value":127}]}}}"#
+    );
+}
+
+#[test]
+fn test_int_enum_round_trip() {
+    assert_eq!(Priority::High.to_repr(), 5);
+    assert_eq!(Priority::from_repr(1), Some(Priority::Low));
+    assert_eq!(Priority::from_repr(2), None);
+
+    assert_eq!(Balance::Debt.to_repr(), -300);
+    assert_eq!(Balance::from_repr(-300), Some(Balance::Debt));
+    assert_eq!(Balance::from_repr(127), Some(Balance::Credit));
+}
+
+#[test]
+fn test_int_enum_map_keys() {
+    assert_eq!(Priority::High.to_key(), "5");
+    assert_eq!(Priority::from_key("1"), Ok(Priority::Low));
+    assert!(Priority::from_key("low").is_err());
+    assert!(Priority::from_key("2").is_err());
+}
+
+#[test]
+fn test_int_enum_serialization() {
+    let ticket = Ticket {
+        priority: Priority::High,
+        estimates: [(Priority::Low, 3), (Priority::High, 8)].into(),
+    };
+
+    let json = ticket.to_json().unwrap();
+    assert_eq!(json, r#"{"priority":5,"estimates":{"1":3,"5":8}}"#);
+    assert_eq!(Ticket::from_json(&json).unwrap(), ticket);
+
+    // Integers which aren't a discriminant are rejected
+    assert!(Ticket::from_json(r#"{"priority":2,"estimates":{}}"#).is_err());
+    assert!(Ticket::from_json(r#"{"priority":"urgent","estimates":{}}"#).is_err());
+}
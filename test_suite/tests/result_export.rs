@@ -0,0 +1,127 @@
+use ronky::{
+    ExportContext, Exportable, Exported, ExportedDeserialize, ExportedSerialize, PropertiesSchema,
+    ResultShape, Serializable,
+};
+use serde::{Deserialize, Serialize};
+
+const API_RESULT: ResultShape = ResultShape::new()
+    .set_discriminator("status")
+    .set_variants("success", "error")
+    .set_properties("data", "error");
+
+ronky::result_module!(api_result = API_RESULT);
+
+const API_RESULT_JSON: &str = r#"{"discriminator":"status","mapping":{"success":{"properties":{"data":{"type":"uint32"}},"optionalProperties":{}},"error":{"properties":{"error":{"type":"string"}},"optionalProperties":{}}}}"#;
+
+#[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+struct Response {
+    #[arri(result = API_RESULT)]
+    #[serde(with = "api_result")]
+    outcome: Result<u32, String>,
+    #[serde(with = "ronky::result")]
+    fallback: Result<u32, String>,
+}
+
+#[test]
+fn test_default_result_export() {
+    assert_eq!(
+        Result::<u32, String>::export().serialize().unwrap(),
+        r#"{"discriminator":"type","mapping":{"Ok":{"properties":{"value":{"type":"uint32"}},"optionalProperties":{}},"Err":{"properties":{"value":{"type":"string"}},"optionalProperties":{}}}}"#
+    );
+}
+
+#[test]
+fn test_context_result_shape_export() {
+    let export = ExportContext::new()
+        .set_result_shape(API_RESULT)
+        .export::<Result<u32, String>>();
+    assert_eq!(export.serialize().unwrap(), API_RESULT_JSON);
+}
+
+#[test]
+fn test_field_result_shape_export() {
+    let export = Response::export();
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+
+    assert_eq!(
+        export.properties["outcome"].serialize().unwrap(),
+        API_RESULT_JSON
+    );
+    // The shape only applies to the annotated field
+    assert_eq!(
+        export.properties["fallback"].serialize(),
+        Result::<u32, String>::export().serialize()
+    );
+}
+
+#[test]
+fn test_result_serialization_follows_shape() {
+    let response = Response {
+        outcome: Err("not found".to_string()),
+        fallback: Ok(7),
+    };
+
+    let json = response.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"outcome":{"status":"error","error":"not found"},"fallback":{"type":"Ok","value":7}}"#
+    );
+    assert_eq!(Response::from_json(&json).unwrap(), response);
+
+    // The discriminator doesn't have to come first
+    let reordered = Response::from_json(
+        r#"{"outcome":{"data":3,"status":"success"},"fallback":{"value":"no","type":"Err"}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        reordered,
+        Response {
+            outcome: Ok(3),
+            fallback: Err("no".to_string()),
+        }
+    );
+
+    assert!(Response::from_json(r#"{"outcome":{"status":"maybe"},"fallback":{}}"#).is_err());
+}
+
+#[test]
+fn test_result_serialization_with_serde_json() {
+    let response = Response {
+        outcome: Ok(3),
+        fallback: Err("no".to_string()),
+    };
+
+    let json = serde_json::to_string(&response).unwrap();
+    assert_eq!(json, response.to_json().unwrap());
+    assert_eq!(serde_json::from_str::<Response>(&json).unwrap(), response);
+
+    // The payload is buffered until the discriminator is read
+    let reordered = serde_json::from_str::<Response>(
+        r#"{"outcome":{"data":3,"status":"success"},"fallback":{"value":"no","type":"Err"}}"#,
+    )
+    .unwrap();
+    assert_eq!(reordered, response);
+}
+
+#[test]
+fn test_custom_result_enum() {
+    // A result-like enum for procedures, which is exported and serialized like any other enum
+    #[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+    #[arri(discriminator = "status", transform = "lowercase")]
+    #[serde(tag = "status", rename_all = "lowercase")]
+    enum Outcome<T: Exportable + 'static, E: Exportable + 'static> {
+        Success { data: T },
+        Error { error: E },
+    }
+
+    let export = Outcome::<u32, String>::export().serialize().unwrap();
+    assert_eq!(
+        export,
+        API_RESULT_JSON.replace("}}}}", r#"}}},"metadata":{"id":"OutcomeString"}}"#)
+    );
+
+    let outcome: Outcome<u32, String> = Outcome::Success { data: 3 };
+    let json = outcome.to_json().unwrap();
+    assert_eq!(json, r#"{"status":"success","data":3}"#);
+    assert_eq!(Outcome::from_json(&json).unwrap(), outcome);
+}
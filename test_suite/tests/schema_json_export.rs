@@ -0,0 +1,81 @@
+use ronky::{ExportContext, ExportDirection, Exportable, Exported, Serializable};
+
+mod models {
+    use ronky::Exported;
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    pub struct User {
+        #[arri(read_only)]
+        pub id: u64,
+        pub name: String,
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+#[arri(transform = "lowercase")]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Page<T: Exportable + 'static> {
+    items: Vec<T>,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Borrowed<'a> {
+    name: &'a str,
+}
+
+#[test]
+fn test_schema_json_matches_export() {
+    assert_eq!(
+        models::User::schema_json(),
+        models::User::export().serialize().as_deref()
+    );
+    assert_eq!(
+        Role::schema_json(),
+        Some(r#"{"enum":["admin","member"],"metadata":{"id":"Role"}}"#)
+    );
+}
+
+#[test]
+fn test_schema_json_is_built_once() {
+    let first = models::User::schema_json().unwrap();
+    let second = models::User::schema_json().unwrap();
+    assert!(std::ptr::eq(first, second));
+}
+
+#[test]
+fn test_schema_json_uses_the_default_context() {
+    let input = ExportContext::new()
+        .set_direction(ExportDirection::Input)
+        .set_naming(ronky::Naming::new().set_qualified(true));
+
+    // Exports in progress don't affect the shared schema
+    ronky::with_naming(ronky::Naming::new().set_qualified(true), || {
+        assert!(
+            models::User::schema_json()
+                .unwrap()
+                .contains(r#""id":"User""#)
+        );
+    });
+    assert!(
+        input
+            .serialize::<models::User>()
+            .unwrap()
+            .contains("ModelsUserInput")
+    );
+}
+
+#[test]
+fn test_generic_types_fall_back() {
+    assert_eq!(Page::<models::User>::schema_json(), None);
+    assert_eq!(Borrowed::schema_json(), None);
+    assert!(Page::<models::User>::export().serialize().is_some());
+}
@@ -78,6 +78,7 @@ fn test_metadata_field_detection() {
         is_deprecated: Some(false),
         deprecated_since: None,
         deprecated_message: None,
+        ..Default::default()
     };
 
     instance.set_metadata(test_metadata.clone());
@@ -140,6 +141,7 @@ fn test_combined_metadata_and_nullable() {
         is_deprecated: None,
         deprecated_since: None,
         deprecated_message: None,
+        ..Default::default()
     };
 
     instance.set_metadata(test_metadata.clone());
@@ -400,6 +402,7 @@ fn test_type_schema_setters() {
         is_deprecated: None,
         deprecated_since: None,
         deprecated_message: None,
+        ..Default::default()
     };
 
     type_schema.set_metadata(metadata.clone());
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use ronky::{Exportable, Exported, ExportedDeserialize, ExportedSerialize};
 use serde::{Deserialize, Serialize};
 
@@ -184,3 +186,74 @@ fn test_generic_struct_serialization() {
     let int_deserialized: Container<i32> = Container::from_json(&int_json).unwrap();
     assert_eq!(int_deserialized, int_container);
 }
+
+#[test]
+fn test_double_option_serialization() {
+    #[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+    struct UserPatch {
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ronky::double_option"
+        )]
+        nickname: Option<Option<String>>,
+    }
+
+    let cases = [
+        (UserPatch { nickname: None }, r#"{}"#),
+        (
+            UserPatch {
+                nickname: Some(None),
+            },
+            r#"{"nickname":null}"#,
+        ),
+        (
+            UserPatch {
+                nickname: Some(Some("Ronky".to_string())),
+            },
+            r#"{"nickname":"Ronky"}"#,
+        ),
+    ];
+
+    for (patch, expected_json) in cases {
+        let json = patch.to_json().unwrap();
+        assert_eq!(json, expected_json);
+
+        let deserialized = UserPatch::from_json(&json).unwrap();
+        assert_eq!(deserialized, patch);
+    }
+}
+
+#[test]
+fn test_map_keys_serialization() {
+    #[derive(Exported, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[arri(transform = "uppercase")]
+    enum Fruit {
+        Apple,
+        Pear,
+    }
+
+    #[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+    struct Inventory {
+        #[serde(with = "ronky::map_keys")]
+        stock: BTreeMap<Fruit, u32>,
+        #[serde(with = "ronky::map_keys")]
+        shelves: HashMap<u8, String>,
+    }
+
+    let inventory = Inventory {
+        stock: [(Fruit::Apple, 3), (Fruit::Pear, 0)].into(),
+        shelves: [(7, "fruit".to_string())].into(),
+    };
+
+    let json = inventory.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"stock":{"APPLE":3,"PEAR":0},"shelves":{"7":"fruit"}}"#
+    );
+    assert_eq!(Inventory::from_json(&json).unwrap(), inventory);
+
+    // Keys outside of the enum schema and invalid numbers are rejected
+    assert!(Inventory::from_json(r#"{"stock":{"Apple":3},"shelves":{}}"#).is_err());
+    assert!(Inventory::from_json(r#"{"stock":{},"shelves":{"-1":"x"}}"#).is_err());
+}
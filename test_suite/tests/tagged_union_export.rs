@@ -138,3 +138,31 @@ fn test_export_metadata() {
     let export = export.downcast_ref::<TaggedUnionSchema>().unwrap();
     assert_eq!(*export, expected);
 }
+
+#[test]
+fn test_nullable_documented_field_export() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    enum Shape {
+        Circle { radius: f64 },
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Drawing {
+        /// The shape which is highlighted
+        #[arri(nullable)]
+        highlighted: Option<Shape>,
+    }
+
+    let export = Drawing::export();
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+
+    // The description of the field is merged with the id of the union
+    assert_eq!(
+        export.optional_properties["highlighted"]
+            .serialize()
+            .unwrap(),
+        r#"{"discriminator":"type","mapping":{"Circle":{"properties":{"radius":{"type":"float64"}},"optionalProperties":{}}},"metadata":{"id":"Shape","description":"The shape which is highlighted"},"isNullable":true}"#
+    );
+}
@@ -0,0 +1,135 @@
+use ronky::{
+    BufferedValue, Exportable, Exported, ExportedDeserialize, ExportedSerialize, Serializable,
+    UntaggedSchema,
+    untagged::{Failure, Untagged, UntaggedError},
+    validation::Validate,
+};
+use serde::{Deserialize, Serialize};
+
+/// An amount in one of the legacy formats.
+#[derive(Exported, Debug, PartialEq)]
+#[arri(untagged, transform = "snake_case")]
+enum Amount {
+    /// A whole amount of cents.
+    Cents(#[arri(min = 0)] i64),
+    Decimal(f64),
+    #[arri(rename = "Formatted")]
+    Text(#[arri(pattern = "^[0-9]+\\.[0-9]{2}$")] String),
+}
+
+#[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+struct Payment {
+    #[serde(with = "ronky::untagged")]
+    amount: Amount,
+}
+
+fn decode(json: &str) -> Result<Amount, UntaggedError> {
+    Amount::decode_untagged(&serde_json::from_str::<BufferedValue>(json).unwrap())
+}
+
+#[test]
+fn test_untagged_export() {
+    let export = Amount::export();
+    assert_eq!(
+        export.serialize().unwrap(),
+        r#"{"metadata":{"id":"Amount","description":"An amount in one of the legacy formats.","x-extensions":{"candidates":[{"name":"cents","schema":{"type":"int64","metadata":{"description":"A whole amount of cents.","constraints":{"min":0}}}},{"name":"decimal","schema":{"type":"float64"}},{"name":"formatted","schema":{"type":"string","metadata":{"constraints":{"pattern":"^[0-9]+\\.[0-9]{2}$"}}}}]}},"isNullable":false}"#
+    );
+
+    let candidates = export
+        .downcast_ref::<UntaggedSchema>()
+        .unwrap()
+        .candidates()
+        .iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(candidates, ["cents", "decimal", "formatted"]);
+}
+
+#[test]
+fn test_untagged_decodes_variants_in_order() {
+    assert_eq!(decode("12"), Ok(Amount::Cents(12)));
+    assert_eq!(decode("12.5"), Ok(Amount::Decimal(12.5)));
+    assert_eq!(decode(r#""12.50""#), Ok(Amount::Text("12.50".to_string())));
+
+    // Cents must be positive, so the next variant is tried
+    assert_eq!(decode("-5"), Ok(Amount::Decimal(-5.0)));
+}
+
+#[test]
+fn test_untagged_reports_every_failure() {
+    let error = decode(r#""twelve""#).unwrap_err();
+    let variants = error
+        .failures
+        .iter()
+        .map(|failure| failure.variant)
+        .collect::<Vec<_>>();
+    assert_eq!(variants, ["cents", "decimal", "formatted"]);
+    assert_eq!(
+        error.failures[2],
+        Failure::new("formatted", "must match the pattern `^[0-9]+\\.[0-9]{2}$`")
+    );
+    assert!(
+        error
+            .to_string()
+            .starts_with("no variant of `untagged_export::Amount` matched: `cents`: ")
+    );
+}
+
+#[test]
+fn test_untagged_validation_uses_the_value_path() {
+    let errors = Amount::Cents(-1).validate().unwrap_err();
+    assert_eq!(errors[0].path, "");
+}
+
+#[test]
+fn test_untagged_serialization() {
+    let payment = Payment {
+        amount: Amount::Text("3.20".to_string()),
+    };
+
+    let json = payment.to_json().unwrap();
+    assert_eq!(json, r#"{"amount":"3.20"}"#);
+    assert_eq!(Payment::from_json(&json).unwrap(), payment);
+    assert_eq!(
+        Payment::from_json(r#"{"amount":7}"#).unwrap().amount,
+        Amount::Cents(7)
+    );
+    assert!(Payment::from_json(r#"{"amount":true}"#).is_err());
+}
+
+#[derive(Exported, Serialize, Deserialize, Debug, PartialEq)]
+struct Outcome {
+    #[serde(with = "ronky::result")]
+    result: Result<u32, String>,
+}
+
+#[derive(Exported, Debug, PartialEq)]
+#[arri(untagged)]
+enum Reply {
+    Outcome(Outcome),
+    Message(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Envelope {
+    #[serde(with = "ronky::untagged")]
+    reply: Reply,
+}
+
+#[test]
+fn test_untagged_variant_with_result_field() {
+    let envelope = Envelope {
+        reply: Reply::Outcome(Outcome { result: Ok(3) }),
+    };
+    let json = r#"{"reply":{"result":{"type":"Ok","value":3}}}"#;
+
+    assert_eq!(envelope.to_json().unwrap(), json);
+    assert_eq!(Envelope::from_json(json).unwrap(), envelope);
+    assert_eq!(serde_json::from_str::<Envelope>(json).unwrap(), envelope);
+    assert_eq!(
+        serde_json::from_str::<Envelope>(r#"{"reply":"hello"}"#)
+            .unwrap()
+            .reply,
+        Reply::Message("hello".to_string())
+    );
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use ronky::{
+    ConstraintsSchema, Exportable, Exported, MetadataSchema, PropertiesSchema, Serializable,
+    TypeSchema, Types,
+    validation::{Validate, ValidationError},
+};
+
+#[allow(dead_code)]
+#[derive(Exported)]
+struct Address {
+    #[arri(min_length = 1)]
+    city: String,
+    #[arri(pattern = "^[0-9]{4}$")]
+    postal_code: String,
+}
+
+#[allow(dead_code)]
+#[derive(Exported)]
+#[arri(rename_all = "camelCase")]
+struct User {
+    /// The age of the user in years
+    #[arri(min = 18, max = 130)]
+    age: u8,
+    #[arri(format = "email")]
+    email_address: String,
+    #[arri(min_length = 1, max_length = 3)]
+    tags: Vec<String>,
+    #[arri(min = -1.5)]
+    score: Option<f64>,
+    #[arri(validate)]
+    address: Address,
+    #[arri(validate)]
+    previous_addresses: Vec<Address>,
+    #[arri(validate)]
+    labels: HashMap<String, Address>,
+}
+
+fn address(city: &str, postal_code: &str) -> Address {
+    Address {
+        city: city.to_string(),
+        postal_code: postal_code.to_string(),
+    }
+}
+
+fn valid_user() -> User {
+    User {
+        age: 30,
+        email_address: "cat@ronky.dev".to_string(),
+        tags: vec!["cat".to_string()],
+        score: Some(1.0),
+        address: address("Ghent", "9000"),
+        previous_addresses: vec![],
+        labels: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_constraints_export() {
+    let export = Address::export();
+    let mut expected = PropertiesSchema::new();
+    expected.set_metadata(MetadataSchema::new().set_id("Address").to_owned());
+    expected.set_property(
+        "city",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_constraints(ConstraintsSchema::new().set_min_length(1).to_owned())
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+    expected.set_property(
+        "postal_code",
+        Box::new({
+            let mut ty = TypeSchema::new(Types::String);
+            ty.set_metadata(
+                MetadataSchema::new()
+                    .set_constraints(
+                        ConstraintsSchema::new()
+                            .set_pattern("^[0-9]{4}$")
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            );
+            ty
+        }),
+    );
+
+    assert!(export.is::<PropertiesSchema>());
+    let export = export.downcast_ref::<PropertiesSchema>().unwrap();
+    assert_eq!(*export, expected);
+}
+
+#[test]
+fn test_constraints_alongside_description() {
+    let serialized: serde_json::Value =
+        serde_json::from_str(&User::export().serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        serialized["properties"]["age"]["metadata"],
+        serde_json::json!({
+            "description": "The age of the user in years",
+            "constraints": { "min": 18, "max": 130 }
+        })
+    );
+    assert_eq!(
+        serialized["properties"]["emailAddress"]["metadata"]["constraints"],
+        serde_json::json!({ "format": "email" })
+    );
+    assert_eq!(
+        serialized["optionalProperties"]["score"]["metadata"]["constraints"],
+        serde_json::json!({ "min": -1.5 })
+    );
+}
+
+#[test]
+fn test_validate_valid_value() {
+    assert_eq!(valid_user().validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_reports_paths() {
+    let mut user = valid_user();
+    user.age = 12;
+    user.email_address = "not an email".to_string();
+    user.tags = vec![];
+    user.score = Some(-2.0);
+    user.address = address("", "9000");
+    user.previous_addresses = vec![address("Ghent", "9000"), address("Brussels", "B-1000")];
+    user.labels.insert("home".to_string(), address("", "9000"));
+
+    assert_eq!(
+        user.validate(),
+        Err(vec![
+            ValidationError::new("age", "must be at least 18"),
+            ValidationError::new("emailAddress", "must be a valid email"),
+            ValidationError::new("tags", "must have a length of at least 1"),
+            ValidationError::new("score", "must be at least -1.5"),
+            ValidationError::new("address.city", "must have a length of at least 1"),
+            ValidationError::new(
+                "previousAddresses[1].postal_code",
+                "must match the pattern `^[0-9]{4}$`"
+            ),
+            ValidationError::new("labels.home.city", "must have a length of at least 1"),
+        ])
+    );
+}
+
+#[test]
+fn test_validate_skips_absent_values() {
+    let mut user = valid_user();
+    user.score = None;
+
+    assert_eq!(user.validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_tagged_union() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    enum Shape {
+        Circle {
+            #[arri(min = 0)]
+            radius: f64,
+        },
+        Label(#[arri(max_length = 5)] String),
+    }
+
+    assert_eq!(Shape::Circle { radius: 1.0 }.validate(), Ok(()));
+    assert_eq!(
+        Shape::Circle { radius: -1.0 }.validate(),
+        Err(vec![ValidationError::new("radius", "must be at least 0")])
+    );
+    assert_eq!(
+        Shape::Label("too long".to_string()).validate(),
+        Err(vec![ValidationError::new(
+            "value",
+            "must have a length of at most 5"
+        )])
+    );
+}
+
+#[test]
+fn test_validate_uses_the_last_constraint() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Code {
+        #[arri(pattern = "^[a-z]+$", min_length = 1)]
+        #[arri(pattern = "^[0-9]+$")]
+        #[arri(min_length = 2)]
+        value: String,
+    }
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&Code::export().serialize().unwrap()).unwrap();
+    assert_eq!(
+        serialized["properties"]["value"]["metadata"]["constraints"],
+        serde_json::json!({ "minLength": 2, "pattern": "^[0-9]+$" })
+    );
+
+    let code = |value: &str| Code {
+        value: value.to_string(),
+    };
+    assert_eq!(code("42").validate(), Ok(()));
+    assert_eq!(
+        code("ab").validate(),
+        Err(vec![ValidationError::new(
+            "value",
+            "must match the pattern `^[0-9]+$`"
+        )])
+    );
+    assert_eq!(
+        code("7").validate(),
+        Err(vec![ValidationError::new(
+            "value",
+            "must have a length of at least 2"
+        )])
+    );
+}
+
+#[test]
+fn test_validate_generic_fields() {
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Page<T> {
+        #[arri(validate)]
+        items: Vec<T>,
+    }
+
+    let page = Page {
+        items: vec![address("Ghent", "9000"), address("", "9000")],
+    };
+    assert_eq!(
+        page.validate(),
+        Err(vec![ValidationError::new(
+            "items[1].city",
+            "must have a length of at least 1"
+        )])
+    );
+}
+
+#[test]
+fn test_validation_error_display() {
+    let error = ValidationError::new("address.city", "must have a length of at least 1");
+    assert_eq!(
+        error.to_string(),
+        "address.city: must have a length of at least 1"
+    );
+}
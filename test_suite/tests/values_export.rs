@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use indexmap::IndexMap;
 use ronky::{
-    Exportable, Exported, MetadataSchema, PropertiesSchema, Serializable, TypeSchema, Types,
-    ValuesSchema,
+    ExportContext, Exportable, Exported, KeyError, KeySchema, MapKey, MetadataSchema,
+    PropertiesSchema, SchemaError, Serializable, TypeSchema, Types, ValuesSchema,
 };
 
 #[test]
@@ -29,3 +30,124 @@ fn test_export() {
     let export = export.downcast_ref::<PropertiesSchema>().unwrap();
     assert_eq!(*export, expected);
 }
+
+#[allow(dead_code)]
+#[derive(Exported, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[arri(transform = "snake_case")]
+enum Region {
+    EuWest,
+    #[arri(rename = "UsEast")]
+    UsEast1,
+}
+
+fn values(export: &dyn Serializable) -> &ValuesSchema {
+    export.downcast_ref::<ValuesSchema>().unwrap()
+}
+
+#[test]
+fn test_string_keys_have_no_key_schema() {
+    let export = HashMap::<String, bool>::export();
+    assert!(values(export.as_ref()).key_schema().is_none());
+    assert_eq!(
+        export.serialize().unwrap(),
+        r#"{"values":{"type":"boolean"}}"#
+    );
+}
+
+#[test]
+fn test_key_schema_export() {
+    let export = BTreeMap::<u32, bool>::export();
+    assert_eq!(
+        values(export.as_ref()).key_schema(),
+        Some(&KeySchema::Type(TypeSchema::new(Types::Uint32)))
+    );
+    assert_eq!(
+        export.serialize().unwrap(),
+        r#"{"values":{"type":"boolean"},"metadata":{"x-extensions":{"keySchema":{"type":"uint32"}}}}"#
+    );
+
+    let export = IndexMap::<Region, bool>::export();
+    let key_schema = values(export.as_ref()).key_schema().unwrap();
+    assert!(matches!(key_schema, KeySchema::Enum(_)));
+    assert_eq!(key_schema.serialize(), Region::export().serialize());
+}
+
+#[test]
+fn test_invalid_key_export() {
+    // Keys without a `MapKey` implementation don't compile, these implement it by hand
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Coordinates {
+        x: i32,
+        y: i32,
+    }
+
+    impl MapKey for Coordinates {
+        fn to_key(&self) -> String {
+            format!("{},{}", self.x, self.y)
+        }
+
+        fn from_key(key: &str) -> Result<Self, KeyError> {
+            let (x, y) = key
+                .split_once(',')
+                .ok_or_else(|| KeyError::new::<Self>(key))?;
+            Ok(Self {
+                x: x.parse().map_err(|_| KeyError::new::<Self>(key))?,
+                y: y.parse().map_err(|_| KeyError::new::<Self>(key))?,
+            })
+        }
+    }
+
+    #[allow(dead_code)]
+    #[derive(Exported)]
+    struct Node {
+        name: String,
+        children: HashMap<Self, bool>,
+    }
+
+    impl MapKey for Node {
+        fn to_key(&self) -> String {
+            self.name.clone()
+        }
+
+        fn from_key(key: &str) -> Result<Self, KeyError> {
+            Ok(Self {
+                name: key.to_string(),
+                children: HashMap::new(),
+            })
+        }
+    }
+
+    let error = ExportContext::new()
+        .try_export::<HashMap<Coordinates, bool>>()
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        SchemaError::InvalidMapKey { key, .. } if key.ends_with("Coordinates")
+    ));
+
+    // The key is a reference to the struct being exported
+    let error = ExportContext::new().try_export::<Node>().unwrap_err();
+    assert!(matches!(
+        error,
+        SchemaError::InvalidMapKey { key, serialized: Some(serialized) }
+            if key.ends_with("Node") && serialized == r#"{"ref":"Node"}"#
+    ));
+}
+
+#[test]
+fn test_enum_map_keys() {
+    assert_eq!(Region::EuWest.to_key(), "eu_west");
+    assert_eq!(Region::UsEast1.to_key(), "us_east");
+
+    // Keys are validated against the values of the enum schema
+    let schema = Region::export().serialize().unwrap();
+    for region in [Region::EuWest, Region::UsEast1] {
+        assert!(schema.contains(&format!("\"{}\"", region.to_key())));
+        assert_eq!(Region::from_key(&region.to_key()), Ok(region));
+    }
+    assert_eq!(
+        Region::from_key("EuWest"),
+        Err(KeyError::new::<Region>("EuWest"))
+    );
+}